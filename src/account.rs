@@ -0,0 +1,199 @@
+//! State trie account leaves and storage trie slot leaves.
+use crate::field::FieldError;
+use crate::ParsedData;
+
+/// Account stored as a state trie leaf value, `[nonce, balance,
+/// storage_root, code_hash]`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Account {
+    pub nonce: u64,
+    pub balance: [u8; 32],
+    pub storage_root: [u8; 32],
+    pub code_hash: [u8; 32],
+}
+
+/// `Account` fields, used to locate errors.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccountField {
+    Balance,
+    CodeHash,
+    Nonce,
+    StorageRoot,
+}
+
+/// Errors in decoding an account.
+#[derive(Debug, Eq, PartialEq)]
+pub enum AccountError {
+    InvalidField {
+        field: AccountField,
+        reason: FieldError,
+    },
+    NotAList,
+    WrongFieldCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+const ACCOUNT_FIELD_COUNT: usize = 4;
+
+/// Decode an account from a decoded trie leaf value.
+pub fn decode_account(data: &ParsedData) -> Result<Account, AccountError> {
+    let fields = data.as_list().map_err(|_| AccountError::NotAList)?;
+    if fields.len() != ACCOUNT_FIELD_COUNT {
+        return Err(AccountError::WrongFieldCount {
+            expected: ACCOUNT_FIELD_COUNT,
+            found: fields.len(),
+        });
+    }
+    Ok(Account {
+        nonce: fields[0]
+            .as_strict_u64()
+            .map_err(invalid(AccountField::Nonce))?,
+        balance: fields[1]
+            .as_strict_u256()
+            .map_err(invalid(AccountField::Balance))?,
+        storage_root: fields[2]
+            .as_fixed_bytes::<32>()
+            .map_err(invalid(AccountField::StorageRoot))?,
+        code_hash: fields[3]
+            .as_fixed_bytes::<32>()
+            .map_err(invalid(AccountField::CodeHash))?,
+    })
+}
+
+/// Decode a storage slot value from a decoded storage trie leaf value,
+/// the canonical scalar left-padded to 32 bytes.
+///
+/// Zero slots are removed from the trie, so a proof never resolves to zero.
+pub fn decode_storage_value(data: &ParsedData) -> Result<[u8; 32], FieldError> {
+    data.as_strict_u256()
+}
+
+fn invalid(field: AccountField) -> impl Fn(FieldError) -> AccountError {
+    move |reason| AccountError::InvalidField { field, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{B256, U256};
+    use alloy_rlp::{Encodable, RlpEncodable};
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use crate::decode_whole_blob;
+
+    #[derive(RlpEncodable)]
+    struct RawAccount {
+        nonce: u64,
+        balance: U256,
+        storage_root: B256,
+        code_hash: B256,
+    }
+
+    /// Root of the empty trie.
+    const EMPTY_ROOT: &str = "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421";
+
+    /// Hash of the empty code.
+    const EMPTY_CODE_HASH: &str =
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+
+    fn decode(raw: &[u8]) -> Result<Account, AccountError> {
+        decode_account(&decode_whole_blob::<&[u8], ()>(&raw, &mut ()).unwrap())
+    }
+
+    #[test]
+    fn account() {
+        let mut raw = Vec::new();
+        RawAccount {
+            nonce: 5,
+            balance: U256::from(1_000_000_000_000_000_000u64),
+            storage_root: EMPTY_ROOT.parse().unwrap(),
+            code_hash: EMPTY_CODE_HASH.parse().unwrap(),
+        }
+        .encode(&mut raw);
+        let mut balance = [0; 32];
+        balance[24..].copy_from_slice(&1_000_000_000_000_000_000u64.to_be_bytes());
+        assert_eq!(
+            decode(&raw),
+            Ok(Account {
+                nonce: 5,
+                balance,
+                storage_root: hex::decode(EMPTY_ROOT).unwrap().try_into().unwrap(),
+                code_hash: hex::decode(EMPTY_CODE_HASH).unwrap().try_into().unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(decode(&[0x80]), Err(AccountError::NotAList));
+        assert_eq!(
+            decode(&[0xc3, 0x01, 0x02, 0x03]),
+            Err(AccountError::WrongFieldCount {
+                expected: 4,
+                found: 3
+            })
+        );
+
+        let account = |nonce: ParsedData, storage_root: Vec<u8>| {
+            decode_account(&ParsedData::List(vec![
+                nonce,
+                ParsedData::String(Vec::new()),
+                ParsedData::String(storage_root),
+                ParsedData::String(vec![0xc5; 32]),
+            ]))
+        };
+        assert!(account(ParsedData::Byte(1), vec![0x56; 32]).is_ok());
+        assert_eq!(
+            account(ParsedData::Byte(0), vec![0x56; 32]),
+            Err(AccountError::InvalidField {
+                field: AccountField::Nonce,
+                reason: FieldError::LeadingZero
+            })
+        );
+        assert_eq!(
+            account(ParsedData::Byte(1), vec![0x56; 31]),
+            Err(AccountError::InvalidField {
+                field: AccountField::StorageRoot,
+                reason: FieldError::WrongLength {
+                    expected: 32,
+                    found: 31
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn storage_value() {
+        let slot = |value: U256| {
+            let mut raw = Vec::new();
+            value.encode(&mut raw);
+            decode_storage_value(&decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap())
+        };
+        for value in [
+            U256::from(1u64),
+            U256::from(0x7fu64),
+            U256::from(0x80u64),
+            U256::from(1_000_000u64),
+            U256::MAX,
+        ] {
+            assert_eq!(slot(value), Ok(value.to_be_bytes()));
+        }
+        assert_eq!(
+            decode_storage_value(&ParsedData::String(vec![0x00, 0x01])),
+            Err(FieldError::LeadingZero)
+        );
+        assert_eq!(
+            decode_storage_value(&ParsedData::String(vec![0x01; 33])),
+            Err(FieldError::ScalarTooLong {
+                max_length: 32,
+                found: 33
+            })
+        );
+        assert_eq!(
+            decode_storage_value(&ParsedData::List(Vec::new())),
+            Err(FieldError::ExpectedString)
+        );
+    }
+}
@@ -0,0 +1,607 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::block::{
+    parse_withdrawals, BlockError, BodyError, Header, OmmersError, Withdrawal, EMPTY_OMMERS_HASH,
+};
+use crate::fork::Fork;
+use crate::span::{span_at_position, ItemKind, ItemSpan};
+use crate::transaction::{decode_transactions_with_spans, TransactionEnvelope, TxConfig};
+use crate::{decode_blob_portion_at_position, Error, ParsedData};
+
+/// Block body `[transactions, ommers, withdrawals?]`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Body {
+    pub transactions: Vec<TransactionEnvelope>,
+    /// Locations of transaction items in the parsed buffer, in the order of
+    /// [`transactions`](Self::transactions).
+    pub transaction_spans: Vec<ItemSpan>,
+    pub ommers: Vec<Header>,
+    /// Location of the ommers list in the parsed buffer.
+    pub ommers_span: ItemSpan,
+    /// Since Shanghai.
+    pub withdrawals: Option<Vec<Withdrawal>>,
+}
+
+/// Block `[header, transactions, ommers, withdrawals?]`, as in devp2p
+/// `NewBlock` message.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Block {
+    pub header: Header,
+    pub body: Body,
+}
+
+impl Body {
+    /// Parse block body `rlp([transactions, ommers, withdrawals?])`.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, BodyError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_inner(data, ext_memory, None)
+    }
+
+    /// Parse block body with withdrawals present exactly if `fork` has
+    /// them.
+    pub fn parse_for_fork<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fork: Fork,
+    ) -> Result<Self, BodyError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_inner(data, ext_memory, Some(fork))
+    }
+
+    fn parse_inner<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fork: Option<Fork>,
+    ) -> Result<Self, BodyError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let fields = whole_list(data, ext_memory).map_err(|error| match error {
+            Some(error) => BodyError::Decoding(error),
+            None => BodyError::NotAList,
+        })?;
+        Self::from_fields(data, ext_memory, &fields, fork)
+    }
+
+    /// Body from the spans of its fields.
+    ///
+    /// Ommer headers always have their fields detected: an ommer could be
+    /// a few blocks older than the block and predate `fork`.
+    pub(crate) fn from_fields<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fields: &[ItemSpan],
+        fork: Option<Fork>,
+    ) -> Result<Self, BodyError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        match fork {
+            Some(fork) if fields.len() != Self::field_count(fork) => {
+                return Err(BodyError::WrongFieldCount {
+                    fork,
+                    expected: Self::field_count(fork),
+                    found: fields.len(),
+                });
+            }
+            None if !(2..=3).contains(&fields.len()) => {
+                return Err(BodyError::InvalidFieldCount {
+                    found: fields.len(),
+                });
+            }
+            _ => {}
+        }
+        let mut position = fields[0].start;
+        let (transactions, transaction_spans) =
+            decode_transactions_with_spans(data, ext_memory, &mut position, &TxConfig::default())
+                .map_err(BodyError::Transactions)?;
+        let ommers = match decode_field(data, ext_memory, &fields[1])? {
+            ParsedData::List(ommers) => ommers
+                .iter()
+                .enumerate()
+                .map(|(index, ommer)| {
+                    Header::from_parsed(ommer, None)
+                        .map_err(|source| BodyError::InvalidOmmer { index, source })
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Err(BodyError::OmmersNotAList),
+        };
+        let withdrawals = match fields.get(2) {
+            Some(field) => Some(
+                parse_withdrawals(&decode_field(data, ext_memory, field)?)
+                    .map_err(BodyError::Withdrawals)?,
+            ),
+            None => None,
+        };
+        Ok(Self {
+            transactions,
+            transaction_spans,
+            ommers,
+            ommers_span: fields[1],
+            withdrawals,
+        })
+    }
+
+    /// Number of body fields under `fork` rules.
+    pub fn field_count(fork: Fork) -> usize {
+        if fork >= Fork::Shanghai {
+            3
+        } else {
+            2
+        }
+    }
+
+    pub fn ommers(&self) -> &[Header] {
+        &self.ommers
+    }
+
+    /// Bytes of transaction `index` as hashed for its transaction hash:
+    /// the whole item for legacy transactions, `type || payload` for typed
+    /// ones. `data` is the buffer the body was parsed from.
+    ///
+    /// `None` if `index` is out of range of [`transactions`](Self::transactions).
+    pub fn transaction_bytes<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        index: usize,
+    ) -> Option<Result<B::ReadBuffer, Error<E>>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let span = self.transaction_spans.get(index)?;
+        Some(match span.kind {
+            ItemKind::List => span.read_raw(data, ext_memory),
+            _ => span.read_payload(data, ext_memory),
+        })
+    }
+}
+
+impl Block {
+    /// Parse block `rlp([header, transactions, ommers, withdrawals?])`.
+    ///
+    /// Header fields present are detected, withdrawals must be present in
+    /// both header and body or in neither.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, BlockError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_inner(data, ext_memory, None)
+    }
+
+    /// Parse block with header and body fields of `fork`.
+    pub fn parse_for_fork<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fork: Fork,
+    ) -> Result<Self, BlockError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_inner(data, ext_memory, Some(fork))
+    }
+
+    fn parse_inner<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fork: Option<Fork>,
+    ) -> Result<Self, BlockError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let fields = whole_list(data, ext_memory).map_err(|error| match error {
+            Some(error) => BlockError::Decoding(error),
+            None => BlockError::NotAList,
+        })?;
+        Self::from_fields(data, ext_memory, &fields, fork)
+    }
+
+    /// Block from the spans of its fields.
+    pub(crate) fn from_fields<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fields: &[ItemSpan],
+        fork: Option<Fork>,
+    ) -> Result<Self, BlockError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        if !(3..=4).contains(&fields.len()) {
+            return Err(BlockError::InvalidFieldCount {
+                found: fields.len(),
+            });
+        }
+        let mut position = fields[0].start;
+        let header = Header::from_parsed(
+            &decode_blob_portion_at_position(data, ext_memory, &mut position)?,
+            fork,
+        )
+        .map_err(BlockError::Header)?;
+        let body =
+            Body::from_fields(data, ext_memory, &fields[1..], fork).map_err(BlockError::Body)?;
+        if header.withdrawals_root.is_some() != body.withdrawals.is_some() {
+            return Err(BlockError::WithdrawalsMismatch);
+        }
+        Ok(Self { header, body })
+    }
+
+    /// Check that a block of `fork` since Paris has no ommers and commits
+    /// to the empty ommers list. Earlier blocks pass.
+    pub fn check_ommers(&self, fork: Fork) -> Result<(), OmmersError> {
+        if fork < Fork::Paris {
+            return Ok(());
+        }
+        if !self.body.ommers.is_empty() {
+            return Err(OmmersError::OmmersAfterMerge {
+                count: self.body.ommers.len(),
+            });
+        }
+        if self.header.ommers_hash != EMPTY_OMMERS_HASH {
+            return Err(OmmersError::UnexpectedOmmersHash);
+        }
+        Ok(())
+    }
+
+    /// Check header ommers hash against the ommers list as encoded in
+    /// `data`, the buffer the block was parsed from.
+    #[cfg(feature = "keccak")]
+    pub fn ommers_hash_matches<B, E>(&self, data: &B, ext_memory: &mut E) -> Result<bool, Error<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let ommers = self.body.ommers_span.read_raw(data, ext_memory)?;
+        Ok(crate::keccak::keccak256(ommers.as_ref()) == self.header.ommers_hash)
+    }
+}
+
+/// Spans of fields of the list taking the whole `data`, `None` error if the
+/// item is not a list.
+pub(crate) fn whole_list<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+) -> Result<Vec<ItemSpan>, Option<Error<E>>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = 0;
+    let list = span_at_position(data, ext_memory, &mut position)?;
+    if position < data.total_len() {
+        return Err(Some(Error::SomeDataUnused { from: position }));
+    }
+    if list.kind != ItemKind::List {
+        return Err(None);
+    }
+    Ok(list.list_items(data, ext_memory)?)
+}
+
+pub(crate) fn decode_field<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    field: &ItemSpan,
+) -> Result<ParsedData, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = field.start;
+    decode_blob_portion_at_position(data, ext_memory, &mut position)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::Header as AlloyHeader;
+    use alloy_eips::eip4895::Withdrawal as AlloyWithdrawal;
+    use alloy_primitives::{keccak256, Address, B256};
+    use alloy_rlp::{Decodable, Encodable};
+    use std::vec;
+
+    use super::*;
+    use crate::block::fixtures::{MAINNET_11117104, MAINNET_19449567};
+    use crate::block::{HeaderError, WithdrawalsError};
+    use crate::decode_whole_blob;
+    use crate::transaction::BatchError;
+
+    /// Mainnet legacy transaction
+    /// 0x280cde7cdefe4b188750e76c888f13bd05ce9a4d7767730feefe8a0e50ca6fc4.
+    const LEGACY: &str = "f9015482078b8505d21dba0083022ef1947a250d5630b4cf539739df2c5dacb4c659f2488d880c46549a521b13d8b8e47ff36ab50000000000000000000000000000000000000000000066ab5a608bd00a23f2fe000000000000000000000000000000000000000000000000000000000000008000000000000000000000000048c04ed5691981c42154c6167398f95e8f38a7ff00000000000000000000000000000000000000000000000000000000632ceac70000000000000000000000000000000000000000000000000000000000000002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20000000000000000000000006c6ee5e31d828de241282b9606c8e98ea48526e225a0c9077369501641a92ef7399ff81c21639ed4fd8fc69cb793cfa1dbfab342e10aa0615facb2f1bcf3274a354cfe384a38d0cc008a11c2dd23a69111bc6930ba27a8";
+
+    /// Mainnet EIP-1559 transaction
+    /// 0xce4dc6d7a7549a98ee3b071b67e970879ff51b5b95d1c340bacd80fa1e1aab31.
+    const EIP1559: &str = "02f86f0102843b9aca0085029e7822d68298f094d9e1459a7a482635700cbc20bbaf52d495ab9c9680841b55ba3ac080a0c199674fcb29f353693dd779c017823b954b3c69dffa3cd6b2a6ff7888798039a028ca912de909e7e6cdef9cdcaf24c54dd8c1032946dfa1d85c206b32a9064fe8";
+
+    /// Mainnet genesis block, `[header, transactions, ommers]`.
+    const GENESIS_BLOCK: &str = "f90219f90214a00000000000000000000000000000000000000000000000000000000000000000a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347940000000000000000000000000000000000000000a0d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000850400000000808213888080a011bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82faa00000000000000000000000000000000000000000000000000000000000000000880000000000000042c0c0";
+
+    fn parsed(raw: &[u8]) -> ParsedData {
+        decode_whole_blob::<&[u8], ()>(&raw, &mut ()).unwrap()
+    }
+
+    fn alloy_encoded(item: &impl Encodable) -> ParsedData {
+        let mut raw = Vec::new();
+        item.encode(&mut raw);
+        parsed(&raw)
+    }
+
+    fn alloy_header(raw: &str) -> AlloyHeader {
+        AlloyHeader::decode(&mut hex::decode(raw).unwrap().as_ref()).unwrap()
+    }
+
+    /// First withdrawal of mainnet block 19,449,567.
+    fn withdrawals() -> Vec<AlloyWithdrawal> {
+        vec![AlloyWithdrawal {
+            index: 38_633_702,
+            validator_index: 570_038,
+            address: Address::from_slice(
+                &hex::decode("7cd1122e8e118b12ece8d25480dfeef230da17ff").unwrap(),
+            ),
+            amount: 18_226_960,
+        }]
+    }
+
+    #[test]
+    fn genesis() {
+        let data = hex::decode(GENESIS_BLOCK).unwrap();
+        let block = Block::parse::<&[u8], ()>(&data.as_ref(), &mut ()).unwrap();
+        assert_eq!(block.header.number, 0);
+        assert_eq!(block.header.nonce, [0, 0, 0, 0, 0, 0, 0, 0x42]);
+        assert_eq!(
+            block.body,
+            Body {
+                transactions: Vec::new(),
+                transaction_spans: Vec::new(),
+                ommers: Vec::new(),
+                ommers_span: ItemSpan {
+                    kind: ItemKind::List,
+                    start: 539,
+                    payload_start: 540,
+                    end: 540
+                },
+                withdrawals: None,
+            }
+        );
+        assert_eq!(block.check_ommers(Fork::Frontier), Ok(()));
+        let body = Body::parse::<&[u8], ()>(&[0xc2, 0xc0, 0xc0].as_ref(), &mut ()).unwrap();
+        assert!(body.transactions.is_empty() && body.ommers().is_empty());
+        assert_eq!(body.ommers_span.start, 2);
+    }
+
+    #[test]
+    fn post_shanghai() {
+        let legacy = hex::decode(LEGACY).unwrap();
+        let typed = hex::decode(EIP1559).unwrap();
+        let body = || {
+            vec![
+                ParsedData::List(vec![parsed(&legacy), ParsedData::String(typed.clone())]),
+                ParsedData::List(Vec::new()),
+                alloy_encoded(&withdrawals()),
+            ]
+        };
+        let data = ParsedData::List(body()).to_vec();
+        let parsed_body = Body::parse::<&[u8], ()>(&data.as_ref(), &mut ()).unwrap();
+        assert_eq!(parsed_body.transactions.len(), 2);
+        assert_eq!(parsed_body.transactions[1].encode(), typed);
+        assert!(parsed_body.ommers.is_empty());
+        assert_eq!(
+            parsed_body.withdrawals.as_ref().map(|a| a[0]),
+            Some(Withdrawal {
+                index: 38_633_702,
+                validator_index: 570_038,
+                address: withdrawals()[0].address.0 .0,
+                amount_gwei: 18_226_960,
+            })
+        );
+        for (index, transaction) in parsed_body.transactions.iter().enumerate() {
+            assert_eq!(
+                parsed_body
+                    .transaction_bytes::<&[u8], ()>(&data.as_ref(), &mut (), index)
+                    .unwrap()
+                    .unwrap(),
+                transaction.encode()
+            );
+        }
+        let count = parsed_body.transactions.len();
+        assert_eq!(
+            parsed_body.transaction_bytes::<&[u8], ()>(&data.as_ref(), &mut (), count),
+            None
+        );
+
+        let header = alloy_header(MAINNET_19449567);
+        let mut fields = vec![parsed(&hex::decode(MAINNET_19449567).unwrap())];
+        fields.extend(body());
+        let data = ParsedData::List(fields).to_vec();
+        let block =
+            Block::parse_for_fork::<&[u8], ()>(&data.as_ref(), &mut (), Fork::Cancun).unwrap();
+        assert_eq!(block.header.number, 19_449_567);
+        assert_eq!(
+            block.header.withdrawals_root,
+            header.withdrawals_root.map(|a| a.0)
+        );
+        assert_eq!(block.check_ommers(Fork::Cancun), Ok(()));
+        // Spans point into the whole block now.
+        assert_eq!(
+            block
+                .body
+                .transaction_bytes::<&[u8], ()>(&data.as_ref(), &mut (), 0)
+                .unwrap()
+                .unwrap(),
+            legacy
+        );
+
+        let mut header = header;
+        header.ommers_hash = B256::repeat_byte(0x02);
+        let mut fields = vec![alloy_encoded(&header)];
+        fields.extend(body());
+        let data = ParsedData::List(fields).to_vec();
+        let block = Block::parse::<&[u8], ()>(&data.as_ref(), &mut ()).unwrap();
+        assert_eq!(
+            block.check_ommers(Fork::Cancun),
+            Err(OmmersError::UnexpectedOmmersHash)
+        );
+    }
+
+    /// Mainnet header 11,117,104 with itself as the only ommer, ommers hash
+    /// updated to match.
+    fn pre_merge_block() -> Vec<u8> {
+        let ommers_raw =
+            ParsedData::List(vec![parsed(&hex::decode(MAINNET_11117104).unwrap())]).to_vec();
+        let mut header = alloy_header(MAINNET_11117104);
+        header.ommers_hash = keccak256(&ommers_raw);
+        ParsedData::List(vec![
+            alloy_encoded(&header),
+            ParsedData::List(vec![parsed(&hex::decode(LEGACY).unwrap())]),
+            parsed(&ommers_raw),
+        ])
+        .to_vec()
+    }
+
+    #[test]
+    fn pre_merge_with_ommer() {
+        let data = pre_merge_block();
+        let block =
+            Block::parse_for_fork::<&[u8], ()>(&data.as_ref(), &mut (), Fork::Istanbul).unwrap();
+        assert_eq!(block.body.transactions.len(), 1);
+        let ommer = hex::decode(MAINNET_11117104).unwrap();
+        assert_eq!(
+            block.body.ommers(),
+            [Header::parse::<&[u8], ()>(&ommer.as_ref(), &mut ()).unwrap()]
+        );
+        assert_eq!(block.body.ommers()[0].number, 11_117_104);
+        assert_eq!(block.body.ommers()[0].encode(), ommer);
+        assert_eq!(block.check_ommers(Fork::Istanbul), Ok(()));
+        assert_eq!(
+            block.check_ommers(Fork::Paris),
+            Err(OmmersError::OmmersAfterMerge { count: 1 })
+        );
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn ommers_hash() {
+        use crate::keccak::keccak256;
+
+        assert_eq!(keccak256(&[0xc0]), EMPTY_OMMERS_HASH);
+        let data = pre_merge_block();
+        let mut block = Block::parse::<&[u8], ()>(&data.as_ref(), &mut ()).unwrap();
+        assert_eq!(
+            block.ommers_hash_matches::<&[u8], ()>(&data.as_ref(), &mut ()),
+            Ok(true)
+        );
+        block.header.ommers_hash = EMPTY_OMMERS_HASH;
+        assert_eq!(
+            block.ommers_hash_matches::<&[u8], ()>(&data.as_ref(), &mut ()),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn fork_field_count() {
+        let data = pre_merge_block();
+        assert!(matches!(
+            Block::parse_for_fork::<&[u8], ()>(&data.as_ref(), &mut (), Fork::Shanghai),
+            Err(BlockError::Header(HeaderError::WrongFieldCount {
+                fork: Fork::Shanghai,
+                expected: 17,
+                found: 15
+            }))
+        ));
+        assert_eq!(
+            Body::parse_for_fork::<&[u8], ()>(
+                &[0xc2, 0xc0, 0xc0].as_ref(),
+                &mut (),
+                Fork::Shanghai
+            ),
+            Err(BodyError::WrongFieldCount {
+                fork: Fork::Shanghai,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Body::parse_for_fork::<&[u8], ()>(
+                &[0xc3, 0xc0, 0xc0, 0xc0].as_ref(),
+                &mut (),
+                Fork::Paris
+            ),
+            Err(BodyError::WrongFieldCount {
+                fork: Fork::Paris,
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        // Body with a withdrawals list, header without withdrawals root.
+        let data = ParsedData::List(vec![
+            parsed(&hex::decode(MAINNET_11117104).unwrap()),
+            ParsedData::List(Vec::new()),
+            ParsedData::List(Vec::new()),
+            ParsedData::List(Vec::new()),
+        ])
+        .to_vec();
+        assert_eq!(
+            Block::parse::<&[u8], ()>(&data.as_ref(), &mut ()),
+            Err(BlockError::WithdrawalsMismatch)
+        );
+
+        let data = ParsedData::List(vec![
+            parsed(&hex::decode(MAINNET_11117104).unwrap()),
+            ParsedData::List(Vec::new()),
+        ])
+        .to_vec();
+        assert_eq!(
+            Block::parse::<&[u8], ()>(&data.as_ref(), &mut ()),
+            Err(BlockError::InvalidFieldCount { found: 2 })
+        );
+
+        let cases: [(&[u8], BodyError<()>); 6] = [
+            (&[0x80], BodyError::NotAList),
+            (&[0xc1, 0xc0], BodyError::InvalidFieldCount { found: 1 }),
+            (&[0xc2, 0xc0, 0x80], BodyError::OmmersNotAList),
+            (
+                &[0xc3, 0xc0, 0xc0, 0x01],
+                BodyError::Withdrawals(WithdrawalsError::NotAList),
+            ),
+            (
+                &[0xc3, 0xc0, 0xc1, 0xc0],
+                BodyError::InvalidOmmer {
+                    index: 0,
+                    source: HeaderError::InvalidFieldCount { found: 0 },
+                },
+            ),
+            (
+                &[0xc2, 0x80, 0xc0],
+                BodyError::Transactions(BatchError::Decoding(Error::ExpectedList { position: 1 })),
+            ),
+        ];
+        for (data, error) in cases {
+            assert_eq!(Body::parse::<&[u8], ()>(&data, &mut ()), Err(error));
+        }
+        assert_eq!(
+            Body::parse::<&[u8], ()>(&[0xc2, 0xc0, 0xc0, 0x00].as_ref(), &mut ()),
+            Err(BodyError::Decoding(Error::SomeDataUnused { from: 3 }))
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! Clique proof of authority header extra data, EIP-225.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::block::Header;
+use crate::transaction::{Signature, SignatureError};
+
+/// Length of the vanity prefix of extra data.
+pub const CLIQUE_VANITY_LEN: usize = 32;
+
+/// Length of the seal suffix of extra data, `r || s || v`.
+pub const CLIQUE_SEAL_LEN: usize = 65;
+
+/// Extra data of a Clique header, `vanity || signers || seal`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CliqueExtra {
+    pub vanity: [u8; 32],
+    /// Authorized signers, only in epoch transition blocks.
+    pub signers: Vec<[u8; 20]>,
+    pub seal: Signature,
+}
+
+/// Errors in parsing Clique extra data.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CliqueError {
+    /// Seal is not a valid signature with recovery id 0 or 1.
+    InvalidSeal(SignatureError),
+    /// Signers section is not a whole number of addresses.
+    InvalidSignersLength { length: usize },
+    /// Signers found outside of an epoch transition block.
+    SignersNotInEpoch { length: usize },
+    /// Extra data too short for vanity and seal.
+    TooShort { length: usize },
+}
+
+impl CliqueExtra {
+    /// Split extra data into vanity, signers and seal. Signers are expected
+    /// only if `is_epoch` is set, i.e. for block numbers divisible by the
+    /// epoch length.
+    pub fn parse(extra_data: &[u8], is_epoch: bool) -> Result<Self, CliqueError> {
+        let length = extra_data.len();
+        if length < CLIQUE_VANITY_LEN + CLIQUE_SEAL_LEN {
+            return Err(CliqueError::TooShort { length });
+        }
+        let (vanity, rest) = extra_data.split_at(CLIQUE_VANITY_LEN);
+        let (signers, seal) = rest.split_at(rest.len() - CLIQUE_SEAL_LEN);
+        if !is_epoch && !signers.is_empty() {
+            return Err(CliqueError::SignersNotInEpoch {
+                length: signers.len(),
+            });
+        }
+        if signers.len() % 20 != 0 {
+            return Err(CliqueError::InvalidSignersLength {
+                length: signers.len(),
+            });
+        }
+        let seal = Signature::from_typed(seal[64] as u64, &seal[..32], &seal[32..64])
+            .map_err(CliqueError::InvalidSeal)?;
+        let mut out = Self {
+            vanity: [0; 32],
+            signers: Vec::with_capacity(signers.len() / 20),
+            seal,
+        };
+        out.vanity.copy_from_slice(vanity);
+        for chunk in signers.chunks_exact(20) {
+            let mut signer = [0; 20];
+            signer.copy_from_slice(chunk);
+            out.signers.push(signer);
+        }
+        Ok(out)
+    }
+}
+
+/// Encoded header with the seal removed from extra data, the payload
+/// whose Keccak-256 hash the signer signs.
+pub fn seal_hash_payload(header: &Header) -> Result<Vec<u8>, CliqueError> {
+    let length = header.extra_data.len();
+    if length < CLIQUE_SEAL_LEN {
+        return Err(CliqueError::TooShort { length });
+    }
+    let mut unsealed = header.clone();
+    unsealed.extra_data.truncate(length - CLIQUE_SEAL_LEN);
+    Ok(unsealed.encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::Header as AlloyHeader;
+    use alloy_primitives::{Address, Bytes, B256, U256};
+    use alloy_rlp::Encodable;
+    use std::vec;
+
+    use super::*;
+    use crate::block::fixtures::GOERLI_4409378;
+
+    /// Goerli genesis header, hash
+    /// 0xbf7e331f7f7c1dd2e05159666b3bf8bc7a8a3a9eb1d518969eab529dd9b88c1a.
+    const GOERLI_GENESIS: &str = "f9026aa00000000000000000000000000000000000000000000000000000000000000000a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347940000000000000000000000000000000000000000a05d6cded585e73c4e322c30c2f782a336316f17dd85a4863b9d838d2d4b8b3008a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000018083a0000080845c51a607b87522466c6578692069732061207468696e6722202d204166726900000000000000e0a2bd4258d2768837baa26a28fe71dc079f84c70000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000000880000000000000000";
+
+    /// Vanity of the Goerli genesis extra data.
+    const VANITY: [u8; 32] = *b"\"Flexi is a thing\" - Afri\0\0\0\0\0\0\0";
+
+    /// The only authorized signer in the Goerli genesis.
+    const GOERLI_SIGNER: [u8; 20] = [
+        0xe0, 0xa2, 0xbd, 0x42, 0x58, 0xd2, 0x76, 0x88, 0x37, 0xba, 0xa2, 0x6a, 0x28, 0xfe, 0x71,
+        0xdc, 0x07, 0x9f, 0x84, 0xc7,
+    ];
+
+    fn goerli(raw: &str) -> Header {
+        let raw = hex::decode(raw).unwrap();
+        Header::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+    }
+
+    fn goerli_genesis() -> Header {
+        goerli(GOERLI_GENESIS)
+    }
+
+    fn seal(v: u8) -> Vec<u8> {
+        let mut seal = vec![0x11; 32];
+        seal.extend([0x22; 32]);
+        seal.push(v);
+        seal
+    }
+
+    fn extra_data(signers: &[[u8; 20]], v: u8) -> Vec<u8> {
+        let mut extra_data = VANITY.to_vec();
+        signers.iter().for_each(|a| extra_data.extend(a));
+        extra_data.extend(seal(v));
+        extra_data
+    }
+
+    #[test]
+    fn epoch_and_regular() {
+        // Genesis is an epoch transition block with an empty seal.
+        let genesis = goerli_genesis();
+        assert_eq!(
+            CliqueExtra::parse(&genesis.extra_data, true),
+            Ok(CliqueExtra {
+                vanity: VANITY,
+                signers: vec![GOERLI_SIGNER],
+                seal: Signature {
+                    r: [0; 32],
+                    s: [0; 32],
+                    y_parity: 0,
+                },
+            })
+        );
+        assert_eq!(
+            CliqueExtra::parse(&genesis.extra_data, false),
+            Err(CliqueError::SignersNotInEpoch { length: 20 })
+        );
+
+        // Regular block with vanity and seal only.
+        let regular = goerli(GOERLI_4409378);
+        let extra = CliqueExtra::parse(&regular.extra_data, false).unwrap();
+        assert_eq!(&extra.vanity, b"Nethermind 1.9.22-0-dc76facfa-20");
+        assert_eq!(extra.signers, Vec::<[u8; 20]>::new());
+        assert_eq!(extra.seal.y_parity, 0);
+        assert_eq!(extra.seal.r[..4], [0x86, 0x39, 0xad, 0x8f]);
+        assert_eq!(CliqueExtra::parse(&regular.extra_data, true), Ok(extra));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            CliqueExtra::parse(&[0; 96], false),
+            Err(CliqueError::TooShort { length: 96 })
+        );
+        assert_eq!(
+            CliqueExtra::parse(&extra_data(&[[0x01; 20]], 0), false),
+            Err(CliqueError::SignersNotInEpoch { length: 20 })
+        );
+        let mut odd = extra_data(&[[0x01; 20]], 0);
+        odd.insert(40, 0x00);
+        assert_eq!(
+            CliqueExtra::parse(&odd, true),
+            Err(CliqueError::InvalidSignersLength { length: 21 })
+        );
+        assert_eq!(
+            CliqueExtra::parse(&extra_data(&[], 27), false),
+            Err(CliqueError::InvalidSeal(SignatureError::InvalidYParity(27)))
+        );
+    }
+
+    #[test]
+    fn seal_hash() {
+        let signers = [[0x01; 20]];
+        let sealed = AlloyHeader {
+            parent_hash: B256::repeat_byte(0x01),
+            beneficiary: Address::ZERO,
+            difficulty: U256::from(2u64),
+            number: 30_000,
+            gas_limit: 30_000_000,
+            timestamp: 1_600_000_000,
+            extra_data: Bytes::from(extra_data(&signers, 0)),
+            base_fee_per_gas: Some(7),
+            ..Default::default()
+        };
+        let mut raw = Vec::new();
+        sealed.encode(&mut raw);
+        let header = Header::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+
+        let mut unsealed = sealed.clone();
+        unsealed.extra_data = Bytes::from(extra_data(&signers, 0)[..52].to_vec());
+        let mut expected = Vec::new();
+        unsealed.encode(&mut expected);
+        assert_eq!(seal_hash_payload(&header), Ok(expected));
+
+        let mut short = header;
+        short.extra_data = vec![0; 64];
+        assert_eq!(
+            seal_hash_payload(&short),
+            Err(CliqueError::TooShort { length: 64 })
+        );
+    }
+
+    /// Header on top of the Goerli genesis sealed by `key`. The seal signs
+    /// the hash alloy computes for the header without the seal, so it does
+    /// not depend on `seal_hash_payload`.
+    #[cfg(feature = "recover")]
+    fn sealed(
+        number: u64,
+        signers: &[[u8; 20]],
+        key: &alloy_signer_local::PrivateKeySigner,
+    ) -> (Header, B256) {
+        use alloy_rlp::Decodable;
+        use alloy_signer::SignerSync;
+
+        let raw = hex::decode(GOERLI_GENESIS).unwrap();
+        let genesis = AlloyHeader::decode(&mut raw.as_ref()).unwrap();
+        let mut extra_data = VANITY.to_vec();
+        signers.iter().for_each(|a| extra_data.extend(a));
+        let unsealed = AlloyHeader {
+            parent_hash: genesis.hash_slow(),
+            number,
+            timestamp: genesis.timestamp + 15 * number,
+            difficulty: U256::from(2u64),
+            extra_data: Bytes::from(extra_data.clone()),
+            ..genesis
+        };
+        let seal_hash = unsealed.hash_slow();
+
+        let signature = key.sign_hash_sync(&seal_hash).unwrap();
+        // Clique seal ends with recovery id 0 or 1.
+        extra_data.extend(signature.r().to_be_bytes::<32>());
+        extra_data.extend(signature.s().to_be_bytes::<32>());
+        extra_data.push(signature.v() as u8);
+        let mut raw = Vec::new();
+        AlloyHeader {
+            extra_data: Bytes::from(extra_data),
+            ..unsealed
+        }
+        .encode(&mut raw);
+        let header = Header::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        (header, seal_hash)
+    }
+
+    #[cfg(feature = "recover")]
+    #[test]
+    fn recover_signer() {
+        use alloy_signer_local::PrivateKeySigner;
+
+        use crate::keccak::keccak256;
+        use crate::recover::recover_address;
+
+        let regular = goerli(GOERLI_4409378);
+        assert_eq!(
+            hex::encode(regular.hash()),
+            "a4856602944fdfd18c528ef93cc52a681b38d766a7e39c27a47488c8461adcb0"
+        );
+        let seal = CliqueExtra::parse(&regular.extra_data, false).unwrap().seal;
+        let prehash = keccak256(&seal_hash_payload(&regular).unwrap());
+        assert_eq!(
+            recover_address(&prehash, &seal.r, &seal.s, seal.y_parity).map(hex::encode),
+            Ok("000000568b9b5a365eaa767d42e74ed88915c204".into())
+        );
+
+        assert_eq!(
+            hex::encode(goerli_genesis().hash()),
+            "bf7e331f7f7c1dd2e05159666b3bf8bc7a8a3a9eb1d518969eab529dd9b88c1a"
+        );
+        let key = PrivateKeySigner::from_bytes(&B256::repeat_byte(0x46)).unwrap();
+        let signer = key.address().0 .0;
+        let other = [0x01; 20];
+
+        // Regular block and epoch transition block with two signers, sealed
+        // by a test key.
+        for (number, signers) in [(1, vec![]), (30_000, vec![signer, other])] {
+            let (header, seal_hash) = sealed(number, &signers, &key);
+            let prehash = keccak256(&seal_hash_payload(&header).unwrap());
+            assert_eq!(prehash, seal_hash.0);
+
+            let extra = CliqueExtra::parse(&header.extra_data, !signers.is_empty()).unwrap();
+            assert_eq!(extra.vanity, VANITY);
+            assert_eq!(extra.signers, signers);
+            assert_eq!(
+                recover_address(&prehash, &extra.seal.r, &extra.seal.s, extra.seal.y_parity),
+                Ok(signer)
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use external_memory_tools::ExternalMemory;
+
+use crate::field::FieldError;
+use crate::fork::Fork;
+use crate::transaction::BatchError;
+use crate::Error;
+
+/// Header fields, used to locate errors.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HeaderField {
+    BaseFeePerGas,
+    Beneficiary,
+    BlobGasUsed,
+    Difficulty,
+    ExcessBlobGas,
+    ExtraData,
+    GasLimit,
+    GasUsed,
+    LogsBloom,
+    MixHash,
+    Nonce,
+    Number,
+    OmmersHash,
+    ParentBeaconBlockRoot,
+    ParentHash,
+    ReceiptsRoot,
+    RequestsHash,
+    StateRoot,
+    Timestamp,
+    TransactionsRoot,
+    WithdrawalsRoot,
+}
+
+/// Errors in parsing block headers.
+#[derive(Debug, Eq, PartialEq)]
+pub enum HeaderError<E: ExternalMemory> {
+    Decoding(Error<E>),
+    InvalidField {
+        field: HeaderField,
+        reason: FieldError,
+    },
+    /// Number of fields matches no fork, see [`Header::FIELD_COUNTS`].
+    ///
+    /// [`Header::FIELD_COUNTS`]: crate::block::Header::FIELD_COUNTS
+    InvalidFieldCount {
+        found: usize,
+    },
+    NotAList,
+    /// Number of fields does not match the expected `fork`.
+    WrongFieldCount {
+        fork: Fork,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<E: ExternalMemory> From<Error<E>> for HeaderError<E> {
+    fn from(error: Error<E>) -> Self {
+        HeaderError::Decoding(error)
+    }
+}
+
+/// Errors in parsing block bodies.
+#[derive(Debug, Eq, PartialEq)]
+pub enum BodyError<E: ExternalMemory> {
+    Decoding(Error<E>),
+    /// Body has neither 2 nor 3 fields.
+    InvalidFieldCount {
+        found: usize,
+    },
+    InvalidOmmer {
+        index: usize,
+        source: HeaderError<E>,
+    },
+    NotAList,
+    OmmersNotAList,
+    Transactions(BatchError<E>),
+    Withdrawals(WithdrawalsError),
+    /// Number of fields does not match the expected `fork`.
+    WrongFieldCount {
+        fork: Fork,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<E: ExternalMemory> From<Error<E>> for BodyError<E> {
+    fn from(error: Error<E>) -> Self {
+        BodyError::Decoding(error)
+    }
+}
+
+/// Errors in parsing blocks.
+#[derive(Debug, Eq, PartialEq)]
+pub enum BlockError<E: ExternalMemory> {
+    Body(BodyError<E>),
+    Decoding(Error<E>),
+    Header(HeaderError<E>),
+    /// Block has neither 3 nor 4 fields.
+    InvalidFieldCount {
+        found: usize,
+    },
+    NotAList,
+    /// Withdrawals are present in only one of the header and the body.
+    WithdrawalsMismatch,
+}
+
+impl<E: ExternalMemory> From<Error<E>> for BlockError<E> {
+    fn from(error: Error<E>) -> Self {
+        BlockError::Decoding(error)
+    }
+}
+
+/// Errors in parsing devp2p `NewBlock` messages.
+#[derive(Debug, Eq, PartialEq)]
+pub enum NewBlockError<E: ExternalMemory> {
+    /// Block header or body is invalid, see [`BlockError::Header`] and
+    /// [`BlockError::Body`].
+    Block(BlockError<E>),
+    Decoding(Error<E>),
+    /// Keccak-256 hash of the header is not the expected block hash.
+    #[cfg(feature = "keccak")]
+    HashMismatch {
+        expected: [u8; 32],
+        found: [u8; 32],
+    },
+    /// Message has other than 2 fields.
+    InvalidFieldCount {
+        found: usize,
+    },
+    NotAList,
+    TotalDifficulty(FieldError),
+}
+
+impl<E: ExternalMemory> From<Error<E>> for NewBlockError<E> {
+    fn from(error: Error<E>) -> Self {
+        NewBlockError::Decoding(error)
+    }
+}
+
+/// Errors in parsing a single withdrawal.
+#[derive(Debug, Eq, PartialEq)]
+pub enum WithdrawalError {
+    InvalidAddress(FieldError),
+    InvalidAmount(FieldError),
+    InvalidIndex(FieldError),
+    InvalidValidatorIndex(FieldError),
+    NotAList,
+    WrongFieldCount { expected: usize, found: usize },
+}
+
+/// Errors in parsing a list of withdrawals.
+#[derive(Debug, Eq, PartialEq)]
+pub enum WithdrawalsError {
+    InvalidWithdrawal {
+        index: usize,
+        source: WithdrawalError,
+    },
+    NotAList,
+}
+
+/// Errors in checking ommers of a block after the merge.
+#[derive(Debug, Eq, PartialEq)]
+pub enum OmmersError {
+    OmmersAfterMerge {
+        count: usize,
+    },
+    /// Header ommers hash is not [`EMPTY_OMMERS_HASH`](crate::block::EMPTY_OMMERS_HASH).
+    UnexpectedOmmersHash,
+}
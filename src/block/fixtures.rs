@@ -0,0 +1,14 @@
+//! Block headers from public networks for tests.
+
+/// Header of mainnet block 11,117,104, before London, hash
+/// 0xb25d0e54ca0104e3ebfb5a1dcdf9528140854d609886a300946fd6750dcb19f4.
+pub(crate) const MAINNET_11117104: &str = "f90217a09400ec9ef59689c157ac89eeed906f15ddd768f94e1575e0e27d37c241439a5da01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794829bd824b016326a401d083b33d092293333a830a0546e330050c66d02923e7f1f3e925efaf64e4384eeecf2288f40088714a77a84a0d5eb3ad6d7c7a4798cc5fb14a6820073f44a941107c5d79dac60bd16325631fea0b21c41cbb3439c5af25304e1405524c885e733b16203221900cb7f4b387b62f0b901001f304e641097eafae088627298685d20202004a4a59e4d8900914724e2402b028c9d596660581f361240816e82d00fa14250c9ca89840887a381efa600288283d170010ab0b2a0694c81842c2482457e0eb77c2c02554614007f42aaf3b4dc15d006a83522c86a240c06d241013258d90540c3008888d576a02c10120808520a2221110f4805200302624d22092b2c0e94e849b1e1aa80bc4cc3206f00b249d0a603ee4310216850e47c8997a20aa81fe95040a49ca5a420464600e008351d161dc00d620970b6a801535c218d0b4116099292000c08001943a225d6485528828110645b8244625a182c1a88a41087e6d039b000a180d04300d0680700a15794870c40faff9c737d83a9a23083be5a6683be0fcc845f93b749967070796520e4b883e5bda9e7a59ee4bb99e9b1bc0103a0d5e2b7b71fbe4ddfe552fb2377bf7cddb16bbb7e185806036cee86994c6e97fc884722f2acd35abe0f";
+
+/// Header of mainnet block 19,449,567, since Cancun, hash
+/// 0x85cdcbe36217fd57bf2c33731d8460657a7ce512401f49c9f6392c82a7ccf7ac.
+pub(crate) const MAINNET_19449567: &str = "f90255a090926e0298d418181bd20c23b332451e35fd7d696b5dcdc5a3a0a6b715f4c717a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d493479495222290dd7278aa3ddd389cc1e1d165cc4bafe5a0707875120a7103621fb4131df59904cda39de948dfda9084a1e3da44594d5404a0889a1c26dc42ba829dab552b779620feac231cde8a6c79af022bdc605c23a780a0d43aa19ecb03571d1b86d89d9bb980139d32f2f2ba59646cd5c1de9e80c68c90b90100c36919406572730518285284f2293101104140c0d42c4a786c892467868a8806f40159d29988002870403902413a1d04321320308da2e845438429e0012a00b419d8ccc8584a1c28f82a415d04eab8a5ae75c00d07761acf233414c08b6d9b571c06156086c70ea5186e9b989b0c2d55c0213c936805cd2ab331589c90194d070c00867549b1e1be14cb24500b0386cd901197c1ef5a00da453234fa48f3003dcaa894e3111c22b80e17f7d4388385a10720cda1140c0400f9e084ca34fc4870fb16b472340a2a6a63115a82522f506c06c2675080508834828c63defd06bc2331b4aa708906a06a560457b114248041e40179ebc05c6846c1e922125982f42780840128c6df8401c9c38083b0033c8465f5f4c38f6265617665726275696c642e6f7267a04c068e902990f21f92a2456fc75c59bec8be03b7f13682b6ebd27da56269beb5880000000000000000850886b221ada0360c33f20eeed5efbc7d08be46e58f8440af5db503e40908ef3d1eb314856ef78080a02843cb9f7d001bd58816a915e685ed96a555c9aeec1217736bd83a96ebd409cc";
+
+/// Header of Goerli block 4,409,378, a regular Clique block sealed by
+/// 0x000000568b9b5a365eaa767d42e74ed88915c204, hash
+/// 0xa4856602944fdfd18c528ef93cc52a681b38d766a7e39c27a47488c8461adcb0.
+pub(crate) const GOERLI_4409378: &str = "f90259a01a9bdc31fc785f8a95efeeb7ae58f40f6366b8e805f47447a52335c95f4ceb49a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347940000000000000000000000000000000000000000a0f38c4bf2958e541ec6df148e54ce073dc6b610f8613147ede568cb7b5c2d81eea056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b90100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000183434822837a12008084604726b0b8614e65746865726d696e6420312e392e32322d302d6463373666616366612d32308639ad8ff3d850a261f3b26bc2a55e0f3a718de0dd040a19a4ce37e7b473f2d7481448a1e1fd8fb69260825377c0478393e6055f471a5cf839467ce919a6ad2700a00000000000000000000000000000000000000000000000000000000000000000880000000000000000";
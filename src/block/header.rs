@@ -0,0 +1,763 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use core::cmp::Ordering;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::block::{HeaderError, HeaderField};
+use crate::encode::leaf;
+use crate::field::FieldError;
+use crate::fork::Fork;
+#[cfg(feature = "keccak")]
+use crate::keccak::keccak256;
+#[cfg(feature = "keccak")]
+use crate::span::{span_at_position, ItemKind};
+use crate::uint::{from_u64, mul_div_floor, saturating_add, saturating_sub};
+#[cfg(feature = "keccak")]
+use crate::Error;
+use crate::{decode_whole_blob, ParsedData};
+
+/// `keccak256(rlp([]))`, ommers hash of blocks without ommers, all blocks
+/// since Paris included.
+pub const EMPTY_OMMERS_HASH: [u8; 32] = [
+    0x1d, 0xcc, 0x4d, 0xe8, 0xde, 0xc7, 0x5d, 0x7a, 0xab, 0x85, 0xb5, 0x67, 0xb6, 0xcc, 0xd4, 0x1a,
+    0xd3, 0x12, 0x45, 0x1b, 0x94, 0x8a, 0x74, 0x13, 0xf0, 0xa1, 0x42, 0xfd, 0x40, 0xd4, 0x93, 0x47,
+];
+
+/// Ratio of the gas limit to the gas target, EIP-1559.
+pub const BASE_FEE_ELASTICITY_MULTIPLIER: u64 = 2;
+
+/// Base fee changes by at most `1 / BASE_FEE_MAX_CHANGE_DENOMINATOR` per
+/// block, EIP-1559.
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+/// Block header. Fields added by later forks are `None` in headers of
+/// earlier blocks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Header {
+    pub parent_hash: [u8; 32],
+    pub ommers_hash: [u8; 32],
+    pub beneficiary: [u8; 20],
+    pub state_root: [u8; 32],
+    pub transactions_root: [u8; 32],
+    pub receipts_root: [u8; 32],
+    pub logs_bloom: [u8; 256],
+    pub difficulty: [u8; 32],
+    pub number: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+    pub extra_data: Vec<u8>,
+    /// Proof of work mix hash, `prevRandao` since Paris.
+    pub mix_hash: [u8; 32],
+    pub nonce: [u8; 8],
+    /// Since London.
+    pub base_fee_per_gas: Option<[u8; 32]>,
+    /// Since Shanghai.
+    pub withdrawals_root: Option<[u8; 32]>,
+    /// Since Cancun.
+    pub blob_gas_used: Option<u64>,
+    /// Since Cancun.
+    pub excess_blob_gas: Option<u64>,
+    /// Since Cancun.
+    pub parent_beacon_block_root: Option<[u8; 32]>,
+    /// Since Prague.
+    pub requests_hash: Option<[u8; 32]>,
+}
+
+impl Header {
+    /// Numbers of header fields before London, before Shanghai, before
+    /// Cancun, before Prague and since Prague.
+    pub const FIELD_COUNTS: [usize; 5] = [15, 16, 17, 20, 21];
+
+    /// Number of header fields under `fork` rules.
+    pub fn field_count(fork: Fork) -> usize {
+        match fork {
+            a if a >= Fork::Prague => 21,
+            a if a >= Fork::Cancun => 20,
+            a if a >= Fork::Shanghai => 17,
+            a if a >= Fork::London => 16,
+            _ => 15,
+        }
+    }
+
+    /// Parse header `rlp([...])` of any fork, fields present are detected
+    /// from their number.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, HeaderError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::from_parsed(&decode_whole_blob(data, ext_memory)?, None)
+    }
+
+    /// Parse header `rlp([...])` with exactly the fields of `fork`.
+    pub fn parse_for_fork<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fork: Fork,
+    ) -> Result<Self, HeaderError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::from_parsed(&decode_whole_blob(data, ext_memory)?, Some(fork))
+    }
+
+    /// Fields present are detected if `fork` is `None`.
+    pub(crate) fn from_parsed<E: ExternalMemory>(
+        parsed_data: &ParsedData,
+        fork: Option<Fork>,
+    ) -> Result<Self, HeaderError<E>> {
+        let fields = parsed_data.as_list().map_err(|_| HeaderError::NotAList)?;
+        match fork {
+            Some(fork) if fields.len() != Self::field_count(fork) => {
+                return Err(HeaderError::WrongFieldCount {
+                    fork,
+                    expected: Self::field_count(fork),
+                    found: fields.len(),
+                });
+            }
+            None if !Self::FIELD_COUNTS.contains(&fields.len()) => {
+                return Err(HeaderError::InvalidFieldCount {
+                    found: fields.len(),
+                });
+            }
+            _ => {}
+        }
+        let hash =
+            |index: usize, field| fields[index].as_fixed_bytes::<32>().map_err(invalid(field));
+        let scalar = |index: usize, field| fields[index].as_strict_u64().map_err(invalid(field));
+        let optional_hash =
+            |index: usize, field| fields.get(index).map(|_| hash(index, field)).transpose();
+        let optional_scalar =
+            |index: usize, field| fields.get(index).map(|_| scalar(index, field)).transpose();
+        Ok(Self {
+            parent_hash: hash(0, HeaderField::ParentHash)?,
+            ommers_hash: hash(1, HeaderField::OmmersHash)?,
+            beneficiary: fields[2]
+                .as_fixed_bytes::<20>()
+                .map_err(invalid(HeaderField::Beneficiary))?,
+            state_root: hash(3, HeaderField::StateRoot)?,
+            transactions_root: hash(4, HeaderField::TransactionsRoot)?,
+            receipts_root: hash(5, HeaderField::ReceiptsRoot)?,
+            logs_bloom: fields[6]
+                .as_fixed_bytes::<256>()
+                .map_err(invalid(HeaderField::LogsBloom))?,
+            difficulty: fields[7]
+                .as_strict_u256()
+                .map_err(invalid(HeaderField::Difficulty))?,
+            number: scalar(8, HeaderField::Number)?,
+            gas_limit: scalar(9, HeaderField::GasLimit)?,
+            gas_used: scalar(10, HeaderField::GasUsed)?,
+            timestamp: scalar(11, HeaderField::Timestamp)?,
+            extra_data: fields[12]
+                .to_bytes_vec()
+                .map_err(invalid(HeaderField::ExtraData))?,
+            mix_hash: hash(13, HeaderField::MixHash)?,
+            nonce: fields[14]
+                .as_fixed_bytes::<8>()
+                .map_err(invalid(HeaderField::Nonce))?,
+            base_fee_per_gas: fields
+                .get(15)
+                .map(|a| a.as_strict_u256())
+                .transpose()
+                .map_err(invalid(HeaderField::BaseFeePerGas))?,
+            withdrawals_root: optional_hash(16, HeaderField::WithdrawalsRoot)?,
+            blob_gas_used: optional_scalar(17, HeaderField::BlobGasUsed)?,
+            excess_blob_gas: optional_scalar(18, HeaderField::ExcessBlobGas)?,
+            parent_beacon_block_root: optional_hash(19, HeaderField::ParentBeaconBlockRoot)?,
+            requests_hash: optional_hash(20, HeaderField::RequestsHash)?,
+        })
+    }
+
+    /// Header fields as they are encoded, optional fields included up to
+    /// the last one present.
+    pub fn to_parsed(&self) -> ParsedData {
+        let hash = |hash: &[u8; 32]| ParsedData::String(hash.to_vec());
+        let mut fields = Vec::from([
+            hash(&self.parent_hash),
+            hash(&self.ommers_hash),
+            ParsedData::String(self.beneficiary.to_vec()),
+            hash(&self.state_root),
+            hash(&self.transactions_root),
+            hash(&self.receipts_root),
+            ParsedData::String(self.logs_bloom.to_vec()),
+            ParsedData::from_u256_be(self.difficulty),
+            ParsedData::from_u64(self.number),
+            ParsedData::from_u64(self.gas_limit),
+            ParsedData::from_u64(self.gas_used),
+            ParsedData::from_u64(self.timestamp),
+            leaf(self.extra_data.clone()),
+            hash(&self.mix_hash),
+            ParsedData::String(self.nonce.to_vec()),
+        ]);
+        let optional = [
+            self.base_fee_per_gas.map(ParsedData::from_u256_be),
+            self.withdrawals_root.as_ref().map(hash),
+            self.blob_gas_used.map(ParsedData::from_u64),
+            self.excess_blob_gas.map(ParsedData::from_u64),
+            self.parent_beacon_block_root.as_ref().map(hash),
+            self.requests_hash.as_ref().map(hash),
+        ];
+        fields.extend(optional.into_iter().map_while(|a| a));
+        ParsedData::List(fields)
+    }
+
+    /// Canonical encoding.
+    pub fn encode(&self) -> Vec<u8> {
+        self.to_parsed().to_vec()
+    }
+
+    /// Block hash, Keccak-256 of the canonical encoding.
+    ///
+    /// Use [`header_hash_from_raw`] if the encoded header is at hand.
+    #[cfg(feature = "keccak")]
+    pub fn hash(&self) -> [u8; 32] {
+        keccak256(&self.encode())
+    }
+
+    /// Proof of work mix hash, see [`prev_randao`](Self::prev_randao) for
+    /// blocks since Paris.
+    pub fn mix_hash(&self) -> [u8; 32] {
+        self.mix_hash
+    }
+
+    /// Proof of work nonce, a fixed 8-byte string rather than a scalar.
+    pub fn nonce(&self) -> [u8; 8] {
+        self.nonce
+    }
+
+    /// Big-endian difficulty.
+    pub fn difficulty(&self) -> [u8; 32] {
+        self.difficulty
+    }
+
+    /// Zero difficulty and nonce, as required since Paris (EIP-3675).
+    pub fn is_post_merge_shaped(&self) -> bool {
+        self.difficulty == [0; 32] && self.nonce == [0; 8]
+    }
+
+    /// Beacon chain randomness stored in the mix hash field, for headers
+    /// that are [post-merge shaped](Self::is_post_merge_shaped).
+    pub fn prev_randao(&self) -> Option<[u8; 32]> {
+        self.is_post_merge_shaped().then_some(self.mix_hash)
+    }
+
+    /// Base fee, `None` before London.
+    pub fn base_fee_per_gas(&self) -> Option<[u8; 32]> {
+        self.base_fee_per_gas
+    }
+
+    /// Base fee of the child block, EIP-1559.
+    ///
+    /// `None` before London, and for gas limits below
+    /// [`BASE_FEE_ELASTICITY_MULTIPLIER`]. Divisions are done in the order of
+    /// the specification, each rounding down.
+    pub fn next_base_fee(&self) -> Option<[u8; 32]> {
+        let base_fee = self.base_fee_per_gas?;
+        let gas_target = self.gas_limit / BASE_FEE_ELASTICITY_MULTIPLIER;
+        if gas_target == 0 {
+            return None;
+        }
+        let delta = |gas_delta| {
+            let scaled = mul_div_floor(&base_fee, gas_delta, gas_target);
+            mul_div_floor(&scaled, 1, BASE_FEE_MAX_CHANGE_DENOMINATOR)
+        };
+        Some(match self.gas_used.cmp(&gas_target) {
+            Ordering::Equal => base_fee,
+            Ordering::Greater => {
+                let increase = delta(self.gas_used - gas_target).max(from_u64(1));
+                saturating_add(&base_fee, &increase)
+            }
+            Ordering::Less => saturating_sub(&base_fee, &delta(gas_target - self.gas_used)),
+        })
+    }
+}
+
+/// Block hash of header encoded in `data`, hashed as is.
+///
+/// Only the outer prefix is checked, the header is not decoded.
+#[cfg(feature = "keccak")]
+pub fn header_hash_from_raw<B, E>(data: &B, ext_memory: &mut E) -> Result<[u8; 32], HeaderError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = 0;
+    let span = span_at_position(data, ext_memory, &mut position)?;
+    if position < data.total_len() {
+        return Err(Error::SomeDataUnused { from: position }.into());
+    }
+    if span.kind != ItemKind::List {
+        return Err(HeaderError::NotAList);
+    }
+    Ok(keccak256(span.read_raw(data, ext_memory)?.as_ref()))
+}
+
+fn invalid<E: ExternalMemory>(field: HeaderField) -> impl Fn(FieldError) -> HeaderError<E> {
+    move |reason| HeaderError::InvalidField { field, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::Header as AlloyHeader;
+    use alloy_eips::eip1559::{calc_next_block_base_fee, BaseFeeParams};
+    use alloy_primitives::{Bytes, B256, B64, U256};
+    use alloy_rlp::{Decodable, Encodable};
+    use std::vec;
+
+    use super::*;
+    use crate::block::fixtures::{GOERLI_4409378, MAINNET_11117104, MAINNET_19449567};
+    use crate::encode::scalar;
+
+    fn hash(hex_hash: &str) -> [u8; 32] {
+        hex::decode(hex_hash).unwrap().try_into().unwrap()
+    }
+
+    fn address(hex_address: &str) -> [u8; 20] {
+        hex::decode(hex_address).unwrap().try_into().unwrap()
+    }
+
+    fn bloom(hex_bloom: &str) -> [u8; 256] {
+        hex::decode(hex_bloom).unwrap().try_into().unwrap()
+    }
+
+    fn u256(value: u64) -> [u8; 32] {
+        U256::from(value).to_be_bytes()
+    }
+
+    /// Mainnet genesis header.
+    fn genesis() -> Vec<u8> {
+        let zero = || ParsedData::String(vec![0; 32]);
+        let empty_trie = || {
+            ParsedData::String(
+                hex::decode("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421")
+                    .unwrap(),
+            )
+        };
+        ParsedData::List(vec![
+            zero(),
+            ParsedData::String(
+                hex::decode("1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347")
+                    .unwrap(),
+            ),
+            ParsedData::String(vec![0; 20]),
+            ParsedData::String(
+                hex::decode("d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544")
+                    .unwrap(),
+            ),
+            empty_trie(),
+            empty_trie(),
+            ParsedData::String(vec![0; 256]),
+            scalar(&17_179_869_184u64.to_be_bytes()),
+            scalar(&[]),
+            scalar(&5000u64.to_be_bytes()),
+            scalar(&[]),
+            scalar(&[]),
+            ParsedData::String(
+                hex::decode("11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa")
+                    .unwrap(),
+            ),
+            zero(),
+            ParsedData::String(vec![0, 0, 0, 0, 0, 0, 0, 0x42]),
+        ])
+        .to_vec()
+    }
+
+    /// Real header since Cancun, base of the modified headers below.
+    fn cancun() -> AlloyHeader {
+        let raw = hex::decode(MAINNET_19449567).unwrap();
+        AlloyHeader::decode(&mut raw.as_ref()).unwrap()
+    }
+
+    fn encode(header: &AlloyHeader) -> Vec<u8> {
+        let mut raw = Vec::new();
+        header.encode(&mut raw);
+        raw
+    }
+
+    fn parse(raw: &[u8]) -> Result<Header, HeaderError<()>> {
+        Header::parse::<&[u8], ()>(&raw, &mut ())
+    }
+
+    #[test]
+    fn frontier() {
+        let raw = genesis();
+        assert_eq!(
+            parse(&raw),
+            Ok(Header {
+                parent_hash: [0; 32],
+                ommers_hash: hash(
+                    "1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+                ),
+                beneficiary: [0; 20],
+                state_root: hash(
+                    "d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544"
+                ),
+                transactions_root: hash(
+                    "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+                ),
+                receipts_root: hash(
+                    "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+                ),
+                logs_bloom: [0; 256],
+                difficulty: u256(17_179_869_184),
+                number: 0,
+                gas_limit: 5000,
+                gas_used: 0,
+                timestamp: 0,
+                extra_data: hex::decode(
+                    "11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa"
+                )
+                .unwrap(),
+                mix_hash: [0; 32],
+                nonce: [0, 0, 0, 0, 0, 0, 0, 0x42],
+                base_fee_per_gas: None,
+                withdrawals_root: None,
+                blob_gas_used: None,
+                excess_blob_gas: None,
+                parent_beacon_block_root: None,
+                requests_hash: None,
+            })
+        );
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn genesis_hash() {
+        let genesis_hash = hash("d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3");
+        assert_eq!(crate::keccak::keccak256(&genesis()), genesis_hash);
+        assert_eq!(parse(&genesis()).unwrap().hash(), genesis_hash);
+        assert_eq!(
+            header_hash_from_raw::<&[u8], ()>(&genesis().as_ref(), &mut ()),
+            Ok(genesis_hash)
+        );
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn cancun_hash() {
+        let raw = hex::decode(MAINNET_19449567).unwrap();
+        let block_hash = hash("85cdcbe36217fd57bf2c33731d8460657a7ce512401f49c9f6392c82a7ccf7ac");
+        assert_eq!(parse(&raw).unwrap().hash(), block_hash);
+        assert_eq!(
+            header_hash_from_raw::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Ok(block_hash)
+        );
+        assert_eq!(
+            header_hash_from_raw::<&[u8], ()>(&[0x80].as_ref(), &mut ()),
+            Err(HeaderError::NotAList)
+        );
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let prague = AlloyHeader {
+            requests_hash: Some(B256::repeat_byte(0x0b)),
+            extra_data: Bytes::from_static(&[0x01]),
+            ..cancun()
+        };
+        let london = AlloyHeader {
+            difficulty: U256::from(1u64),
+            base_fee_per_gas: Some(7),
+            withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
+            extra_data: Bytes::new(),
+            ..cancun()
+        };
+        for raw in [
+            genesis(),
+            hex::decode(MAINNET_11117104).unwrap(),
+            encode(&london),
+            encode(&cancun()),
+            encode(&prague),
+        ] {
+            assert_eq!(parse(&raw).unwrap().encode(), raw);
+        }
+    }
+
+    #[test]
+    fn pre_london() {
+        let raw = hex::decode(MAINNET_11117104).unwrap();
+        assert_eq!(
+            parse(&raw),
+            Ok(Header {
+                parent_hash: hash(
+                    "9400ec9ef59689c157ac89eeed906f15ddd768f94e1575e0e27d37c241439a5d"
+                ),
+                ommers_hash: EMPTY_OMMERS_HASH,
+                beneficiary: address("829bd824b016326a401d083b33d092293333a830"),
+                state_root: hash(
+                    "546e330050c66d02923e7f1f3e925efaf64e4384eeecf2288f40088714a77a84"
+                ),
+                transactions_root: hash(
+                    "d5eb3ad6d7c7a4798cc5fb14a6820073f44a941107c5d79dac60bd16325631fe"
+                ),
+                receipts_root: hash(
+                    "b21c41cbb3439c5af25304e1405524c885e733b16203221900cb7f4b387b62f0"
+                ),
+                logs_bloom: bloom("1f304e641097eafae088627298685d20202004a4a59e4d8900914724e2402b028c9d596660581f361240816e82d00fa14250c9ca89840887a381efa600288283d170010ab0b2a0694c81842c2482457e0eb77c2c02554614007f42aaf3b4dc15d006a83522c86a240c06d241013258d90540c3008888d576a02c10120808520a2221110f4805200302624d22092b2c0e94e849b1e1aa80bc4cc3206f00b249d0a603ee4310216850e47c8997a20aa81fe95040a49ca5a420464600e008351d161dc00d620970b6a801535c218d0b4116099292000c08001943a225d6485528828110645b8244625a182c1a88a41087e6d039b000a180d04300d0680700a15794"),
+                difficulty: u256(3_449_146_494_972_797),
+                number: 11_117_104,
+                gas_limit: 12_474_982,
+                gas_used: 12_455_884,
+                timestamp: 1_603_516_233,
+                extra_data: hex::decode("7070796520e4b883e5bda9e7a59ee4bb99e9b1bc0103").unwrap(),
+                mix_hash: hash(
+                    "d5e2b7b71fbe4ddfe552fb2377bf7cddb16bbb7e185806036cee86994c6e97fc"
+                ),
+                nonce: [0x47, 0x22, 0xf2, 0xac, 0xd3, 0x5a, 0xbe, 0x0f],
+                base_fee_per_gas: None,
+                withdrawals_root: None,
+                blob_gas_used: None,
+                excess_blob_gas: None,
+                parent_beacon_block_root: None,
+                requests_hash: None,
+            })
+        );
+    }
+
+    #[test]
+    fn next_base_fee() {
+        let header = |gas_used, gas_limit, base_fee: Option<u64>| {
+            parse(&encode(&AlloyHeader {
+                gas_used,
+                gas_limit,
+                base_fee_per_gas: base_fee,
+                withdrawals_root: None,
+                blob_gas_used: None,
+                excess_blob_gas: None,
+                parent_beacon_block_root: None,
+                ..cancun()
+            }))
+            .unwrap()
+        };
+        let cases = [
+            // Mainnet London fork block 12965000 gas figures.
+            (30_025_257, 30_029_122, 1_000_000_000),
+            (30_000_000, 30_000_000, 28_000_000_000),
+            (15_000_000, 30_000_000, 28_000_000_000),
+            (0, 30_000_000, 28_000_000_000),
+            (14_999_999, 30_000_000, 28_000_000_000),
+            (15_000_001, 30_000_000, 28_000_000_000),
+            // Increase rounds down to zero, minimum of 1 applies.
+            (15_000_001, 30_000_000, 7),
+            (0, 30_000_000, 7),
+            (0, 30_000_000, 0),
+            (36_000_000, 36_000_000, u64::MAX / 2),
+        ];
+        for (gas_used, gas_limit, base_fee) in cases {
+            let expected =
+                calc_next_block_base_fee(gas_used, gas_limit, base_fee, BaseFeeParams::ethereum());
+            let header = header(gas_used, gas_limit, Some(base_fee));
+            assert_eq!(header.base_fee_per_gas(), Some(u256(base_fee)));
+            assert_eq!(header.next_base_fee(), Some(u256(expected)));
+        }
+        assert_eq!(
+            header(30_025_257, 30_029_122, Some(1_000_000_000)).next_base_fee(),
+            Some(u256(1_124_967_822))
+        );
+
+        // Base fee above u64, full block.
+        let mut large = header(30_000_000, 30_000_000, Some(0));
+        large.base_fee_per_gas = Some([0xff; 32]);
+        assert_eq!(large.next_base_fee(), Some([0xff; 32]));
+        let base_fee = U256::from(1u64) << 200usize;
+        large.base_fee_per_gas = Some(base_fee.to_be_bytes());
+        assert_eq!(
+            large.next_base_fee(),
+            Some((base_fee + base_fee / U256::from(8u64)).to_be_bytes())
+        );
+
+        let pre_london = header(30_000_000, 30_000_000, None);
+        assert_eq!(pre_london.base_fee_per_gas(), None);
+        assert_eq!(pre_london.next_base_fee(), None);
+    }
+
+    #[test]
+    fn seal_fields() {
+        // Nonce with leading zero bytes is kept as is.
+        let genesis = parse(&genesis()).unwrap();
+        assert_eq!(genesis.difficulty(), u256(17_179_869_184));
+        assert_eq!(genesis.nonce(), [0, 0, 0, 0, 0, 0, 0, 0x42]);
+        assert!(!genesis.is_post_merge_shaped());
+
+        let proof_of_work = parse(&hex::decode(MAINNET_11117104).unwrap()).unwrap();
+        assert_eq!(proof_of_work.difficulty(), u256(3_449_146_494_972_797));
+        assert_eq!(
+            proof_of_work.nonce(),
+            [0x47, 0x22, 0xf2, 0xac, 0xd3, 0x5a, 0xbe, 0x0f]
+        );
+        assert_eq!(
+            proof_of_work.mix_hash(),
+            hash("d5e2b7b71fbe4ddfe552fb2377bf7cddb16bbb7e185806036cee86994c6e97fc")
+        );
+        assert_eq!(proof_of_work.prev_randao(), None);
+        assert!(!proof_of_work.is_post_merge_shaped());
+
+        let proof_of_stake = parse(&hex::decode(MAINNET_19449567).unwrap()).unwrap();
+        assert!(proof_of_stake.is_post_merge_shaped());
+        assert_eq!(proof_of_stake.difficulty(), [0; 32]);
+        assert_eq!(proof_of_stake.nonce(), [0; 8]);
+        assert_eq!(
+            proof_of_stake.prev_randao(),
+            Some(hash(
+                "4c068e902990f21f92a2456fc75c59bec8be03b7f13682b6ebd27da56269beb5"
+            ))
+        );
+
+        // Clique blocks have a zero nonce but a difficulty of 1 or 2.
+        let clique = parse(&hex::decode(GOERLI_4409378).unwrap()).unwrap();
+        assert_eq!(clique.difficulty(), u256(1));
+        assert_eq!(clique.nonce(), [0; 8]);
+        assert!(!clique.is_post_merge_shaped());
+        assert_eq!(clique.prev_randao(), None);
+
+        // Zero difficulty alone is not enough.
+        let zero_difficulty = parse(&encode(&AlloyHeader {
+            nonce: B64::repeat_byte(1),
+            ..cancun()
+        }))
+        .unwrap();
+        assert!(!zero_difficulty.is_post_merge_shaped());
+        assert_eq!(zero_difficulty.prev_randao(), None);
+    }
+
+    #[test]
+    fn cancun_and_prague() {
+        let raw = hex::decode(MAINNET_19449567).unwrap();
+        assert_eq!(
+            parse(&raw),
+            Ok(Header {
+                parent_hash: hash(
+                    "90926e0298d418181bd20c23b332451e35fd7d696b5dcdc5a3a0a6b715f4c717"
+                ),
+                ommers_hash: EMPTY_OMMERS_HASH,
+                beneficiary: address("95222290dd7278aa3ddd389cc1e1d165cc4bafe5"),
+                state_root: hash(
+                    "707875120a7103621fb4131df59904cda39de948dfda9084a1e3da44594d5404"
+                ),
+                transactions_root: hash(
+                    "889a1c26dc42ba829dab552b779620feac231cde8a6c79af022bdc605c23a780"
+                ),
+                receipts_root: hash(
+                    "d43aa19ecb03571d1b86d89d9bb980139d32f2f2ba59646cd5c1de9e80c68c90"
+                ),
+                logs_bloom: bloom("c36919406572730518285284f2293101104140c0d42c4a786c892467868a8806f40159d29988002870403902413a1d04321320308da2e845438429e0012a00b419d8ccc8584a1c28f82a415d04eab8a5ae75c00d07761acf233414c08b6d9b571c06156086c70ea5186e9b989b0c2d55c0213c936805cd2ab331589c90194d070c00867549b1e1be14cb24500b0386cd901197c1ef5a00da453234fa48f3003dcaa894e3111c22b80e17f7d4388385a10720cda1140c0400f9e084ca34fc4870fb16b472340a2a6a63115a82522f506c06c2675080508834828c63defd06bc2331b4aa708906a06a560457b114248041e40179ebc05c6846c1e922125982f427"),
+                difficulty: [0; 32],
+                number: 19_449_567,
+                gas_limit: 30_000_000,
+                gas_used: 11_535_164,
+                timestamp: 1_710_617_795,
+                extra_data: b"beaverbuild.org".to_vec(),
+                mix_hash: hash(
+                    "4c068e902990f21f92a2456fc75c59bec8be03b7f13682b6ebd27da56269beb5"
+                ),
+                nonce: [0; 8],
+                base_fee_per_gas: Some(u256(36_619_559_341)),
+                withdrawals_root: Some(hash(
+                    "360c33f20eeed5efbc7d08be46e58f8440af5db503e40908ef3d1eb314856ef7"
+                )),
+                blob_gas_used: Some(0),
+                excess_blob_gas: Some(0),
+                parent_beacon_block_root: Some(hash(
+                    "2843cb9f7d001bd58816a915e685ed96a555c9aeec1217736bd83a96ebd409cc"
+                )),
+                requests_hash: None,
+            })
+        );
+
+        let prague = AlloyHeader {
+            requests_hash: Some(B256::repeat_byte(0x0b)),
+            ..cancun()
+        };
+        assert_eq!(
+            parse(&encode(&prague)).unwrap().requests_hash,
+            Some([0x0b; 32])
+        );
+    }
+
+    #[test]
+    fn fork_field_count() {
+        let prague = encode(&AlloyHeader {
+            requests_hash: Some(B256::repeat_byte(0x0b)),
+            ..cancun()
+        });
+        // Prague header cut to the first `count` fields.
+        let with_fields = |count| {
+            let ParsedData::List(mut fields) =
+                decode_whole_blob::<&[u8], ()>(&prague.as_ref(), &mut ()).unwrap()
+            else {
+                panic!("header is a list");
+            };
+            fields.truncate(count);
+            ParsedData::List(fields).to_vec()
+        };
+        let parse_for_fork =
+            |raw: &[u8], fork| Header::parse_for_fork::<&[u8], ()>(&raw, &mut (), fork);
+
+        for (fork, count, other) in [
+            (Fork::Frontier, 15, 16),
+            (Fork::Byzantium, 15, 16),
+            (Fork::London, 16, 15),
+            (Fork::Paris, 16, 17),
+            (Fork::Shanghai, 17, 20),
+            (Fork::Cancun, 20, 21),
+            (Fork::Prague, 21, 20),
+        ] {
+            assert_eq!(Header::field_count(fork), count);
+            let raw = with_fields(count);
+            assert_eq!(parse_for_fork(&raw, fork), parse(&raw));
+            assert!(parse(&raw).is_ok());
+            assert_eq!(
+                parse_for_fork(&with_fields(other), fork),
+                Err(HeaderError::WrongFieldCount {
+                    fork,
+                    expected: count,
+                    found: other
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let raw = encode(&cancun());
+        let ParsedData::List(mut fields) =
+            decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+        else {
+            panic!("header is a list");
+        };
+        // Between Shanghai and Cancun.
+        fields.truncate(18);
+        assert_eq!(
+            parse(&ParsedData::List(fields).to_vec()),
+            Err(HeaderError::InvalidFieldCount { found: 18 })
+        );
+
+        let mut raw = genesis();
+        // Beneficiary prefix `0x94` turned into `0x93`, 19 bytes.
+        assert_eq!(raw[69], 0x94);
+        raw[69] = 0x93;
+        raw.remove(70);
+        raw[2] -= 1;
+        assert_eq!(
+            parse(&raw),
+            Err(HeaderError::InvalidField {
+                field: HeaderField::Beneficiary,
+                reason: FieldError::WrongLength {
+                    expected: 20,
+                    found: 19
+                }
+            })
+        );
+        assert_eq!(parse(&[0x80]), Err(HeaderError::NotAList));
+    }
+}
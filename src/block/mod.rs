@@ -0,0 +1,23 @@
+//! Blocks and their parts.
+mod body;
+mod clique;
+mod error;
+#[cfg(test)]
+pub(crate) mod fixtures;
+mod header;
+mod new_block;
+mod withdrawal;
+
+pub use body::{Block, Body};
+pub use clique::{seal_hash_payload, CliqueError, CliqueExtra, CLIQUE_SEAL_LEN, CLIQUE_VANITY_LEN};
+pub use error::{
+    BlockError, BodyError, HeaderError, HeaderField, NewBlockError, OmmersError, WithdrawalError,
+    WithdrawalsError,
+};
+#[cfg(feature = "keccak")]
+pub use header::header_hash_from_raw;
+pub use header::{
+    Header, BASE_FEE_ELASTICITY_MULTIPLIER, BASE_FEE_MAX_CHANGE_DENOMINATOR, EMPTY_OMMERS_HASH,
+};
+pub use new_block::NewBlockMessage;
+pub use withdrawal::{parse_withdrawals, Withdrawal};
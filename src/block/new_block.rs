@@ -0,0 +1,211 @@
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::block::body::{decode_field, whole_list};
+use crate::block::{Block, BlockError, NewBlockError};
+use crate::span::{ItemKind, ItemSpan};
+
+/// devp2p `NewBlock` message `[block, total_difficulty]`, with `block` as
+/// `[header, transactions, ommers]`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct NewBlockMessage {
+    pub block: Block,
+    /// Big-endian total difficulty of the chain up to and including the
+    /// block.
+    pub total_difficulty: [u8; 32],
+}
+
+impl NewBlockMessage {
+    /// Parse `rlp([block, total_difficulty])`. Block header fields present
+    /// are detected.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, NewBlockError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_inner(data, ext_memory).map(|(message, _)| message)
+    }
+
+    /// Parse like [`parse`](Self::parse) and, if `expected_hash` is given,
+    /// check it against the Keccak-256 hash of the header as encoded in
+    /// `data`.
+    #[cfg(feature = "keccak")]
+    pub fn parse_with_expected_hash<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        expected_hash: Option<[u8; 32]>,
+    ) -> Result<Self, NewBlockError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let (message, header_span) = Self::parse_inner(data, ext_memory)?;
+        if let Some(expected) = expected_hash {
+            let header = header_span.read_raw(data, ext_memory)?;
+            let found = crate::keccak::keccak256(header.as_ref());
+            if found != expected {
+                return Err(NewBlockError::HashMismatch { expected, found });
+            }
+        }
+        Ok(message)
+    }
+
+    /// Message and the location of the block header in `data`.
+    fn parse_inner<B, E>(data: &B, ext_memory: &mut E) -> Result<(Self, ItemSpan), NewBlockError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let fields = whole_list(data, ext_memory).map_err(|error| match error {
+            Some(error) => NewBlockError::Decoding(error),
+            None => NewBlockError::NotAList,
+        })?;
+        if fields.len() != 2 {
+            return Err(NewBlockError::InvalidFieldCount {
+                found: fields.len(),
+            });
+        }
+        if fields[0].kind != ItemKind::List {
+            return Err(NewBlockError::Block(BlockError::NotAList));
+        }
+        let block_fields = fields[0].list_items(data, ext_memory)?;
+        let block = Block::from_fields(data, ext_memory, &block_fields, None)
+            .map_err(NewBlockError::Block)?;
+        let total_difficulty = decode_field(data, ext_memory, &fields[1])?
+            .as_strict_u256()
+            .map_err(NewBlockError::TotalDifficulty)?;
+        Ok((
+            Self {
+                block,
+                total_difficulty,
+            },
+            block_fields[0],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::U256;
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use crate::block::{BodyError, HeaderError};
+    use crate::field::FieldError;
+    use crate::{decode_whole_blob, ParsedData};
+
+    /// Mainnet genesis block, `[header, transactions, ommers]`.
+    const GENESIS_BLOCK: &str = "f90219f90214a00000000000000000000000000000000000000000000000000000000000000000a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347940000000000000000000000000000000000000000a0d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000850400000000808213888080a011bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82faa00000000000000000000000000000000000000000000000000000000000000000880000000000000042c0c0";
+
+    fn genesis_block() -> Vec<ParsedData> {
+        let raw = hex::decode(GENESIS_BLOCK).unwrap();
+        match decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap() {
+            ParsedData::List(fields) => fields,
+            _ => unreachable!(),
+        }
+    }
+
+    fn message(block: Vec<ParsedData>, total_difficulty: ParsedData) -> Vec<u8> {
+        ParsedData::List(vec![ParsedData::List(block), total_difficulty]).to_vec()
+    }
+
+    fn parse(raw: &[u8]) -> Result<NewBlockMessage, NewBlockError<()>> {
+        NewBlockMessage::parse::<&[u8], ()>(&raw, &mut ())
+    }
+
+    #[test]
+    fn genesis() {
+        // Total difficulty of the genesis block is its own difficulty.
+        let raw = message(genesis_block(), ParsedData::String(vec![0x04, 0, 0, 0, 0]));
+        let parsed = parse(&raw).unwrap();
+        assert_eq!(parsed.block.header.number, 0);
+        assert_eq!(parsed.total_difficulty, parsed.block.header.difficulty());
+        assert!(parsed.block.body.transactions.is_empty());
+
+        // Terminal total difficulty of the merge, well above u64.
+        let ttd = U256::from(58_750_000_000_000_000_000_000u128);
+        let raw = message(genesis_block(), ParsedData::from_u256_be(ttd.to_be_bytes()));
+        assert_eq!(parse(&raw).unwrap().total_difficulty, ttd.to_be_bytes());
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn expected_hash() {
+        let raw = message(genesis_block(), ParsedData::String(vec![0x04, 0, 0, 0, 0]));
+        let genesis_hash: [u8; 32] =
+            hex::decode("d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let parse_with = |expected_hash| {
+            NewBlockMessage::parse_with_expected_hash::<&[u8], ()>(
+                &raw.as_ref(),
+                &mut (),
+                expected_hash,
+            )
+        };
+        assert!(parse_with(Some(genesis_hash)).is_ok());
+        assert!(parse_with(None).is_ok());
+        assert_eq!(
+            parse_with(Some([0; 32])),
+            Err(NewBlockError::HashMismatch {
+                expected: [0; 32],
+                found: genesis_hash
+            })
+        );
+    }
+
+    #[test]
+    fn corrupted_total_difficulty() {
+        let with_total_difficulty =
+            |total_difficulty| parse(&message(genesis_block(), total_difficulty)).map(|_| ());
+        assert_eq!(
+            with_total_difficulty(ParsedData::String(vec![0, 0x04, 0, 0, 0, 0])),
+            Err(NewBlockError::TotalDifficulty(FieldError::LeadingZero))
+        );
+        assert_eq!(
+            with_total_difficulty(ParsedData::String(vec![0x01; 33])),
+            Err(NewBlockError::TotalDifficulty(FieldError::ScalarTooLong {
+                max_length: 32,
+                found: 33
+            }))
+        );
+        assert_eq!(
+            with_total_difficulty(ParsedData::List(Vec::new())),
+            Err(NewBlockError::TotalDifficulty(FieldError::ExpectedString))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let total_difficulty = || ParsedData::Byte(1);
+
+        let mut block = genesis_block();
+        block[0] = ParsedData::Byte(0);
+        assert_eq!(
+            parse(&message(block, total_difficulty())),
+            Err(NewBlockError::Block(BlockError::Header(
+                HeaderError::NotAList
+            )))
+        );
+
+        let mut block = genesis_block();
+        block[2] = ParsedData::String(Vec::new());
+        assert_eq!(
+            parse(&message(block, total_difficulty())),
+            Err(NewBlockError::Block(BlockError::Body(
+                BodyError::OmmersNotAList
+            )))
+        );
+
+        let block = ParsedData::List(genesis_block()).to_vec();
+        assert_eq!(
+            parse(&ParsedData::List(vec![ParsedData::String(block), total_difficulty()]).to_vec()),
+            Err(NewBlockError::Block(BlockError::NotAList))
+        );
+        assert_eq!(
+            parse(&ParsedData::List(vec![ParsedData::List(genesis_block())]).to_vec()),
+            Err(NewBlockError::InvalidFieldCount { found: 1 })
+        );
+        assert_eq!(parse(&[0x80]), Err(NewBlockError::NotAList));
+    }
+}
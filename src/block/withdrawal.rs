@@ -0,0 +1,227 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::block::{WithdrawalError, WithdrawalsError};
+use crate::encode::leaf;
+use crate::ParsedData;
+
+/// Beacon chain withdrawal, EIP-4895.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Withdrawal {
+    pub index: u64,
+    pub validator_index: u64,
+    pub address: [u8; 20],
+    pub amount_gwei: u64,
+}
+
+impl Withdrawal {
+    const FIELD_COUNT: usize = 4;
+
+    /// Parse withdrawal `[index, validator_index, address, amount]`.
+    pub fn parse_from(parsed_data: &ParsedData) -> Result<Self, WithdrawalError> {
+        let fields = parsed_data
+            .as_list()
+            .map_err(|_| WithdrawalError::NotAList)?;
+        if fields.len() != Self::FIELD_COUNT {
+            return Err(WithdrawalError::WrongFieldCount {
+                expected: Self::FIELD_COUNT,
+                found: fields.len(),
+            });
+        }
+        Ok(Self {
+            index: fields[0]
+                .as_strict_u64()
+                .map_err(WithdrawalError::InvalidIndex)?,
+            validator_index: fields[1]
+                .as_strict_u64()
+                .map_err(WithdrawalError::InvalidValidatorIndex)?,
+            address: fields[2]
+                .as_fixed_bytes::<20>()
+                .map_err(WithdrawalError::InvalidAddress)?,
+            amount_gwei: fields[3]
+                .as_strict_u64()
+                .map_err(WithdrawalError::InvalidAmount)?,
+        })
+    }
+
+    /// Amount in wei, big-endian.
+    pub fn amount_wei(&self) -> [u8; 32] {
+        // Fits into u128: (2^64 - 1) * 10^9 < 2^94.
+        let wei = self.amount_gwei as u128 * 1_000_000_000;
+        let mut out = [0; 32];
+        out[16..].copy_from_slice(&wei.to_be_bytes());
+        out
+    }
+
+    pub fn to_parsed(&self) -> ParsedData {
+        ParsedData::List(Vec::from([
+            ParsedData::from_u64(self.index),
+            ParsedData::from_u64(self.validator_index),
+            leaf(self.address.to_vec()),
+            ParsedData::from_u64(self.amount_gwei),
+        ]))
+    }
+
+    /// Canonical encoding, the value of the withdrawal in the withdrawals
+    /// trie.
+    pub fn encode(&self) -> Vec<u8> {
+        self.to_parsed().to_vec()
+    }
+}
+
+/// Parse list of withdrawals, as in block body.
+pub fn parse_withdrawals(parsed_data: &ParsedData) -> Result<Vec<Withdrawal>, WithdrawalsError> {
+    parsed_data
+        .as_list()
+        .map_err(|_| WithdrawalsError::NotAList)?
+        .iter()
+        .enumerate()
+        .map(|(index, withdrawal)| {
+            Withdrawal::parse_from(withdrawal)
+                .map_err(|source| WithdrawalsError::InvalidWithdrawal { index, source })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::proofs::calculate_withdrawals_root;
+    use alloy_eips::eip4895::Withdrawal as AlloyWithdrawal;
+    use alloy_primitives::{Address, U256};
+    use alloy_rlp::Encodable;
+    use alloy_trie::root::ordered_trie_root_encoded;
+    use std::vec;
+
+    use super::*;
+    use crate::decode_whole_blob;
+    use crate::field::FieldError;
+
+    fn alloy_withdrawals() -> Vec<AlloyWithdrawal> {
+        (0..16)
+            .map(|a| AlloyWithdrawal {
+                index: 1_000_000 + a,
+                validator_index: 300_000 + 7 * a,
+                address: Address::repeat_byte(a as u8),
+                amount: 14_000_000 + a,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_alloy() {
+        let alloy_withdrawals = alloy_withdrawals();
+        let mut raw = Vec::new();
+        alloy_withdrawals.encode(&mut raw);
+        let parsed_data = decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        let withdrawals = parse_withdrawals(&parsed_data).unwrap();
+        assert_eq!(withdrawals.len(), 16);
+        for (withdrawal, alloy_withdrawal) in withdrawals.iter().zip(&alloy_withdrawals) {
+            assert_eq!(withdrawal.index, alloy_withdrawal.index);
+            assert_eq!(withdrawal.validator_index, alloy_withdrawal.validator_index);
+            assert_eq!(withdrawal.address, alloy_withdrawal.address.0 .0);
+            assert_eq!(withdrawal.amount_gwei, alloy_withdrawal.amount);
+            assert_eq!(
+                withdrawal.amount_wei(),
+                alloy_withdrawal.amount_wei().to_be_bytes::<32>()
+            );
+            let mut alloy_raw = Vec::new();
+            alloy_withdrawal.encode(&mut alloy_raw);
+            assert_eq!(withdrawal.encode(), alloy_raw);
+        }
+    }
+
+    #[test]
+    fn mainnet() {
+        // First withdrawal of mainnet block 19,449,567.
+        let alloy_withdrawal = AlloyWithdrawal {
+            index: 38_633_702,
+            validator_index: 570_038,
+            address: "7cd1122e8e118b12ece8d25480dfeef230da17ff".parse().unwrap(),
+            amount: 18_226_960,
+        };
+        let mut raw = Vec::new();
+        vec![alloy_withdrawal].encode(&mut raw);
+        let parsed_data = decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        let withdrawals = parse_withdrawals(&parsed_data).unwrap();
+        assert_eq!(
+            withdrawals,
+            [Withdrawal {
+                index: 38_633_702,
+                validator_index: 570_038,
+                address: alloy_withdrawal.address.into_array(),
+                amount_gwei: 18_226_960,
+            }]
+        );
+        assert_eq!(
+            withdrawals[0].amount_wei(),
+            U256::from(18_226_960_000_000_000u64).to_be_bytes()
+        );
+
+        let encoded: Vec<Vec<u8>> = withdrawals.iter().map(Withdrawal::encode).collect();
+        assert_eq!(
+            ordered_trie_root_encoded(&encoded),
+            calculate_withdrawals_root(&[alloy_withdrawal])
+        );
+    }
+
+    #[test]
+    fn amount_wei() {
+        let withdrawal = |amount_gwei| Withdrawal {
+            index: 0,
+            validator_index: 0,
+            address: [0; 20],
+            amount_gwei,
+        };
+        assert_eq!(withdrawal(0).amount_wei(), [0; 32]);
+        assert_eq!(
+            withdrawal(32_000_000_000).amount_wei(),
+            U256::from(32_000_000_000_000_000_000u128).to_be_bytes()
+        );
+        assert_eq!(
+            withdrawal(u64::MAX).amount_wei(),
+            (U256::from(u64::MAX) * U256::from(1_000_000_000u64)).to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let valid = || Withdrawal {
+            index: 1,
+            validator_index: 2,
+            address: [0x03; 20],
+            amount_gwei: 4,
+        };
+        assert_eq!(
+            parse_withdrawals(&ParsedData::List(vec![
+                valid().to_parsed(),
+                ParsedData::List(vec![
+                    ParsedData::Byte(1),
+                    ParsedData::Byte(2),
+                    ParsedData::String(vec![0x03; 19]),
+                    ParsedData::Byte(4),
+                ]),
+            ])),
+            Err(WithdrawalsError::InvalidWithdrawal {
+                index: 1,
+                source: WithdrawalError::InvalidAddress(FieldError::WrongLength {
+                    expected: 20,
+                    found: 19
+                })
+            })
+        );
+        assert_eq!(
+            parse_withdrawals(&ParsedData::Byte(0)),
+            Err(WithdrawalsError::NotAList)
+        );
+        assert_eq!(
+            Withdrawal::parse_from(&ParsedData::List(vec![ParsedData::Byte(1)])),
+            Err(WithdrawalError::WrongFieldCount {
+                expected: 4,
+                found: 1
+            })
+        );
+    }
+}
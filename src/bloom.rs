@@ -0,0 +1,262 @@
+//! Logs bloom filter, as found in receipts and block headers.
+use crate::keccak::keccak256;
+use crate::receipt::{Log, Receipt};
+
+pub const BLOOM_SIZE: usize = 256;
+
+/// Logs bloom filter of a receipt or a block header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bloom(pub [u8; BLOOM_SIZE]);
+
+impl Default for Bloom {
+    fn default() -> Self {
+        Self([0; BLOOM_SIZE])
+    }
+}
+
+impl Bloom {
+    /// Check if the log address may be present, see [`bloom_contains`].
+    pub fn contains_address(&self, address: &[u8; 20]) -> bool {
+        bloom_contains(&self.0, address)
+    }
+
+    /// Check if the log topic may be present, see [`bloom_contains`].
+    pub fn contains_topic(&self, topic: &[u8; 32]) -> bool {
+        bloom_contains(&self.0, topic)
+    }
+
+    /// Set the bits of `item`, a log address or topic.
+    pub fn accrue(&mut self, item: &[u8]) {
+        for (byte_index, mask) in bloom_bits(item) {
+            self.0[byte_index] |= mask;
+        }
+    }
+
+    /// Set the bits of the log address and all its topics.
+    pub fn accrue_log(&mut self, log: &Log) {
+        self.accrue(&log.address);
+        for topic in log.topics.iter() {
+            self.accrue(topic);
+        }
+    }
+}
+
+impl Receipt {
+    /// Bloom recomputed from the receipt logs.
+    pub fn expected_bloom(&self) -> Bloom {
+        let mut bloom = Bloom::default();
+        for log in self.logs.iter() {
+            bloom.accrue_log(log);
+        }
+        bloom
+    }
+
+    /// Check that the bloom field matches the receipt logs.
+    pub fn bloom_consistent(&self) -> bool {
+        self.expected_bloom().0 == self.logs_bloom
+    }
+}
+
+/// Bloom of a block, to compare with the header `logs_bloom`: union of the
+/// blooms recomputed from the logs of `receipts`.
+pub fn combined_bloom(receipts: &[Receipt]) -> Bloom {
+    let mut bloom = Bloom::default();
+    for receipt in receipts.iter() {
+        for (byte, receipt_byte) in bloom.0.iter_mut().zip(receipt.expected_bloom().0) {
+            *byte |= receipt_byte;
+        }
+    }
+    bloom
+}
+
+/// Check if `item` (log address or topic) may be present in `bloom`.
+///
+/// Three 11-bit indices are taken from byte pairs 0-1, 2-3 and 4-5 of the
+/// keccak hash of the item; all three bits must be set. Bits are numbered from
+/// the least significant bit of the last bloom byte. False positives are
+/// possible, false negatives are not.
+pub fn bloom_contains(bloom: &[u8; BLOOM_SIZE], item: &[u8]) -> bool {
+    bloom_bits(item)
+        .iter()
+        .all(|(byte_index, mask)| bloom[*byte_index] & mask != 0)
+}
+
+/// Byte indices and bit masks set in bloom for an item.
+fn bloom_bits(item: &[u8]) -> [(usize, u8); 3] {
+    let hash = keccak256(item);
+    core::array::from_fn(|i| {
+        let bit = (((hash[2 * i] as usize) << 8) | hash[2 * i + 1] as usize) & 0x7ff;
+        (BLOOM_SIZE - 1 - bit / 8, 1 << (bit % 8))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{Eip658Value, Receipt as AlloyReceipt};
+    use alloy_primitives::{
+        Address, Bloom as AlloyBloom, BloomInput, Bytes, Log as AlloyLog, B256,
+    };
+    use alloy_rlp::Encodable;
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use crate::block::fixtures::MAINNET_19449567;
+    use crate::block::Header;
+    use crate::receipt::APPROVAL_TOPIC;
+
+    #[test]
+    fn matches_alloy_bloom() {
+        let address = Address::repeat_byte(0xa0);
+        let topic = B256::repeat_byte(0x0f);
+        let mut bloom = AlloyBloom::ZERO;
+        bloom.accrue(BloomInput::Raw(address.as_slice()));
+        bloom.accrue(BloomInput::Raw(topic.as_slice()));
+
+        assert!(bloom_contains(&bloom.0, address.as_slice()));
+        assert!(bloom_contains(&bloom.0, topic.as_slice()));
+        assert!(!bloom_contains(
+            &bloom.0,
+            Address::repeat_byte(0xa1).as_slice()
+        ));
+        assert!(!bloom_contains(&[0; BLOOM_SIZE], address.as_slice()));
+    }
+
+    #[test]
+    fn full_bloom_contains_everything() {
+        assert!(bloom_contains(&[0xff; BLOOM_SIZE], b"anything"));
+    }
+
+    /// Logs bloom of the receipt of mainnet transaction
+    /// 0x21f6554c28453a01e7276c1db2fc1695bb512b170818bfa98fa8136433100616,
+    /// a single USDT `Approval` log.
+    const APPROVAL_RECEIPT_BLOOM: &str = "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000200000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000400000800000000000000000000000000000000004000000000000000000800000000100000020000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000010000000000000000000000000000";
+
+    fn parse_bloom(hex_str: &str) -> Bloom {
+        Bloom(hex::decode(hex_str).unwrap().try_into().unwrap())
+    }
+
+    fn address(hex_str: &str) -> [u8; 20] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn bloom_type() {
+        // USDT contract and the ERC-20 `Transfer` event signature.
+        let usdt: Address = "dac17f958d2ee523a2206206994597c13d831ec7".parse().unwrap();
+        let transfer: B256 = "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+            .parse()
+            .unwrap();
+        let alloy_log = AlloyLog::new_unchecked(usdt, vec![transfer], Default::default());
+        let mut alloy_bloom = AlloyBloom::ZERO;
+        alloy_bloom.accrue_log(&alloy_log);
+
+        let mut bloom = Bloom::default();
+        bloom.accrue_log(&Log {
+            address: usdt.into_array(),
+            topics: vec![transfer.0],
+            data: Vec::new(),
+        });
+        assert_eq!(bloom.0, alloy_bloom.0);
+
+        let mut by_items = Bloom::default();
+        by_items.accrue(usdt.as_slice());
+        by_items.accrue(transfer.as_slice());
+        assert_eq!(by_items, bloom);
+
+        // Real receipt bloom: the USDT contract is known to be present.
+        let receipt_bloom = parse_bloom(APPROVAL_RECEIPT_BLOOM);
+        assert!(receipt_bloom.contains_address(&usdt.into_array()));
+        assert!(receipt_bloom.contains_topic(&APPROVAL_TOPIC));
+        assert!(!receipt_bloom.contains_topic(&transfer.0));
+
+        // Header bloom of mainnet block 19,449,567.
+        let raw = hex::decode(MAINNET_19449567).unwrap();
+        let header = Header::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        let header_bloom = Bloom(header.logs_bloom);
+        assert!(header_bloom.contains_address(&address("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")));
+        assert!(header_bloom.contains_topic(&transfer.0));
+        assert!(
+            !header_bloom.contains_address(&address("5a0b54d5dc17e0aadc383d2db43b0a0d3e029c4c"))
+        );
+        assert!(!header_bloom.contains_topic(&B256::repeat_byte(0x42).0));
+        assert!(!Bloom::default().contains_address(&usdt.into_array()));
+    }
+
+    /// Logs bloom of the receipt of mainnet transaction
+    /// 0xea1093d492a1dcb1bef708f771a99a96ff05dcab81ca76c31940300177fcf49f in
+    /// block 1,000,000.
+    const BLOCK_1000000_RECEIPT_BLOOM: &str = "00000000000000000000000000000000000800000000000000000000000800000000000000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000400000000000000000";
+
+    fn log(address: &str, topics: &[&str], data: &str) -> AlloyLog {
+        AlloyLog::new_unchecked(
+            address.parse().unwrap(),
+            topics.iter().map(|a| a.parse().unwrap()).collect(),
+            Bytes::from(hex::decode(data).unwrap()),
+        )
+    }
+
+    fn receipt(status: Eip658Value, logs: Vec<AlloyLog>) -> Receipt {
+        let receipt = AlloyReceipt {
+            status,
+            cumulative_gas_used: 100_000,
+            logs,
+        }
+        .with_bloom();
+        let mut raw = Vec::new();
+        receipt.encode(&mut raw);
+        Receipt::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+    }
+
+    #[test]
+    fn receipt_blooms() {
+        let block_1000000 = receipt(
+            Eip658Value::PostState(
+                "284d35bf53b82ef480ab4208527325477439c64fb90ef518450f05ee151c8e10"
+                    .parse()
+                    .unwrap(),
+            ),
+            vec![log(
+                "c083e9947cf02b8ffc7d3090ae9aea72df98fd47",
+                &["e1fffcc4923d04b559f4d29a8bfc6cda04eb5b0d3c460751c2402c5c5cc9109c"],
+                "00000000000000000000000039fa8c5f2793459d6622857e7d9fbb4bd91766d30000000000000000000000000000000000000000000000056bc75e2d63100000",
+            )],
+        );
+        let approval = receipt(
+            Eip658Value::Eip658(true),
+            vec![log(
+                "dac17f958d2ee523a2206206994597c13d831ec7",
+                &[
+                    "8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925",
+                    "0000000000000000000000009a53bfba35269414f3b2d20b52ca01b15932c7b2",
+                    "00000000000000000000000039e5dbb9d2fead31234d7c647d6ce77d85826f76",
+                ],
+                "00000000000000000000000000000000000000000052b7d2dcc80cd2e4000000",
+            )],
+        );
+        let empty = receipt(Eip658Value::Eip658(false), Vec::new());
+
+        let block_1000000_bloom = parse_bloom(BLOCK_1000000_RECEIPT_BLOOM);
+        let approval_bloom = parse_bloom(APPROVAL_RECEIPT_BLOOM);
+        assert_eq!(block_1000000.expected_bloom(), block_1000000_bloom);
+        assert_eq!(approval.expected_bloom(), approval_bloom);
+        assert!(block_1000000.bloom_consistent());
+        assert!(approval.bloom_consistent());
+        assert_eq!(empty.expected_bloom(), Bloom::default());
+        assert!(empty.bloom_consistent());
+
+        let receipts = [approval, empty, block_1000000];
+        let mut union = approval_bloom;
+        union
+            .0
+            .iter_mut()
+            .zip(block_1000000_bloom.0)
+            .for_each(|(a, b)| *a |= b);
+        assert_eq!(combined_bloom(&receipts), union);
+        assert_eq!(combined_bloom(&[]), Bloom::default());
+
+        // Log not covered by the bloom.
+        let mut tampered = receipts[0].clone();
+        tampered.logs[0].address = address("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+        assert!(!tampered.bloom_consistent());
+    }
+}
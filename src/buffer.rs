@@ -0,0 +1,255 @@
+//! Additional [`AddressableBuffer`] implementations.
+//!
+//! Buffers here only change where the input bytes live; storage of decoded
+//! strings is chosen separately, see [`container`](crate::container).
+#[cfg(any(feature = "std", test))]
+use std::{borrow::Cow, vec::Vec};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::{borrow::Cow, vec::Vec};
+
+use external_memory_tools::{AddressableBuffer, BufferError, ExternalMemory};
+
+/// Input scattered over several chunks, such as DMA or ring buffer
+/// segments, decoded without compacting them first.
+///
+/// Reads within one segment borrow from it, reads across a segment border
+/// are copied into a temporary `Vec`. Segments are searched linearly on
+/// each read.
+#[derive(Clone, Copy, Debug)]
+pub struct SegmentedBuffer<'a> {
+    segments: &'a [&'a [u8]],
+    len: usize,
+}
+
+impl<'a> SegmentedBuffer<'a> {
+    pub fn new(segments: &'a [&'a [u8]]) -> Self {
+        Self {
+            segments,
+            len: segments.iter().map(|a| a.len()).sum(),
+        }
+    }
+}
+
+impl<'a, E: ExternalMemory> AddressableBuffer<E> for SegmentedBuffer<'a> {
+    type ReadBuffer = Cow<'a, [u8]>;
+
+    fn total_len(&self) -> usize {
+        self.len
+    }
+
+    fn read_slice(
+        &self,
+        _ext_memory: &mut E,
+        position: usize,
+        slice_len: usize,
+    ) -> Result<Self::ReadBuffer, BufferError<E>> {
+        if position > self.len {
+            return Err(BufferError::OutOfRange {
+                position,
+                total_length: self.len,
+            });
+        }
+        if slice_len > self.len - position {
+            return Err(BufferError::DataTooShort {
+                position,
+                minimal_length: slice_len,
+            });
+        }
+        let mut start = position;
+        let mut segments = self.segments.iter();
+        for segment in segments.by_ref() {
+            if start < segment.len() {
+                if start + slice_len <= segment.len() {
+                    return Ok(Cow::Borrowed(&segment[start..start + slice_len]));
+                }
+                let mut out = Vec::with_capacity(slice_len);
+                out.extend_from_slice(&segment[start..]);
+                for segment in segments {
+                    let missing = slice_len - out.len();
+                    if missing <= segment.len() {
+                        out.extend_from_slice(&segment[..missing]);
+                        break;
+                    }
+                    out.extend_from_slice(segment);
+                }
+                return Ok(Cow::Owned(out));
+            }
+            start -= segment.len();
+        }
+        // Empty read at the very end.
+        Ok(Cow::Borrowed(&[]))
+    }
+
+    fn limit_length(&self, new_len: usize) -> Result<Self, BufferError<E>> {
+        if new_len > self.len {
+            return Err(BufferError::DataTooShort {
+                position: 0,
+                minimal_length: new_len,
+            });
+        }
+        Ok(Self {
+            segments: self.segments,
+            len: new_len,
+        })
+    }
+}
+
+/// Statically allocated input, for targets that keep incoming data in a
+/// [`heapless::Vec`].
+///
+/// `heapless::Vec` dereferences into a slice, so `vec.as_slice()` could be
+/// decoded directly as well; this wrapper keeps the buffer type tied to the
+/// capacity `N`.
+#[cfg(feature = "heapless")]
+#[derive(Clone, Copy, Debug)]
+pub struct HeaplessBuffer<'a, const N: usize> {
+    data: &'a heapless::Vec<u8, N>,
+    len: usize,
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, const N: usize> HeaplessBuffer<'a, N> {
+    pub fn new(data: &'a heapless::Vec<u8, N>) -> Self {
+        Self {
+            data,
+            len: data.len(),
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, const N: usize> From<&'a heapless::Vec<u8, N>> for HeaplessBuffer<'a, N> {
+    fn from(data: &'a heapless::Vec<u8, N>) -> Self {
+        Self::new(data)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, E: ExternalMemory, const N: usize> AddressableBuffer<E> for HeaplessBuffer<'a, N> {
+    type ReadBuffer = &'a [u8];
+
+    fn total_len(&self) -> usize {
+        self.len
+    }
+
+    fn read_slice(
+        &self,
+        ext_memory: &mut E,
+        position: usize,
+        slice_len: usize,
+    ) -> Result<Self::ReadBuffer, BufferError<E>> {
+        let visible: &'a [u8] = &self.data.as_slice()[..self.len];
+        visible.read_slice(ext_memory, position, slice_len)
+    }
+
+    fn limit_length(&self, new_len: usize) -> Result<Self, BufferError<E>> {
+        if new_len > self.len {
+            return Err(BufferError::DataTooShort {
+                position: 0,
+                minimal_length: new_len,
+            });
+        }
+        Ok(Self {
+            data: self.data,
+            len: new_len,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+    use crate::decode_whole_blob;
+    #[cfg(feature = "heapless")]
+    use crate::{Error, ParsedData};
+
+    #[test]
+    fn decode_from_segments() {
+        // Same blob split every way into three segments, empty ones
+        // included.
+        let data: Vec<u8> = [0xc8, 0x83, b'c', b'a', b't', 0x82, 0x01, 0x02, 0x05].to_vec();
+        let expected = decode_whole_blob::<&[u8], ()>(&data.as_ref(), &mut ()).unwrap();
+        for first in 0..=data.len() {
+            for second in first..=data.len() {
+                let segments = [&data[..first], &data[first..second], &data[second..]];
+                let buffer = SegmentedBuffer::new(&segments);
+                assert_eq!(
+                    decode_whole_blob::<SegmentedBuffer, ()>(&buffer, &mut ()).as_ref(),
+                    Ok(&expected)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn segmented_reads() {
+        let segments: [&[u8]; 3] = [&[1, 2], &[], &[3, 4, 5]];
+        let buffer = SegmentedBuffer::new(&segments);
+        let read = |position, slice_len| {
+            AddressableBuffer::<()>::read_slice(&buffer, &mut (), position, slice_len)
+        };
+        assert!(matches!(read(0, 2), Ok(Cow::Borrowed([1, 2]))));
+        assert!(matches!(read(2, 2), Ok(Cow::Borrowed([3, 4]))));
+        assert_eq!(read(1, 3), Ok(Cow::Owned(vec![2, 3, 4])));
+        assert_eq!(read(0, 5), Ok(Cow::Owned(vec![1, 2, 3, 4, 5])));
+        assert_eq!(read(5, 0), Ok(Cow::Borrowed(&[][..])));
+        assert_eq!(
+            read(4, 2),
+            Err(BufferError::DataTooShort {
+                position: 4,
+                minimal_length: 2
+            })
+        );
+        assert_eq!(
+            read(6, 0),
+            Err(BufferError::OutOfRange {
+                position: 6,
+                total_length: 5
+            })
+        );
+
+        let segments: [&[u8]; 2] = [&[0xc3, 0x01], &[0x02, 0x03]];
+        let limited =
+            AddressableBuffer::<()>::limit_length(&SegmentedBuffer::new(&segments), 3).unwrap();
+        assert_eq!(
+            decode_whole_blob::<SegmentedBuffer, ()>(&limited, &mut ()),
+            decode_whole_blob::<&[u8], ()>(&[0xc3, 0x01, 0x02].as_ref(), &mut ())
+        );
+        assert!(AddressableBuffer::<()>::limit_length(&limited, 4).is_err());
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn decode_from_heapless() {
+        let data: heapless::Vec<u8, 16> =
+            heapless::Vec::from_slice(&[0xc5, 0x83, b'c', b'a', b't', 0x01]).unwrap();
+        let parsed =
+            decode_whole_blob::<HeaplessBuffer<16>, ()>(&HeaplessBuffer::new(&data), &mut ())
+                .unwrap();
+        assert_eq!(
+            parsed,
+            ParsedData::List(vec![
+                ParsedData::String(b"cat".to_vec()),
+                ParsedData::Byte(1)
+            ])
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn limited_heapless_buffer() {
+        let data: heapless::Vec<u8, 16> = heapless::Vec::from_slice(&[0xc3, 0x01, 0x02]).unwrap();
+        let buffer = HeaplessBuffer::new(&data);
+        let limited = AddressableBuffer::<()>::limit_length(&buffer, 2).unwrap();
+        assert_eq!(
+            decode_whole_blob::<HeaplessBuffer<16>, ()>(&limited, &mut ()),
+            Err(Error::Buffer(BufferError::DataTooShort {
+                position: 0,
+                minimal_length: 4
+            }))
+        );
+    }
+}
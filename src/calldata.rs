@@ -0,0 +1,213 @@
+//! Recognition of common contract calls in transaction data.
+
+/// `transfer(address,uint256)`.
+pub const ERC20_TRANSFER: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+/// `approve(address,uint256)`.
+pub const ERC20_APPROVE: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+
+/// `transferFrom(address,address,uint256)`.
+pub const ERC20_TRANSFER_FROM: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+
+/// ABI word size.
+const WORD: usize = 32;
+
+/// ERC-20 call with decoded arguments. Amounts are big-endian `uint256`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Erc20Call {
+    Transfer {
+        to: [u8; 20],
+        amount: [u8; 32],
+    },
+    Approve {
+        spender: [u8; 20],
+        amount: [u8; 32],
+    },
+    TransferFrom {
+        from: [u8; 20],
+        to: [u8; 20],
+        amount: [u8; 32],
+    },
+}
+
+/// Recognize an ERC-20 `transfer`, `approve` or `transferFrom` call.
+///
+/// Arguments must be exactly the static ABI encoding for the selector, so
+/// calldata with truncated or trailing words is not recognized. Addresses
+/// must have the upper 12 bytes of their word zeroed.
+pub fn recognize_erc20(data: &[u8]) -> Option<Erc20Call> {
+    let (selector, arguments) = data.split_first_chunk::<4>()?;
+    let words = match *selector {
+        ERC20_TRANSFER | ERC20_APPROVE => 2,
+        ERC20_TRANSFER_FROM => 3,
+        _ => return None,
+    };
+    if arguments.len() != words * WORD {
+        return None;
+    }
+    let word = |index: usize| -> [u8; 32] {
+        arguments[index * WORD..(index + 1) * WORD]
+            .try_into()
+            .unwrap()
+    };
+    match *selector {
+        ERC20_TRANSFER => Some(Erc20Call::Transfer {
+            to: address(&word(0))?,
+            amount: word(1),
+        }),
+        ERC20_APPROVE => Some(Erc20Call::Approve {
+            spender: address(&word(0))?,
+            amount: word(1),
+        }),
+        _ => Some(Erc20Call::TransferFrom {
+            from: address(&word(0))?,
+            to: address(&word(1))?,
+            amount: word(2),
+        }),
+    }
+}
+
+/// Address from the last 20 bytes of an ABI word with zero upper bytes.
+fn address(word: &[u8; 32]) -> Option<[u8; 20]> {
+    let (padding, address) = word.split_at(12);
+    if padding.iter().all(|a| *a == 0) {
+        address.try_into().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::keccak256;
+    use std::vec::Vec;
+
+    use super::*;
+
+    fn calldata(selector: [u8; 4], words: &[[u8; 32]]) -> Vec<u8> {
+        let mut data = selector.to_vec();
+        words.iter().for_each(|word| data.extend_from_slice(word));
+        data
+    }
+
+    fn address_word(address: [u8; 20]) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[12..].copy_from_slice(&address);
+        word
+    }
+
+    fn amount(value: u64) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    #[test]
+    fn selectors() {
+        assert_eq!(keccak256("transfer(address,uint256)")[..4], ERC20_TRANSFER);
+        assert_eq!(keccak256("approve(address,uint256)")[..4], ERC20_APPROVE);
+        assert_eq!(
+            keccak256("transferFrom(address,address,uint256)")[..4],
+            ERC20_TRANSFER_FROM
+        );
+    }
+
+    #[test]
+    fn transfer() {
+        // 250 USDC, 6 decimals.
+        let data = hex::decode(concat!(
+            "a9059cbb",
+            "000000000000000000000000dac17f958d2ee523a2206206994597c13d831ec7",
+            "000000000000000000000000000000000000000000000000000000000ee6b280",
+        ))
+        .unwrap();
+        let to = hex::decode("dac17f958d2ee523a2206206994597c13d831ec7")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            recognize_erc20(&data),
+            Some(Erc20Call::Transfer {
+                to,
+                amount: amount(250_000_000),
+            })
+        );
+    }
+
+    #[test]
+    fn mainnet_approve() {
+        // Input of mainnet transaction
+        // 0x21f6554c28453a01e7276c1db2fc1695bb512b170818bfa98fa8136433100616 to
+        // USDT, as confirmed by its `Approval` log.
+        let data = hex::decode(concat!(
+            "095ea7b3",
+            "00000000000000000000000039e5dbb9d2fead31234d7c647d6ce77d85826f76",
+            "00000000000000000000000000000000000000000052b7d2dcc80cd2e4000000",
+        ))
+        .unwrap();
+        let spender = hex::decode("39e5dbb9d2fead31234d7c647d6ce77d85826f76")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let mut amount = [0; 32];
+        amount[21..].copy_from_slice(&hex::decode("52b7d2dcc80cd2e4000000").unwrap());
+        assert_eq!(
+            recognize_erc20(&data),
+            Some(Erc20Call::Approve { spender, amount })
+        );
+    }
+
+    #[test]
+    fn approve_and_transfer_from() {
+        let unlimited = [0xff; 32];
+        assert_eq!(
+            recognize_erc20(&calldata(
+                ERC20_APPROVE,
+                &[address_word([0x11; 20]), unlimited]
+            )),
+            Some(Erc20Call::Approve {
+                spender: [0x11; 20],
+                amount: unlimited,
+            })
+        );
+        assert_eq!(
+            recognize_erc20(&calldata(
+                ERC20_TRANSFER_FROM,
+                &[
+                    address_word([0x11; 20]),
+                    address_word([0x22; 20]),
+                    amount(5)
+                ]
+            )),
+            Some(Erc20Call::TransferFrom {
+                from: [0x11; 20],
+                to: [0x22; 20],
+                amount: amount(5),
+            })
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let valid = calldata(ERC20_TRANSFER, &[address_word([0x11; 20]), amount(1)]);
+        assert!(recognize_erc20(&valid).is_some());
+        for length in 0..valid.len() {
+            assert_eq!(recognize_erc20(&valid[..length]), None);
+        }
+
+        let mut trailing = valid.clone();
+        trailing.extend_from_slice(&[0; 32]);
+        assert_eq!(recognize_erc20(&trailing), None);
+
+        let mut dirty_address = address_word([0x11; 20]);
+        dirty_address[0] = 0x01;
+        assert_eq!(
+            recognize_erc20(&calldata(ERC20_TRANSFER, &[dirty_address, amount(1)])),
+            None
+        );
+        assert_eq!(
+            recognize_erc20(&calldata([0xde, 0xad, 0xbe, 0xef], &[amount(1), amount(1)])),
+            None
+        );
+    }
+}
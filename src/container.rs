@@ -0,0 +1,106 @@
+//! Storage of string payloads in decoded [`ParsedData`](crate::ParsedData)
+//! trees.
+//!
+//! Strings are `Vec<u8>` by default. Trees could hold borrowed slices of
+//! the input instead, or inline storage such as [`heapless::Vec`]; other
+//! inline vectors could implement [`StringContainer`] the same way.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+/// String payload storage, built from bytes `R` read from the buffer.
+pub trait StringContainer<R: AsRef<[u8]>>: AsRef<[u8]> + Sized {
+    /// `None` if the payload does not fit.
+    fn from_read(read: R) -> Option<Self>;
+}
+
+impl<R: AsRef<[u8]>> StringContainer<R> for Vec<u8> {
+    fn from_read(read: R) -> Option<Self> {
+        Some(read.as_ref().to_vec())
+    }
+}
+
+/// Slices borrowed from the input, for buffers reading into the input
+/// itself, such as `&[u8]`.
+impl<'a> StringContainer<&'a [u8]> for &'a [u8] {
+    fn from_read(read: &'a [u8]) -> Option<Self> {
+        Some(read)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<R: AsRef<[u8]>, const N: usize> StringContainer<R> for heapless::Vec<u8, N> {
+    fn from_read(read: R) -> Option<Self> {
+        heapless::Vec::from_slice(read.as_ref()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode_whole_blob, decode_whole_blob_as, DecodeConfig};
+
+    const LEGACY: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    #[test]
+    fn borrowed() {
+        let raw = hex::decode(LEGACY).unwrap();
+        let (borrowed, _) = decode_whole_blob_as::<&[u8], &[u8], ()>(
+            &raw.as_ref(),
+            &mut (),
+            &DecodeConfig::default(),
+        )
+        .unwrap();
+        let fields = borrowed.as_list().unwrap();
+        // `to` points into the input.
+        let to = fields[3].as_bytes().unwrap();
+        assert_eq!(to, [0x35; 20]);
+        assert_eq!(to.as_ptr(), raw[13..].as_ptr());
+        assert_eq!(fields[0].as_strict_u64(), Ok(9));
+        assert_eq!(borrowed.to_vec(), raw);
+
+        let owned = decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(owned.to_vec(), raw);
+        assert_eq!(owned.hex_len(), borrowed.hex_len());
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn inline() {
+        use crate::{Error, ParsedData};
+        use std::{boxed::Box, vec};
+
+        let raw = [0xc6, 0x83, b'c', b'a', b't', 0x81, 0x80];
+        let (parsed, _) = decode_whole_blob_as::<heapless::Vec<u8, 4>, &[u8], ()>(
+            &raw.as_ref(),
+            &mut (),
+            &DecodeConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            parsed,
+            ParsedData::List(vec![
+                ParsedData::String(heapless::Vec::from_slice(b"cat").unwrap()),
+                ParsedData::String(heapless::Vec::from_slice(&[0x80]).unwrap()),
+            ])
+        );
+
+        let too_long = [0xc6, 0x85, 1, 2, 3, 4, 5];
+        assert_eq!(
+            decode_whole_blob_as::<heapless::Vec<u8, 4>, &[u8], ()>(
+                &too_long.as_ref(),
+                &mut (),
+                &DecodeConfig::default(),
+            ),
+            Err(Error::InList {
+                depth: 0,
+                index: 0,
+                source: Box::new(Error::StringCapacity {
+                    position: 1,
+                    length: 5
+                })
+            })
+        );
+    }
+}
@@ -0,0 +1,265 @@
+//! Navigation over encoded items without building a [`ParsedData`] tree.
+//!
+//! [`RlpCursor`] reads item prefixes as it moves and reads payloads only on
+//! request, so a few fields could be taken from a large structure at the
+//! cost of reading their prefixes and payloads. Encodings are accepted as
+//! with [`CanonicalPolicy::Accept`](crate::CanonicalPolicy::Accept).
+use external_memory_tools::{AddressableBuffer, BufferError, ExternalMemory};
+
+use crate::field::FieldError;
+use crate::span::{span_at_position, ItemKind, ItemSpan};
+use crate::{Error, ParsedData};
+
+/// Cursor over consecutive items of a buffer or of a list payload.
+///
+/// Iterating over the cursor yields spans of the items it moves past.
+pub struct RlpCursor<'a, B, E> {
+    data: &'a B,
+    ext_memory: &'a mut E,
+    position: usize,
+    end: usize,
+}
+
+/// Errors in moving [`RlpCursor`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum CursorError<E: ExternalMemory> {
+    Decoding(Error<E>),
+    /// Item starting at `position` is not of the requested kind or value.
+    InvalidItem {
+        position: usize,
+        reason: FieldError,
+    },
+    /// No items left before `position`, the end of the buffer or the list.
+    NoItemsLeft {
+        position: usize,
+    },
+}
+
+impl<E: ExternalMemory> From<Error<E>> for CursorError<E> {
+    fn from(error: Error<E>) -> Self {
+        CursorError::Decoding(error)
+    }
+}
+
+impl<'a, B, E> RlpCursor<'a, B, E>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    /// Cursor at the first item of `data`.
+    pub fn new(data: &'a B, ext_memory: &'a mut E) -> Self {
+        Self {
+            data,
+            ext_memory,
+            position: 0,
+            end: data.total_len(),
+        }
+    }
+
+    /// Position of the next item within the buffer.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.end
+    }
+
+    /// Move past the next item, which must be a list, and return a cursor
+    /// over its elements.
+    pub fn enter_list(&mut self) -> Result<RlpCursor<'_, B, E>, CursorError<E>> {
+        let span = self.next_of_kind(true)?;
+        Ok(RlpCursor {
+            data: self.data,
+            ext_memory: self.ext_memory,
+            position: span.payload_start,
+            end: span.end,
+        })
+    }
+
+    /// Read payload of the next item, which must be a string or a single
+    /// byte.
+    pub fn read_bytes(&mut self) -> Result<B::ReadBuffer, CursorError<E>> {
+        let span = self.next_of_kind(false)?;
+        Ok(span.read_payload(self.data, self.ext_memory)?)
+    }
+
+    /// Read the next item as a scalar, see [`ParsedData::as_u64`].
+    pub fn read_u64(&mut self) -> Result<u64, CursorError<E>> {
+        self.read_scalar(ParsedData::as_u64)
+    }
+
+    /// Read the next item as a scalar, see [`ParsedData::as_u256`].
+    pub fn read_u256(&mut self) -> Result<[u8; 32], CursorError<E>> {
+        self.read_scalar(ParsedData::as_u256)
+    }
+
+    /// Locate the next item and move past it; payload is not read.
+    fn next_span(&mut self) -> Result<ItemSpan, CursorError<E>> {
+        if self.is_finished() {
+            return Err(CursorError::NoItemsLeft {
+                position: self.position,
+            });
+        }
+        let mut position = self.position;
+        let span = span_at_position(self.data, self.ext_memory, &mut position)?;
+        if span.end > self.end {
+            return Err(Error::Buffer(BufferError::DataTooShort {
+                position: span.payload_start,
+                minimal_length: span.payload_len(),
+            })
+            .into());
+        }
+        self.position = position;
+        Ok(span)
+    }
+
+    /// Move past the next item if it is a list (`list` set) or not a list.
+    fn next_of_kind(&mut self, list: bool) -> Result<ItemSpan, CursorError<E>> {
+        let position = self.position;
+        let span = self.next_span()?;
+        let reason = match (span.kind == ItemKind::List, list) {
+            (false, true) => FieldError::ExpectedList,
+            (true, false) => FieldError::ExpectedString,
+            _ => return Ok(span),
+        };
+        self.position = position;
+        Err(CursorError::InvalidItem { position, reason })
+    }
+
+    fn read_scalar<T>(
+        &mut self,
+        convert: impl FnOnce(&ParsedData<B::ReadBuffer>) -> Result<T, FieldError>,
+    ) -> Result<T, CursorError<E>> {
+        let position = self.position;
+        let span = self.next_of_kind(false)?;
+        let payload = span.read_payload(self.data, self.ext_memory)?;
+        let item = match span.kind {
+            ItemKind::Byte => ParsedData::Byte(payload.as_ref()[0]),
+            _ => ParsedData::String(payload),
+        };
+        convert(&item).map_err(|reason| {
+            self.position = position;
+            CursorError::InvalidItem { position, reason }
+        })
+    }
+}
+
+impl<B, E> Iterator for RlpCursor<'_, B, E>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    type Item = Result<ItemSpan, CursorError<E>>;
+
+    /// Move past the next item. After an error the cursor is finished.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished() {
+            return None;
+        }
+        let result = self.next_span();
+        if result.is_err() {
+            self.position = self.end;
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    #[test]
+    fn transaction_fields() {
+        let raw = hex::decode(LEGACY).unwrap();
+        let data = raw.as_ref();
+        let mut ext_memory = ();
+        let mut cursor = RlpCursor::<&[u8], ()>::new(&data, &mut ext_memory);
+        let mut fields = cursor.enter_list().unwrap();
+        assert_eq!(fields.read_u64(), Ok(9));
+        // Skip gas price and gas limit.
+        assert_eq!(fields.nth(1).unwrap().unwrap().end, 12);
+        assert_eq!(fields.read_bytes(), Ok(&[0x35; 20][..]));
+        assert_eq!(
+            fields.read_u256().unwrap()[24..],
+            1_000_000_000_000_000_000u64.to_be_bytes()
+        );
+        assert_eq!(fields.read_bytes(), Ok(&[][..]));
+        assert_eq!(fields.read_u64(), Ok(37));
+        assert_eq!(fields.count(), 2);
+        assert!(cursor.is_finished());
+        assert_eq!(cursor.position(), raw.len());
+    }
+
+    #[test]
+    fn nested_lists() {
+        // [ [ "cat", [ 0x05 ] ], "" ]
+        let data: &[u8] = &[0xc8, 0xc6, 0x83, b'c', b'a', b't', 0xc1, 0x05, 0x80];
+        let mut ext_memory = ();
+        let mut cursor = RlpCursor::<&[u8], ()>::new(&data, &mut ext_memory);
+        let mut outer = cursor.enter_list().unwrap();
+        {
+            let mut inner = outer.enter_list().unwrap();
+            assert_eq!(inner.read_bytes(), Ok(&b"cat"[..]));
+            assert_eq!(inner.enter_list().unwrap().read_u64(), Ok(5));
+            assert_eq!(
+                inner.read_bytes(),
+                Err(CursorError::NoItemsLeft { position: 8 })
+            );
+        }
+        assert_eq!(outer.read_u64(), Ok(0));
+        assert!(outer.is_finished());
+    }
+
+    #[test]
+    fn errors() {
+        // [ "cat", [] ]
+        let data: &[u8] = &[0xc5, 0x83, b'c', b'a', b't', 0xc0];
+        let mut ext_memory = ();
+        let mut cursor = RlpCursor::<&[u8], ()>::new(&data, &mut ext_memory);
+        assert_eq!(
+            cursor.read_bytes(),
+            Err(CursorError::InvalidItem {
+                position: 0,
+                reason: FieldError::ExpectedString
+            })
+        );
+        let mut list = cursor.enter_list().unwrap();
+        assert_eq!(
+            list.enter_list().err(),
+            Some(CursorError::InvalidItem {
+                position: 1,
+                reason: FieldError::ExpectedList
+            })
+        );
+        assert_eq!(
+            list.read_u64(),
+            Ok(u64::from_be_bytes([0, 0, 0, 0, 0, b'c', b'a', b't']))
+        );
+        assert_eq!(
+            list.read_u64(),
+            Err(CursorError::InvalidItem {
+                position: 5,
+                reason: FieldError::ExpectedString
+            })
+        );
+        assert_eq!(list.position(), 5);
+
+        // [ "ca" ] with the string declared 3 bytes long
+        let data: &[u8] = &[0xc3, 0x83, b'c', b'a', b't'];
+        let mut cursor = RlpCursor::<&[u8], ()>::new(&data, &mut ext_memory);
+        let mut list = cursor.enter_list().unwrap();
+        assert_eq!(
+            list.next(),
+            Some(Err(CursorError::Decoding(Error::Buffer(
+                BufferError::DataTooShort {
+                    position: 2,
+                    minimal_length: 3
+                }
+            ))))
+        );
+        assert!(list.next().is_none());
+    }
+}
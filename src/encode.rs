@@ -0,0 +1,198 @@
+//! RLP encoding of [`ParsedData`] trees.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::{ParsedData, BORDER_A, BORDER_C, SHORT_FORM_MAX};
+
+impl<S: AsRef<[u8]>> ParsedData<S> {
+    /// Encode the tree.
+    ///
+    /// Encoding follows the tree as is: a [`ParsedData::String`] always gets
+    /// a string prefix, even if it holds a single byte below `0x80`. Trees
+    /// produced by the lenient decoder therefore re-encode into the original
+    /// bytes, provided the lengths were encoded canonically.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.encoded_len());
+        self.encode_into(&mut out);
+        out
+    }
+
+    /// Append the encoding to `out`.
+    pub fn encode_into(&self, out: &mut Vec<u8>) {
+        match self {
+            ParsedData::Byte(a) if *a < BORDER_A => out.push(*a),
+            ParsedData::Byte(a) => {
+                out.push(BORDER_A + 1);
+                out.push(*a);
+            }
+            ParsedData::List(a) => {
+                let payload_len = a.iter().map(Self::encoded_len).sum();
+                write_prefix(out, BORDER_C, payload_len);
+                a.iter().for_each(|item| item.encode_into(out));
+            }
+            ParsedData::String(a) => {
+                write_prefix(out, BORDER_A, a.as_ref().len());
+                out.extend_from_slice(a.as_ref());
+            }
+        }
+    }
+
+    /// Length of the encoding in bytes.
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            ParsedData::Byte(a) if *a < BORDER_A => 1,
+            ParsedData::Byte(_) => 2,
+            ParsedData::List(a) => {
+                let payload_len = a.iter().map(Self::encoded_len).sum();
+                prefix_len(payload_len) + payload_len
+            }
+            ParsedData::String(a) => prefix_len(a.as_ref().len()) + a.as_ref().len(),
+        }
+    }
+}
+
+impl ParsedData {
+    /// Canonical scalar item: leading zeros stripped, zero is the empty
+    /// string, values below `0x80` are single bytes. Inverse of
+    /// [`as_u64`](Self::as_u64).
+    pub fn from_u64(value: u64) -> Self {
+        scalar(&value.to_be_bytes())
+    }
+
+    /// Canonical scalar item from a big-endian 256-bit value, see
+    /// [`from_u64`](Self::from_u64). Inverse of [`as_u256`](Self::as_u256).
+    pub fn from_u256_be(value: [u8; 32]) -> Self {
+        scalar(&value)
+    }
+}
+
+/// Leaf as the decoder would produce it: a single byte below `0x80` becomes
+/// [`ParsedData::Byte`].
+pub(crate) fn leaf(bytes: Vec<u8>) -> ParsedData {
+    match bytes[..] {
+        [a] if a < BORDER_A => ParsedData::Byte(a),
+        _ => ParsedData::String(bytes),
+    }
+}
+
+/// Big-endian scalar with leading zeros stripped, zero is the empty string.
+pub(crate) fn scalar(bytes: &[u8]) -> ParsedData {
+    let start = bytes.iter().position(|a| *a != 0).unwrap_or(bytes.len());
+    leaf(bytes[start..].to_vec())
+}
+
+fn write_prefix(out: &mut Vec<u8>, short_base: u8, payload_len: usize) {
+    if payload_len <= SHORT_FORM_MAX {
+        out.push(short_base + payload_len as u8);
+    } else {
+        let length_bytes = payload_len.to_be_bytes();
+        let skip = length_bytes.iter().take_while(|a| **a == 0).count();
+        out.push(short_base + SHORT_FORM_MAX as u8 + (length_bytes.len() - skip) as u8);
+        out.extend_from_slice(&length_bytes[skip..]);
+    }
+}
+
+fn prefix_len(payload_len: usize) -> usize {
+    if payload_len <= SHORT_FORM_MAX {
+        1
+    } else {
+        1 + payload_len
+            .to_be_bytes()
+            .iter()
+            .skip_while(|a| **a == 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_rlp::{Encodable, RlpEncodable};
+    use std::{string::String, vec};
+
+    use super::*;
+    use crate::decode_whole_blob;
+
+    fn round_trip(bytes_input: &[u8]) {
+        let parsed = decode_whole_blob::<&[u8], ()>(&bytes_input, &mut ()).unwrap();
+        assert_eq!(parsed.encoded_len(), bytes_input.len());
+        assert_eq!(parsed.to_vec(), bytes_input);
+    }
+
+    #[test]
+    fn matches_alloy() {
+        #[derive(RlpEncodable)]
+        struct MockStruct {
+            a: String,
+            b: u8,
+            c: u8,
+            d: u8,
+            e: Vec<Vec<u64>>,
+        }
+
+        let mut buffer = Vec::new();
+        MockStruct {
+            a: String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do"),
+            b: 0,
+            c: 0x7f,
+            d: 0x80,
+            e: vec![vec![1, 1024, u64::MAX]; 20],
+        }
+        .encode(&mut buffer);
+        round_trip(&buffer);
+
+        let mut buffer = Vec::new();
+        vec![0xaau8; 70_000].as_slice().encode(&mut buffer);
+        round_trip(&buffer);
+    }
+
+    #[test]
+    fn faithful_to_tree() {
+        assert_eq!(<ParsedData>::Byte(0x05).to_vec(), [0x05]);
+        assert_eq!(<ParsedData>::Byte(0x80).to_vec(), [0x81, 0x80]);
+        assert_eq!(ParsedData::String(vec![0x05]).to_vec(), [0x81, 0x05]);
+        assert_eq!(ParsedData::String(Vec::new()).to_vec(), [0x80]);
+        assert_eq!(<ParsedData>::List(Vec::new()).to_vec(), [0xc0]);
+        round_trip(&[0xc2, 0x81, 0x05]);
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(scalar(&[0; 32]), ParsedData::String(Vec::new()));
+        assert_eq!(scalar(&[0, 0, 0x7f]), ParsedData::Byte(0x7f));
+        assert_eq!(scalar(&[0, 0x80]), ParsedData::String(vec![0x80]));
+        assert_eq!(scalar(&[0, 1, 0]), ParsedData::String(vec![1, 0]));
+    }
+
+    #[test]
+    fn scalar_constructors() {
+        assert_eq!(ParsedData::from_u64(0), ParsedData::String(Vec::new()));
+        assert_eq!(ParsedData::from_u64(0x7f), ParsedData::Byte(0x7f));
+        assert_eq!(ParsedData::from_u64(0x80), ParsedData::String(vec![0x80]));
+        assert_eq!(
+            ParsedData::from_u64(0x0400),
+            ParsedData::String(vec![0x04, 0x00])
+        );
+        for value in [0, 1, 0x7f, 0x80, 0xff, 0x100, 1 << 32, u64::MAX] {
+            let item = ParsedData::from_u64(value);
+            assert_eq!(item.as_u64(), Ok(value));
+            assert_eq!(item.as_strict_u64(), Ok(value));
+
+            let mut wide = [0; 32];
+            wide[24..].copy_from_slice(&value.to_be_bytes());
+            assert_eq!(ParsedData::from_u256_be(wide), item);
+        }
+
+        let mut wide = [0; 32];
+        wide[1] = 0x01;
+        let item = ParsedData::from_u256_be(wide);
+        assert_eq!(item.as_bytes().unwrap().len(), 31);
+        assert_eq!(item.as_strict_u256(), Ok(wide));
+        assert_eq!(
+            ParsedData::from_u256_be([0xff; 32]).as_u256(),
+            Ok([0xff; 32])
+        );
+    }
+}
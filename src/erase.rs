@@ -0,0 +1,200 @@
+//! Decoding errors without the [`ExternalMemory`] parameter.
+//!
+//! [`Error::erase`] turns errors of any buffer backend into
+//! [`DecodeError`], so errors from different backends could be collected
+//! together. Backend-specific memory errors are kept as text.
+#[cfg(any(feature = "std", test))]
+use std::{
+    boxed::Box,
+    string::{String, ToString},
+};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+
+use external_memory_tools::{BufferError, ExternalMemory};
+
+use crate::{Error, NonCanonical};
+
+/// [`Error`] with the buffer error erased, variants match one to one.
+#[derive(Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    Buffer(ErasedBufferError),
+    ExpectedList {
+        position: usize,
+    },
+    InList {
+        depth: usize,
+        index: usize,
+        source: Box<DecodeError>,
+    },
+    LengthFieldTooLong {
+        position: usize,
+    },
+    LengthOverflow {
+        position: usize,
+    },
+    LimitedTooShort {
+        border: usize,
+        length: usize,
+    },
+    NonCanonical {
+        position: usize,
+        reason: NonCanonical,
+    },
+    NotWorking,
+    PositionOutOfBounds {
+        position: usize,
+        len: usize,
+    },
+    ReadBudgetExceeded {
+        position: usize,
+    },
+    SomeDataUnused {
+        from: usize,
+    },
+    StringCapacity {
+        position: usize,
+        length: usize,
+    },
+}
+
+/// [`BufferError`] with the external memory error kept as its text.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ErasedBufferError {
+    DataTooShort {
+        position: usize,
+        minimal_length: usize,
+    },
+    External(String),
+    OutOfRange {
+        position: usize,
+        total_length: usize,
+    },
+}
+
+impl<E: ExternalMemory> Error<E> {
+    /// Drop the buffer type from the error.
+    pub fn erase(self) -> DecodeError {
+        match self {
+            Error::Buffer(error) => DecodeError::Buffer(error.into()),
+            Error::ExpectedList { position } => DecodeError::ExpectedList { position },
+            Error::InList {
+                depth,
+                index,
+                source,
+            } => DecodeError::InList {
+                depth,
+                index,
+                source: Box::new(source.erase()),
+            },
+            Error::LengthFieldTooLong { position } => DecodeError::LengthFieldTooLong { position },
+            Error::LengthOverflow { position } => DecodeError::LengthOverflow { position },
+            Error::LimitedTooShort { border, length } => {
+                DecodeError::LimitedTooShort { border, length }
+            }
+            Error::NonCanonical { position, reason } => {
+                DecodeError::NonCanonical { position, reason }
+            }
+            Error::NotWorking => DecodeError::NotWorking,
+            Error::PositionOutOfBounds { position, len } => {
+                DecodeError::PositionOutOfBounds { position, len }
+            }
+            Error::ReadBudgetExceeded { position } => DecodeError::ReadBudgetExceeded { position },
+            Error::SomeDataUnused { from } => DecodeError::SomeDataUnused { from },
+            Error::StringCapacity { position, length } => {
+                DecodeError::StringCapacity { position, length }
+            }
+        }
+    }
+}
+
+impl<E: ExternalMemory> From<Error<E>> for DecodeError {
+    fn from(error: Error<E>) -> Self {
+        error.erase()
+    }
+}
+
+impl<E: ExternalMemory> From<BufferError<E>> for ErasedBufferError {
+    fn from(error: BufferError<E>) -> Self {
+        match error {
+            BufferError::DataTooShort {
+                position,
+                minimal_length,
+            } => ErasedBufferError::DataTooShort {
+                position,
+                minimal_length,
+            },
+            BufferError::External(error) => ErasedBufferError::External(error.to_string()),
+            BufferError::OutOfRange {
+                position,
+                total_length,
+            } => ErasedBufferError::OutOfRange {
+                position,
+                total_length,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt;
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use crate::decode_whole_blob;
+
+    #[derive(Debug)]
+    struct Flaky;
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct ReadFailed;
+
+    impl fmt::Display for ReadFailed {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "read failed")
+        }
+    }
+
+    impl ExternalMemory for Flaky {
+        type ExternalMemoryError = ReadFailed;
+    }
+
+    #[test]
+    fn erase() {
+        let in_slice = decode_whole_blob::<&[u8], ()>(&[0xc2, 0x82, 0x01].as_ref(), &mut ())
+            .unwrap_err()
+            .erase();
+        let external: Error<Flaky> = Error::InList {
+            depth: 0,
+            index: 0,
+            source: Box::new(Error::Buffer(BufferError::External(ReadFailed))),
+        };
+
+        let errors: Vec<DecodeError> = vec![in_slice, external.into()];
+        assert_eq!(
+            errors,
+            [
+                DecodeError::InList {
+                    depth: 0,
+                    index: 0,
+                    source: Box::new(DecodeError::Buffer(ErasedBufferError::DataTooShort {
+                        position: 2,
+                        minimal_length: 2
+                    }))
+                },
+                DecodeError::InList {
+                    depth: 0,
+                    index: 0,
+                    source: Box::new(DecodeError::Buffer(ErasedBufferError::External(
+                        "read failed".to_string()
+                    )))
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,804 @@
+//! Payloads of devp2p `eth` protocol messages.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::block::{Header, HeaderError};
+use crate::field::FieldError;
+use crate::receipt::{ReceiptEnvelope, ReceiptError};
+use crate::span::{span_at_position, ItemKind, ItemSpan};
+use crate::transaction::{PooledTransaction, TxError};
+use crate::{decode_blob_portion_at_position, decode_whole_blob, limit_to, Error, ParsedData};
+
+/// EIP-2124 fork identifier.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ForkId {
+    /// CRC32 checksum of the genesis hash and past fork blocks.
+    pub hash: [u8; 4],
+    /// Block number or timestamp of the next fork, `0` if none is scheduled.
+    pub next: u64,
+}
+
+/// `Status` handshake message, `[protocol_version, network_id,
+/// total_difficulty, best_hash, genesis_hash, fork_id]`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Status {
+    pub protocol_version: u64,
+    pub network_id: u64,
+    pub total_difficulty: [u8; 32],
+    pub best_hash: [u8; 32],
+    pub genesis_hash: [u8; 32],
+    pub fork_id: ForkId,
+}
+
+/// `BlockHeaders` message, `[request_id, [header, ...]]` since eth/66.
+#[derive(Debug, Eq, PartialEq)]
+pub struct BlockHeadersMessage {
+    pub request_id: u64,
+    pub headers: Vec<Header>,
+}
+
+/// `PooledTransactions` message, `[request_id, [tx, ...]]` since eth/66.
+#[derive(Debug, Eq, PartialEq)]
+pub struct PooledTransactions {
+    pub request_id: u64,
+    pub transactions: Vec<PooledTransaction>,
+}
+
+/// `Receipts` message, `[request_id, [[receipt, ...], ...]]` since eth/66,
+/// receipts grouped by block.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ReceiptsMessage {
+    pub request_id: u64,
+    pub receipts: Vec<Vec<ReceiptEnvelope>>,
+}
+
+/// `Receipts` message with only the block lists located, see
+/// [`ReceiptsMessage::parse_lazy`].
+#[derive(Debug, Eq, PartialEq)]
+pub struct LazyReceiptsMessage {
+    pub request_id: u64,
+    /// Receipt list of each block.
+    pub blocks: Vec<ItemSpan>,
+}
+
+/// `Status` fields, used to locate errors.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatusField {
+    BestHash,
+    ForkHash,
+    ForkId,
+    ForkNext,
+    GenesisHash,
+    NetworkId,
+    ProtocolVersion,
+    TotalDifficulty,
+}
+
+/// Errors in decoding `eth` messages.
+#[derive(Debug, Eq, PartialEq)]
+pub enum MessageError<E: ExternalMemory> {
+    Decoding(Error<E>),
+    /// Item of `BlockHeaders` is not a list.
+    HeaderNotAList {
+        index: usize,
+    },
+    InvalidField {
+        field: StatusField,
+        reason: FieldError,
+    },
+    /// Header `index` of the message could not be parsed.
+    InvalidHeader {
+        index: usize,
+        source: HeaderError<E>,
+    },
+    /// Receipt `index` of block `block` could not be parsed.
+    InvalidReceipt {
+        block: usize,
+        index: usize,
+        source: ReceiptError<E>,
+    },
+    InvalidRequestId(FieldError),
+    /// Transaction `index` of the message could not be parsed.
+    InvalidTransaction {
+        index: usize,
+        source: TxError<E>,
+    },
+    NotAList,
+    /// Receipts of block `block` are not a list.
+    ReceiptsNotAList {
+        block: usize,
+    },
+    WrongFieldCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<E: ExternalMemory> From<Error<E>> for MessageError<E> {
+    fn from(error: Error<E>) -> Self {
+        MessageError::Decoding(error)
+    }
+}
+
+const STATUS_FIELD_COUNT: usize = 6;
+
+/// Decode `Status` message payload.
+pub fn decode_eth_status<B, E>(data: &B, ext_memory: &mut E) -> Result<Status, MessageError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let parsed_data = decode_whole_blob(data, ext_memory)?;
+    let fields = parsed_data.as_list().map_err(|_| MessageError::NotAList)?;
+    if fields.len() != STATUS_FIELD_COUNT {
+        return Err(MessageError::WrongFieldCount {
+            expected: STATUS_FIELD_COUNT,
+            found: fields.len(),
+        });
+    }
+    Ok(Status {
+        protocol_version: fields[0]
+            .as_strict_u64()
+            .map_err(invalid(StatusField::ProtocolVersion))?,
+        network_id: fields[1]
+            .as_strict_u64()
+            .map_err(invalid(StatusField::NetworkId))?,
+        total_difficulty: fields[2]
+            .as_strict_u256()
+            .map_err(invalid(StatusField::TotalDifficulty))?,
+        best_hash: fields[3]
+            .as_fixed_bytes::<32>()
+            .map_err(invalid(StatusField::BestHash))?,
+        genesis_hash: fields[4]
+            .as_fixed_bytes::<32>()
+            .map_err(invalid(StatusField::GenesisHash))?,
+        fork_id: parse_fork_id(&fields[5])?,
+    })
+}
+
+/// Decode `BlockHeaders` message payload of eth/65 and earlier, a bare list
+/// of block headers. Header fields present are detected.
+///
+/// Since eth/66 the list is wrapped as `[request_id, headers]`, see
+/// [`BlockHeadersMessage::parse`].
+pub fn decode_block_headers<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+) -> Result<Vec<Header>, MessageError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = 0;
+    let list = span_at_position(data, ext_memory, &mut position)?;
+    if position < data.total_len() {
+        return Err(MessageError::Decoding(Error::SomeDataUnused {
+            from: position,
+        }));
+    }
+    if list.kind != ItemKind::List {
+        return Err(MessageError::NotAList);
+    }
+    parse_headers(data, ext_memory, &list)
+}
+
+impl BlockHeadersMessage {
+    /// Decode `BlockHeaders` message payload. Header fields present are
+    /// detected.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, MessageError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let (request_id, list) = parse_request(data, ext_memory)?;
+        Ok(Self {
+            request_id,
+            headers: parse_headers(data, ext_memory, &list)?,
+        })
+    }
+}
+
+/// Parse the items of header list `list`.
+fn parse_headers<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    list: &ItemSpan,
+) -> Result<Vec<Header>, MessageError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    list.list_items(data, ext_memory)?
+        .iter()
+        .enumerate()
+        .map(|(index, span)| {
+            if span.kind != ItemKind::List {
+                return Err(MessageError::HeaderNotAList { index });
+            }
+            let mut position = span.start;
+            let parsed_data = decode_blob_portion_at_position(data, ext_memory, &mut position)?;
+            Header::from_parsed(&parsed_data, None)
+                .map_err(|source| MessageError::InvalidHeader { index, source })
+        })
+        .collect()
+}
+
+impl PooledTransactions {
+    /// Decode `PooledTransactions` message payload.
+    ///
+    /// Legacy transactions are lists, typed ones are strings, and blob
+    /// transactions are expected with their sidecars, though canonical blob
+    /// transactions are accepted as well.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, MessageError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let (request_id, list) = parse_request(data, ext_memory)?;
+        let list_data = limit_to(data, list.end)?;
+        let transactions = list
+            .list_items(data, ext_memory)?
+            .iter()
+            .enumerate()
+            .map(|(index, span)| {
+                PooledTransaction::from_span(&list_data, ext_memory, span)
+                    .map_err(|source| MessageError::InvalidTransaction { index, source })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            request_id,
+            transactions,
+        })
+    }
+}
+
+impl ReceiptsMessage {
+    /// Decode `Receipts` message payload.
+    ///
+    /// Receipts are parsed with the outcome kind detected, as in
+    /// [`Receipt::parse`](crate::receipt::Receipt::parse).
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, MessageError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let lazy = Self::parse_lazy(data, ext_memory)?;
+        let receipts = (0..lazy.blocks.len())
+            .map(|block| lazy.block(data, ext_memory, block))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            request_id: lazy.request_id,
+            receipts,
+        })
+    }
+
+    /// Locate receipt lists of the blocks without reading the receipts.
+    pub fn parse_lazy<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+    ) -> Result<LazyReceiptsMessage, MessageError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let (request_id, list) = parse_request(data, ext_memory)?;
+        let blocks = list.list_items(data, ext_memory)?;
+        if let Some(block) = blocks.iter().position(|a| a.kind != ItemKind::List) {
+            return Err(MessageError::ReceiptsNotAList { block });
+        }
+        Ok(LazyReceiptsMessage { request_id, blocks })
+    }
+}
+
+impl LazyReceiptsMessage {
+    /// Parse receipts of block `block`, `data` being the whole message.
+    ///
+    /// Panics if `block` is out of range of [`blocks`](Self::blocks).
+    pub fn block<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        block: usize,
+    ) -> Result<Vec<ReceiptEnvelope>, MessageError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let span = self.blocks[block];
+        let list_data = limit_to(data, span.end)?;
+        span.list_items(data, ext_memory)?
+            .iter()
+            .enumerate()
+            .map(|(index, receipt)| {
+                ReceiptEnvelope::from_span(&list_data, ext_memory, receipt).map_err(|source| {
+                    MessageError::InvalidReceipt {
+                        block,
+                        index,
+                        source,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+/// Read message `[request_id, list]` of eth/66 and later.
+fn parse_request<B, E>(data: &B, ext_memory: &mut E) -> Result<(u64, ItemSpan), MessageError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = 0;
+    let message = span_at_position(data, ext_memory, &mut position)?;
+    if position < data.total_len() {
+        return Err(MessageError::Decoding(Error::SomeDataUnused {
+            from: position,
+        }));
+    }
+    if message.kind != ItemKind::List {
+        return Err(MessageError::NotAList);
+    }
+    let items = message.list_items(data, ext_memory)?;
+    if items.len() != 2 {
+        return Err(MessageError::WrongFieldCount {
+            expected: 2,
+            found: items.len(),
+        });
+    }
+    let mut request_id_position = items[0].start;
+    let request_id = decode_blob_portion_at_position(data, ext_memory, &mut request_id_position)?
+        .as_strict_u64()
+        .map_err(MessageError::InvalidRequestId)?;
+    if items[1].kind != ItemKind::List {
+        return Err(MessageError::NotAList);
+    }
+    Ok((request_id, items[1]))
+}
+
+fn parse_fork_id<E: ExternalMemory>(parsed_data: &ParsedData) -> Result<ForkId, MessageError<E>> {
+    let fields = parsed_data
+        .as_list()
+        .map_err(invalid(StatusField::ForkId))?;
+    if fields.len() != 2 {
+        return Err(MessageError::InvalidField {
+            field: StatusField::ForkId,
+            reason: FieldError::WrongLength {
+                expected: 2,
+                found: fields.len(),
+            },
+        });
+    }
+    Ok(ForkId {
+        hash: fields[0]
+            .as_fixed_bytes::<4>()
+            .map_err(invalid(StatusField::ForkHash))?,
+        next: fields[1]
+            .as_strict_u64()
+            .map_err(invalid(StatusField::ForkNext))?,
+    })
+}
+
+fn invalid<E: ExternalMemory>(field: StatusField) -> impl FnOnce(FieldError) -> MessageError<E> {
+    move |reason| MessageError::InvalidField { field, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{
+        Eip658Value, Receipt as AlloyReceipt, ReceiptEnvelope as AlloyReceiptEnvelope,
+    };
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{Bytes, B256, U256};
+    use alloy_rlp::{Encodable, RlpEncodable};
+    use std::vec;
+
+    use super::*;
+    use crate::block::fixtures::{MAINNET_11117104, MAINNET_19449567};
+    use crate::encode::scalar;
+    use crate::transaction::{
+        Signature, TransactionEnvelope, TxBuilder, TxKind, BLOB_SIZE, KZG_COMMITMENT_SIZE,
+        KZG_PROOF_SIZE,
+    };
+
+    #[derive(RlpEncodable)]
+    struct RawForkId {
+        hash: Bytes,
+        next: u64,
+    }
+
+    #[derive(RlpEncodable)]
+    struct RawStatus {
+        protocol_version: u8,
+        network_id: u64,
+        total_difficulty: U256,
+        best_hash: B256,
+        genesis_hash: B256,
+        fork_id: RawForkId,
+    }
+
+    const GENESIS_HASH: &str = "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3";
+
+    fn raw_status(fork_hash: &[u8]) -> Vec<u8> {
+        let mut raw = Vec::new();
+        RawStatus {
+            protocol_version: 68,
+            network_id: 1,
+            total_difficulty: U256::from(58_750_003_716_598_352_816_469u128),
+            best_hash: B256::repeat_byte(0xbb),
+            genesis_hash: GENESIS_HASH.parse().unwrap(),
+            fork_id: RawForkId {
+                hash: Bytes::copy_from_slice(fork_hash),
+                next: 1_746_612_311,
+            },
+        }
+        .encode(&mut raw);
+        raw
+    }
+
+    #[test]
+    fn status() {
+        let raw = raw_status(&[0x9f, 0x3d, 0x22, 0x54]);
+        let status = decode_eth_status::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        let mut total_difficulty = [0; 32];
+        total_difficulty[22..]
+            .copy_from_slice(&58_750_003_716_598_352_816_469u128.to_be_bytes()[6..]);
+        assert_eq!(
+            status,
+            Status {
+                protocol_version: 68,
+                network_id: 1,
+                total_difficulty,
+                best_hash: [0xbb; 32],
+                genesis_hash: hex::decode(GENESIS_HASH).unwrap().try_into().unwrap(),
+                fork_id: ForkId {
+                    hash: [0x9f, 0x3d, 0x22, 0x54],
+                    next: 1_746_612_311,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn status_errors() {
+        let raw = raw_status(&[0x9f, 0x3d, 0x22]);
+        assert_eq!(
+            decode_eth_status::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(MessageError::InvalidField {
+                field: StatusField::ForkHash,
+                reason: FieldError::WrongLength {
+                    expected: 4,
+                    found: 3
+                }
+            })
+        );
+        assert_eq!(
+            decode_eth_status::<&[u8], ()>(&[0xc2, 0x44, 0x01].as_ref(), &mut ()),
+            Err(MessageError::WrongFieldCount {
+                expected: 6,
+                found: 2
+            })
+        );
+    }
+
+    const LEGACY: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    fn signature() -> Signature {
+        Signature {
+            r: [0x01; 32],
+            s: [0x01; 32],
+            y_parity: 0,
+        }
+    }
+
+    fn eip1559() -> TransactionEnvelope {
+        TxBuilder::eip1559()
+            .chain_id(1)
+            .nonce(1)
+            .max_priority_fee_per_gas([0x01; 32])
+            .max_fee_per_gas([0x02; 32])
+            .gas_limit(21_000)
+            .to(TxKind::Call([0x22; 20]))
+            .build_signed(signature())
+            .unwrap()
+    }
+
+    fn eip4844() -> TransactionEnvelope {
+        TxBuilder::eip4844()
+            .chain_id(1)
+            .nonce(2)
+            .max_priority_fee_per_gas([0x01; 32])
+            .max_fee_per_gas([0x02; 32])
+            .gas_limit(21_000)
+            .to(TxKind::Call([0x33; 20]))
+            .max_fee_per_blob_gas([0x03; 32])
+            .blob_versioned_hashes(vec![[0x01; 32]])
+            .build_signed(signature())
+            .unwrap()
+    }
+
+    /// Network form `0x03 || rlp([body, blobs, commitments, proofs])`.
+    fn blob_wrapped(tx: &TransactionEnvelope) -> Vec<u8> {
+        let body = tx.encode();
+        let body = decode_whole_blob::<&[u8], ()>(&&body[1..], &mut ()).unwrap();
+        let sidecar_list = |item: Vec<u8>| ParsedData::List(vec![ParsedData::String(item)]);
+        let mut raw = vec![0x03];
+        raw.extend(
+            ParsedData::List(vec![
+                body,
+                sidecar_list(vec![0xb0; BLOB_SIZE]),
+                sidecar_list(vec![0xc0; KZG_COMMITMENT_SIZE]),
+                sidecar_list(vec![0xd0; KZG_PROOF_SIZE]),
+            ])
+            .to_vec(),
+        );
+        raw
+    }
+
+    fn pooled_transactions(request_id: u64, transactions: Vec<ParsedData>) -> Vec<u8> {
+        ParsedData::List(vec![
+            scalar(&request_id.to_be_bytes()),
+            ParsedData::List(transactions),
+        ])
+        .to_vec()
+    }
+
+    #[test]
+    fn pooled_transactions_mixed() {
+        let legacy_raw = hex::decode(LEGACY).unwrap();
+        let legacy = decode_whole_blob::<&[u8], ()>(&legacy_raw.as_ref(), &mut ()).unwrap();
+        let raw = pooled_transactions(
+            0x0102,
+            vec![
+                legacy,
+                ParsedData::String(eip1559().encode()),
+                ParsedData::String(blob_wrapped(&eip4844())),
+            ],
+        );
+        let message = PooledTransactions::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(message.request_id, 0x0102);
+        assert_eq!(message.transactions.len(), 3);
+        assert_eq!(
+            message.transactions[0],
+            PooledTransaction::Canonical(
+                TransactionEnvelope::parse::<&[u8], ()>(&legacy_raw.as_ref(), &mut ()).unwrap()
+            )
+        );
+        assert_eq!(
+            message.transactions[1],
+            PooledTransaction::Canonical(eip1559())
+        );
+        let PooledTransaction::BlobWrapped(pooled) = &message.transactions[2] else {
+            panic!("expected blob transaction with sidecar");
+        };
+        assert_eq!(
+            TransactionEnvelope::Eip4844(pooled.transaction.clone()),
+            eip4844()
+        );
+        assert_eq!(pooled.sidecar.len(), 1);
+        let blob = pooled.sidecar.blob(0).unwrap();
+        assert_eq!(blob.payload_len(), BLOB_SIZE);
+        assert_eq!(raw[blob.payload_start], 0xb0);
+
+        // Blob transaction without sidecar.
+        let raw = pooled_transactions(7, vec![ParsedData::String(eip4844().encode())]);
+        assert_eq!(
+            PooledTransactions::parse::<&[u8], ()>(&raw.as_ref(), &mut ())
+                .unwrap()
+                .transactions,
+            vec![PooledTransaction::Canonical(eip4844())]
+        );
+    }
+
+    #[test]
+    fn pooled_transactions_errors() {
+        let mut broken = blob_wrapped(&eip4844());
+        // Drop the last byte of the proof: the wrapper no longer parses.
+        broken.pop();
+        let raw = pooled_transactions(
+            1,
+            vec![
+                ParsedData::String(eip1559().encode()),
+                ParsedData::String(broken),
+            ],
+        );
+        assert!(matches!(
+            PooledTransactions::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(MessageError::InvalidTransaction { index: 1, .. })
+        ));
+
+        let raw = ParsedData::List(vec![
+            ParsedData::String(vec![0x00, 0x01]),
+            ParsedData::List(Vec::new()),
+        ])
+        .to_vec();
+        assert_eq!(
+            PooledTransactions::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(MessageError::InvalidRequestId(FieldError::LeadingZero))
+        );
+        assert_eq!(
+            PooledTransactions::parse::<&[u8], ()>(&[0xc1, 0x01].as_ref(), &mut ()),
+            Err(MessageError::WrongFieldCount {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    fn receipt(cumulative_gas_used: u64) -> AlloyReceipt {
+        AlloyReceipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used,
+            logs: Vec::new(),
+        }
+    }
+
+    /// Receipt as a message item: legacy receipts are lists, typed ones are
+    /// strings.
+    fn receipt_item(envelope: AlloyReceiptEnvelope) -> ParsedData {
+        let raw = envelope.encoded_2718();
+        match envelope {
+            AlloyReceiptEnvelope::Legacy(_) => {
+                decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+            }
+            _ => ParsedData::String(raw),
+        }
+    }
+
+    fn receipts_message(corrupted: bool) -> Vec<u8> {
+        let mut third = AlloyReceiptEnvelope::Eip1559(receipt(63_000).with_bloom()).encoded_2718();
+        if corrupted {
+            third[0] = 0x05;
+        }
+        ParsedData::List(vec![
+            scalar(&[0x2a]),
+            ParsedData::List(vec![
+                ParsedData::List(vec![receipt_item(AlloyReceiptEnvelope::Legacy(
+                    receipt(21_000).with_bloom(),
+                ))]),
+                ParsedData::List(vec![
+                    receipt_item(AlloyReceiptEnvelope::Eip2930(receipt(21_000).with_bloom())),
+                    receipt_item(AlloyReceiptEnvelope::Eip4844(receipt(42_000).with_bloom())),
+                    ParsedData::String(third),
+                ]),
+            ]),
+        ])
+        .to_vec()
+    }
+
+    #[test]
+    fn receipts() {
+        let raw = receipts_message(false);
+        let message = ReceiptsMessage::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(message.request_id, 0x2a);
+        let types = message
+            .receipts
+            .iter()
+            .map(|block| block.iter().map(|a| a.tx_type()).collect::<Vec<u8>>())
+            .collect::<Vec<_>>();
+        assert_eq!(types, vec![vec![0], vec![1, 3, 2]]);
+        assert_eq!(message.receipts[1][2].receipt().cumulative_gas_used, 63_000);
+
+        let raw = receipts_message(true);
+        assert_eq!(
+            ReceiptsMessage::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(MessageError::InvalidReceipt {
+                block: 1,
+                index: 2,
+                source: ReceiptError::UnknownTransactionType { type_byte: 0x05 }
+            })
+        );
+
+        // Blocks are located without reading receipts; the first block is
+        // fine on its own.
+        let lazy = ReceiptsMessage::parse_lazy::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(lazy.blocks.len(), 2);
+        assert_eq!(
+            lazy.block::<&[u8], ()>(&raw.as_ref(), &mut (), 0).unwrap()[0].tx_type(),
+            0
+        );
+        assert!(matches!(
+            lazy.block::<&[u8], ()>(&raw.as_ref(), &mut (), 1),
+            Err(MessageError::InvalidReceipt {
+                block: 1,
+                index: 2,
+                ..
+            })
+        ));
+
+        let raw = ParsedData::List(vec![
+            scalar(&[0x2a]),
+            ParsedData::List(vec![ParsedData::List(Vec::new()), scalar(&[0x01])]),
+        ])
+        .to_vec();
+        assert_eq!(
+            ReceiptsMessage::parse_lazy::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(MessageError::ReceiptsNotAList { block: 1 })
+        );
+    }
+
+    fn mainnet_headers() -> Vec<ParsedData> {
+        [MAINNET_11117104, MAINNET_19449567]
+            .iter()
+            .map(|header| {
+                let raw = hex::decode(header).unwrap();
+                decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn block_headers() {
+        let expected: Vec<Header> = [MAINNET_11117104, MAINNET_19449567]
+            .iter()
+            .map(|header| {
+                Header::parse::<&[u8], ()>(&hex::decode(header).unwrap().as_ref(), &mut ()).unwrap()
+            })
+            .collect();
+        assert_eq!(expected[0].number, 11_117_104);
+        assert_eq!(expected[1].number, 19_449_567);
+        assert!(expected[1].parent_beacon_block_root.is_some());
+
+        let raw = ParsedData::List(mainnet_headers()).to_vec();
+        assert_eq!(
+            decode_block_headers::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Ok(expected)
+        );
+        assert_eq!(
+            decode_block_headers::<&[u8], ()>(&[0xc0].as_ref(), &mut ()),
+            Ok(Vec::new())
+        );
+
+        let mut headers = mainnet_headers();
+        headers[1] = scalar(&[0x03]);
+        assert_eq!(
+            decode_block_headers::<&[u8], ()>(
+                &ParsedData::List(headers).to_vec().as_ref(),
+                &mut ()
+            ),
+            Err(MessageError::HeaderNotAList { index: 1 })
+        );
+        let mut headers = mainnet_headers();
+        headers[1] = ParsedData::List(vec![scalar(&[0x01]), scalar(&[0x02])]);
+        assert_eq!(
+            decode_block_headers::<&[u8], ()>(
+                &ParsedData::List(headers).to_vec().as_ref(),
+                &mut ()
+            ),
+            Err(MessageError::InvalidHeader {
+                index: 1,
+                source: HeaderError::InvalidFieldCount { found: 2 }
+            })
+        );
+        assert_eq!(
+            decode_block_headers::<&[u8], ()>(&[0x80].as_ref(), &mut ()),
+            Err(MessageError::NotAList)
+        );
+    }
+
+    #[test]
+    fn block_headers_message() {
+        let raw =
+            ParsedData::List(vec![scalar(&[0x2a]), ParsedData::List(mainnet_headers())]).to_vec();
+        let message = BlockHeadersMessage::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(message.request_id, 42);
+        assert_eq!(
+            message.headers,
+            decode_block_headers::<&[u8], ()>(
+                &ParsedData::List(mainnet_headers()).to_vec().as_ref(),
+                &mut ()
+            )
+            .unwrap()
+        );
+
+        let raw = ParsedData::List(vec![scalar(&[0x2a]), scalar(&[0x01])]).to_vec();
+        assert_eq!(
+            BlockHeadersMessage::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(MessageError::NotAList)
+        );
+    }
+}
@@ -0,0 +1,379 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::{decode_whole_blob, Error, ParsedData};
+
+/// Errors in interpreting a single decoded item as a typed field.
+#[derive(Debug, Eq, PartialEq)]
+pub enum FieldError {
+    EmptyString,
+    ExpectedList,
+    ExpectedString,
+    /// String item content is not a valid RLP blob. Positions are within the
+    /// string content.
+    InvalidInner(Error<()>),
+    LeadingZero,
+    ScalarTooLong {
+        max_length: usize,
+        found: usize,
+    },
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+    WrongListLength {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<S: AsRef<[u8]>> ParsedData<S> {
+    /// Bytes of a string item. Single byte items are treated as one byte
+    /// strings.
+    pub fn as_bytes(&self) -> Result<&[u8], FieldError> {
+        match self {
+            ParsedData::Byte(a) => Ok(core::slice::from_ref(a)),
+            ParsedData::List(_) => Err(FieldError::ExpectedString),
+            ParsedData::String(a) => Ok(a.as_ref()),
+        }
+    }
+
+    /// Bytes of a string item, like [`as_bytes`](Self::as_bytes), but
+    /// the empty string is rejected.
+    pub fn try_as_nonempty_bytes(&self) -> Result<&[u8], FieldError> {
+        match self.as_bytes()? {
+            [] => Err(FieldError::EmptyString),
+            a => Ok(a),
+        }
+    }
+
+    /// Elements of a list item.
+    pub fn as_list(&self) -> Result<&[ParsedData<S>], FieldError> {
+        match self {
+            ParsedData::List(a) => Ok(a),
+            _ => Err(FieldError::ExpectedList),
+        }
+    }
+
+    /// Elements of a list item of exactly `n` elements.
+    pub fn expect_list_len(&self, n: usize) -> Result<&[ParsedData<S>], FieldError> {
+        let list = self.as_list()?;
+        if list.len() != n {
+            return Err(FieldError::WrongListLength {
+                expected: n,
+                found: list.len(),
+            });
+        }
+        Ok(list)
+    }
+
+    /// Empty string `0x80`, such as `to` of a contract creation.
+    pub fn is_empty_string(&self) -> bool {
+        matches!(self, ParsedData::String(a) if a.as_ref().is_empty())
+    }
+
+    /// Empty list `0xc0`, such as an empty ommers list.
+    pub fn is_empty_list(&self) -> bool {
+        matches!(self, ParsedData::List(a) if a.is_empty())
+    }
+
+    /// String item of exactly `N` bytes.
+    pub fn as_fixed_bytes<const N: usize>(&self) -> Result<[u8; N], FieldError> {
+        let bytes = self.as_bytes()?;
+        bytes.try_into().map_err(|_| FieldError::WrongLength {
+            expected: N,
+            found: bytes.len(),
+        })
+    }
+
+    /// Scalar of at most 1 byte.
+    pub fn as_u8(&self) -> Result<u8, FieldError> {
+        let [a] = self.as_padded_scalar::<1>()?;
+        Ok(a)
+    }
+
+    /// Big-endian scalar of at most 8 bytes.
+    pub fn as_u64(&self) -> Result<u64, FieldError> {
+        let padded = self.as_padded_scalar::<8>()?;
+        Ok(u64::from_be_bytes(padded))
+    }
+
+    /// Big-endian scalar of at most 32 bytes, left-padded with zeroes.
+    pub fn as_u256(&self) -> Result<[u8; 32], FieldError> {
+        self.as_padded_scalar::<32>()
+    }
+
+    /// Canonical scalar of at most 1 byte, see [`as_strict_u256`](Self::as_strict_u256).
+    pub fn as_strict_u8(&self) -> Result<u8, FieldError> {
+        self.check_no_leading_zero()?;
+        self.as_u8()
+    }
+
+    /// Canonical big-endian scalar of at most 8 bytes, see
+    /// [`as_strict_u256`](Self::as_strict_u256).
+    pub fn as_strict_u64(&self) -> Result<u64, FieldError> {
+        self.check_no_leading_zero()?;
+        self.as_u64()
+    }
+
+    /// Canonical big-endian scalar of at most 32 bytes.
+    ///
+    /// Integers in Ethereum structures are encoded without leading zeroes,
+    /// with zero itself encoded as the empty string. Single `0x00` byte is
+    /// therefore rejected as well.
+    pub fn as_strict_u256(&self) -> Result<[u8; 32], FieldError> {
+        self.check_no_leading_zero()?;
+        self.as_u256()
+    }
+
+    /// String item of exactly 20 bytes as an alloy [`Address`](alloy_primitives::Address).
+    #[cfg(feature = "alloy")]
+    pub fn as_alloy_address(&self) -> Result<alloy_primitives::Address, FieldError> {
+        self.as_fixed_bytes::<20>()
+            .map(alloy_primitives::Address::new)
+    }
+
+    /// String item of exactly 32 bytes as an alloy [`B256`](alloy_primitives::B256).
+    #[cfg(feature = "alloy")]
+    pub fn as_alloy_b256(&self) -> Result<alloy_primitives::B256, FieldError> {
+        self.as_fixed_bytes::<32>().map(alloy_primitives::B256::new)
+    }
+
+    /// Scalar as an alloy [`U256`](alloy_primitives::U256), like
+    /// [`as_u256`](Self::as_u256).
+    #[cfg(feature = "alloy")]
+    pub fn as_alloy_u256(&self) -> Result<alloy_primitives::U256, FieldError> {
+        self.as_u256().map(alloy_primitives::U256::from_be_bytes)
+    }
+
+    /// String item copied into a new `Vec`.
+    pub fn to_bytes_vec(&self) -> Result<Vec<u8>, FieldError> {
+        Ok(self.as_bytes()?.to_vec())
+    }
+
+    /// Decode string item content as a nested RLP blob.
+    pub fn decode_inner(&self) -> Result<ParsedData, FieldError> {
+        let bytes = self.as_bytes()?;
+        decode_whole_blob::<&[u8], ()>(&bytes, &mut ()).map_err(FieldError::InvalidInner)
+    }
+
+    fn check_no_leading_zero(&self) -> Result<(), FieldError> {
+        match self.as_bytes()?.first() {
+            Some(0) => Err(FieldError::LeadingZero),
+            _ => Ok(()),
+        }
+    }
+
+    fn as_padded_scalar<const N: usize>(&self) -> Result<[u8; N], FieldError> {
+        let bytes = self.as_bytes()?;
+        if bytes.len() > N {
+            return Err(FieldError::ScalarTooLong {
+                max_length: N,
+                found: bytes.len(),
+            });
+        }
+        let mut padded = [0; N];
+        padded[N - bytes.len()..].copy_from_slice(bytes);
+        Ok(padded)
+    }
+}
+
+impl ParsedData {
+    /// Owned bytes of a string item, without copying. Lists are returned
+    /// back unchanged.
+    pub fn into_bytes(self) -> Result<Vec<u8>, ParsedData> {
+        match self {
+            ParsedData::Byte(a) => Ok(Vec::from([a])),
+            ParsedData::List(_) => Err(self),
+            ParsedData::String(a) => Ok(a),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use external_memory_tools::BufferError;
+    use std::{boxed::Box, vec};
+
+    use super::*;
+
+    #[test]
+    fn into_bytes() {
+        assert_eq!(ParsedData::Byte(0x05).into_bytes(), Ok(vec![0x05]));
+        assert_eq!(
+            ParsedData::String(vec![0x80, 0x81]).into_bytes(),
+            Ok(vec![0x80, 0x81])
+        );
+        let list = ParsedData::List(vec![ParsedData::Byte(1)]);
+        assert_eq!(
+            list.into_bytes(),
+            Err(ParsedData::List(vec![ParsedData::Byte(1)]))
+        );
+    }
+
+    #[test]
+    fn nonempty_bytes() {
+        assert_eq!(
+            <ParsedData>::Byte(0x00).try_as_nonempty_bytes(),
+            Ok(&[0x00][..])
+        );
+        assert_eq!(
+            ParsedData::String(vec![0x80, 0x81]).try_as_nonempty_bytes(),
+            Ok(&[0x80, 0x81][..])
+        );
+        assert_eq!(
+            ParsedData::String(Vec::new()).try_as_nonempty_bytes(),
+            Err(FieldError::EmptyString)
+        );
+        assert_eq!(
+            <ParsedData>::List(Vec::new()).try_as_nonempty_bytes(),
+            Err(FieldError::ExpectedString)
+        );
+    }
+
+    #[test]
+    fn empty_items() {
+        let empty_string = <ParsedData>::String(Vec::new());
+        let empty_list = <ParsedData>::List(Vec::new());
+        assert!(empty_string.is_empty_string());
+        assert!(!empty_string.is_empty_list());
+        assert!(empty_list.is_empty_list());
+        assert!(!empty_list.is_empty_string());
+        assert!(!<ParsedData>::Byte(0x00).is_empty_string());
+        assert!(!ParsedData::String(vec![0x00]).is_empty_string());
+        assert!(!ParsedData::List(vec![empty_list]).is_empty_list());
+    }
+
+    #[test]
+    fn expect_list_len() {
+        let list = ParsedData::List(vec![ParsedData::Byte(1), ParsedData::String(vec![0x80])]);
+        assert_eq!(list.expect_list_len(2).map(<[_]>::len), Ok(2));
+        assert_eq!(
+            list.expect_list_len(3),
+            Err(FieldError::WrongListLength {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            <ParsedData>::Byte(1).expect_list_len(1),
+            Err(FieldError::ExpectedList)
+        );
+        assert_eq!(
+            <ParsedData>::List(Vec::new()).expect_list_len(0),
+            Ok(&[][..])
+        );
+    }
+
+    #[test]
+    fn decode_inner() {
+        let nested = ParsedData::String(vec![0xc2, 0x01, 0x82]);
+        assert_eq!(
+            nested.decode_inner(),
+            Err(FieldError::InvalidInner(Error::InList {
+                depth: 0,
+                index: 1,
+                source: Box::new(Error::Buffer(BufferError::DataTooShort {
+                    position: 3,
+                    minimal_length: 2
+                }))
+            }))
+        );
+        let nested = ParsedData::String(vec![0xc3, 0x01, 0x81, 0x80]);
+        assert_eq!(
+            nested.decode_inner(),
+            Ok(ParsedData::List(vec![
+                ParsedData::Byte(1),
+                ParsedData::String(vec![0x80])
+            ]))
+        );
+        assert_eq!(
+            <ParsedData>::Byte(0x05).decode_inner(),
+            Ok(ParsedData::Byte(0x05))
+        );
+        assert_eq!(
+            ParsedData::String(vec![0x01, 0x02]).decode_inner(),
+            Err(FieldError::InvalidInner(Error::SomeDataUnused { from: 1 }))
+        );
+        assert_eq!(
+            <ParsedData>::List(Vec::new()).decode_inner(),
+            Err(FieldError::ExpectedString)
+        );
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(ParsedData::String(Vec::new()).as_u64(), Ok(0));
+        assert_eq!(<ParsedData>::Byte(0x7f).as_u64(), Ok(0x7f));
+        assert_eq!(ParsedData::String(vec![0x03, 0xe8]).as_u64(), Ok(1000));
+        assert_eq!(
+            ParsedData::String(vec![1; 9]).as_u64(),
+            Err(FieldError::ScalarTooLong {
+                max_length: 8,
+                found: 9
+            })
+        );
+        assert_eq!(
+            <ParsedData>::List(Vec::new()).as_u64(),
+            Err(FieldError::ExpectedString)
+        );
+    }
+
+    #[test]
+    fn strict_scalars_reject_leading_zero() {
+        assert_eq!(ParsedData::String(Vec::new()).as_strict_u64(), Ok(0));
+        assert_eq!(
+            <ParsedData>::Byte(0).as_strict_u64(),
+            Err(FieldError::LeadingZero)
+        );
+        assert_eq!(
+            ParsedData::String(vec![0x00, 0x01]).as_strict_u256(),
+            Err(FieldError::LeadingZero)
+        );
+        assert_eq!(ParsedData::String(vec![0x00, 0x01]).as_u64(), Ok(1));
+    }
+
+    #[cfg(feature = "alloy")]
+    #[test]
+    fn alloy_conversions() {
+        use alloy_primitives::{Address, B256, U256};
+
+        assert_eq!(
+            ParsedData::String(vec![0xaa; 20]).as_alloy_address(),
+            Ok(Address::repeat_byte(0xaa))
+        );
+        assert_eq!(
+            ParsedData::String(vec![0xaa; 19]).as_alloy_address(),
+            Err(FieldError::WrongLength {
+                expected: 20,
+                found: 19
+            })
+        );
+        assert_eq!(
+            ParsedData::String(vec![0xbb; 32]).as_alloy_b256(),
+            Ok(B256::repeat_byte(0xbb))
+        );
+        assert_eq!(
+            <ParsedData>::List(Vec::new()).as_alloy_b256(),
+            Err(FieldError::ExpectedString)
+        );
+        assert_eq!(
+            ParsedData::String(vec![0x01, 0x00, 0x00]).as_alloy_u256(),
+            Ok(U256::from(0x010000u64))
+        );
+        assert_eq!(
+            ParsedData::String(Vec::new()).as_alloy_u256(),
+            Ok(U256::ZERO)
+        );
+        assert_eq!(
+            ParsedData::String(vec![1; 33]).as_alloy_u256(),
+            Err(FieldError::ScalarTooLong {
+                max_length: 32,
+                found: 33
+            })
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Protocol upgrades that changed encoding or validation rules.
+
+/// Ethereum protocol fork, in activation order.
+///
+/// Forks compare by activation order, so `fork >= Fork::Istanbul` reads as
+/// "Istanbul rules are active".
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Fork {
+    Frontier,
+    Homestead,
+    TangerineWhistle,
+    SpuriousDragon,
+    Byzantium,
+    Constantinople,
+    Petersburg,
+    Istanbul,
+    Berlin,
+    London,
+    Paris,
+    Shanghai,
+    Cancun,
+    Prague,
+}
+
+/// Networks with known fork schedules, see [`Fork::from_block_and_time`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum KnownChain {
+    Mainnet,
+    Sepolia,
+    Holesky,
+    Hoodi,
+}
+
+/// Fork activation condition.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Activation {
+    /// Active from this block number.
+    Block(u64),
+    /// Active from this block timestamp, for forks since Shanghai.
+    Timestamp(u64),
+}
+
+const MAINNET: &[(Fork, Activation)] = &[
+    (Fork::Frontier, Activation::Block(0)),
+    (Fork::Homestead, Activation::Block(1_150_000)),
+    (Fork::TangerineWhistle, Activation::Block(2_463_000)),
+    (Fork::SpuriousDragon, Activation::Block(2_675_000)),
+    (Fork::Byzantium, Activation::Block(4_370_000)),
+    (Fork::Constantinople, Activation::Block(7_280_000)),
+    (Fork::Petersburg, Activation::Block(7_280_000)),
+    (Fork::Istanbul, Activation::Block(9_069_000)),
+    (Fork::Berlin, Activation::Block(12_244_000)),
+    (Fork::London, Activation::Block(12_965_000)),
+    (Fork::Paris, Activation::Block(15_537_394)),
+    (Fork::Shanghai, Activation::Timestamp(1_681_338_455)),
+    (Fork::Cancun, Activation::Timestamp(1_710_338_135)),
+    (Fork::Prague, Activation::Timestamp(1_746_612_311)),
+];
+
+const SEPOLIA: &[(Fork, Activation)] = &[
+    (Fork::London, Activation::Block(0)),
+    (Fork::Paris, Activation::Block(1_450_409)),
+    (Fork::Shanghai, Activation::Timestamp(1_677_557_088)),
+    (Fork::Cancun, Activation::Timestamp(1_706_655_072)),
+    (Fork::Prague, Activation::Timestamp(1_741_159_776)),
+];
+
+const HOLESKY: &[(Fork, Activation)] = &[
+    (Fork::Paris, Activation::Block(0)),
+    (Fork::Shanghai, Activation::Timestamp(1_696_000_704)),
+    (Fork::Cancun, Activation::Timestamp(1_707_305_664)),
+    (Fork::Prague, Activation::Timestamp(1_740_434_112)),
+];
+
+const HOODI: &[(Fork, Activation)] = &[
+    (Fork::Cancun, Activation::Timestamp(0)),
+    (Fork::Prague, Activation::Timestamp(1_742_999_832)),
+];
+
+impl KnownChain {
+    /// Fork activations in order. Forks preceding the first entry were
+    /// active from genesis.
+    pub fn forks(&self) -> &'static [(Fork, Activation)] {
+        match self {
+            KnownChain::Mainnet => MAINNET,
+            KnownChain::Sepolia => SEPOLIA,
+            KnownChain::Holesky => HOLESKY,
+            KnownChain::Hoodi => HOODI,
+        }
+    }
+}
+
+impl Fork {
+    /// Fork active on `chain` at block `number` with block `timestamp`.
+    pub fn from_block_and_time(chain: KnownChain, number: u64, timestamp: u64) -> Fork {
+        chain
+            .forks()
+            .iter()
+            .take_while(|(_, activation)| match activation {
+                Activation::Block(block) => number >= *block,
+                Activation::Timestamp(time) => timestamp >= *time,
+            })
+            .last()
+            .map(|(fork, _)| *fork)
+            .unwrap_or(Fork::Frontier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mainnet_boundaries() {
+        let fork =
+            |number, timestamp| Fork::from_block_and_time(KnownChain::Mainnet, number, timestamp);
+        assert_eq!(fork(0, 0), Fork::Frontier);
+        assert_eq!(fork(1_149_999, 1_457_981_342), Fork::Frontier);
+        assert_eq!(fork(1_150_000, 1_457_981_393), Fork::Homestead);
+        assert_eq!(fork(4_370_000, 1_508_131_331), Fork::Byzantium);
+        assert_eq!(fork(7_280_000, 1_551_383_524), Fork::Petersburg);
+        assert_eq!(fork(12_964_999, 1_628_166_812), Fork::Berlin);
+        assert_eq!(fork(12_965_000, 1_628_166_822), Fork::London);
+        assert_eq!(fork(15_537_393, 1_663_224_162), Fork::London);
+        assert_eq!(fork(15_537_394, 1_663_224_179), Fork::Paris);
+        assert_eq!(fork(17_034_869, 1_681_338_443), Fork::Paris);
+        assert_eq!(fork(17_034_870, 1_681_338_455), Fork::Shanghai);
+        assert_eq!(fork(19_426_586, 1_710_338_123), Fork::Shanghai);
+        assert_eq!(fork(19_426_587, 1_710_338_135), Fork::Cancun);
+        assert_eq!(fork(22_431_084, 1_746_612_311), Fork::Prague);
+    }
+
+    #[test]
+    fn testnets() {
+        assert_eq!(
+            Fork::from_block_and_time(KnownChain::Sepolia, 0, 1_633_267_481),
+            Fork::London
+        );
+        assert_eq!(
+            Fork::from_block_and_time(KnownChain::Sepolia, 5_187_023, 1_706_655_072),
+            Fork::Cancun
+        );
+        assert_eq!(
+            Fork::from_block_and_time(KnownChain::Holesky, 0, 1_695_902_400),
+            Fork::Paris
+        );
+        assert_eq!(
+            Fork::from_block_and_time(KnownChain::Hoodi, 0, 1_742_213_400),
+            Fork::Cancun
+        );
+        assert_eq!(
+            Fork::from_block_and_time(KnownChain::Hoodi, 60_412, 1_742_999_832),
+            Fork::Prague
+        );
+    }
+}
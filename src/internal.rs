@@ -0,0 +1,213 @@
+//! Compact internal format for caching decoded [`ParsedData`] trees.
+//!
+//! Unlike RLP, the format stores the tree exactly as built, so
+//! [`ParsedData::Byte`] and one byte [`ParsedData::String`] stay distinct.
+//! Each node is a tag byte followed by:
+//!
+//! - `0x00`: the byte itself, for [`ParsedData::Byte`],
+//! - `0x01`: LEB128 length and the string bytes, for [`ParsedData::String`],
+//! - `0x02`: LEB128 element count and the elements, for [`ParsedData::List`].
+//!
+//! The format is not stable across crate versions.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::ParsedData;
+
+const TAG_BYTE: u8 = 0x00;
+const TAG_STRING: u8 = 0x01;
+const TAG_LIST: u8 = 0x02;
+
+/// Errors in reading the internal format.
+#[derive(Debug, Eq, PartialEq)]
+pub enum InternalFormatError {
+    /// Length does not fit into `usize`.
+    LengthOverflow {
+        position: usize,
+    },
+    SomeDataUnused {
+        from: usize,
+    },
+    UnexpectedEnd,
+    UnknownTag {
+        position: usize,
+        tag: u8,
+    },
+}
+
+impl ParsedData {
+    /// Serialize the tree into the internal format.
+    pub fn encode_internal(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_internal_into(&mut out);
+        out
+    }
+
+    /// Deserialize a tree written by [`encode_internal`](Self::encode_internal).
+    pub fn decode_internal(data: &[u8]) -> Result<Self, InternalFormatError> {
+        let mut position = 0;
+        let parsed_data = read_node(data, &mut position)?;
+        if position < data.len() {
+            Err(InternalFormatError::SomeDataUnused { from: position })
+        } else {
+            Ok(parsed_data)
+        }
+    }
+
+    fn encode_internal_into(&self, out: &mut Vec<u8>) {
+        match self {
+            ParsedData::Byte(a) => out.extend_from_slice(&[TAG_BYTE, *a]),
+            ParsedData::String(a) => {
+                out.push(TAG_STRING);
+                write_length(out, a.len());
+                out.extend_from_slice(a);
+            }
+            ParsedData::List(a) => {
+                out.push(TAG_LIST);
+                write_length(out, a.len());
+                a.iter().for_each(|item| item.encode_internal_into(out));
+            }
+        }
+    }
+}
+
+fn read_node(data: &[u8], position: &mut usize) -> Result<ParsedData, InternalFormatError> {
+    let tag = read_byte(data, position)?;
+    match tag {
+        TAG_BYTE => Ok(ParsedData::Byte(read_byte(data, position)?)),
+        TAG_STRING => {
+            let length = read_length(data, position)?;
+            let end = position
+                .checked_add(length)
+                .filter(|end| *end <= data.len())
+                .ok_or(InternalFormatError::UnexpectedEnd)?;
+            let string = data[*position..end].to_vec();
+            *position = end;
+            Ok(ParsedData::String(string))
+        }
+        TAG_LIST => {
+            let count = read_length(data, position)?;
+            // Every element takes at least two bytes; cap the allocation for
+            // corrupted counts.
+            let mut list = Vec::with_capacity(count.min((data.len() - *position) / 2));
+            for _ in 0..count {
+                list.push(read_node(data, position)?);
+            }
+            Ok(ParsedData::List(list))
+        }
+        tag => Err(InternalFormatError::UnknownTag {
+            position: *position - 1,
+            tag,
+        }),
+    }
+}
+
+fn read_byte(data: &[u8], position: &mut usize) -> Result<u8, InternalFormatError> {
+    let byte = *data
+        .get(*position)
+        .ok_or(InternalFormatError::UnexpectedEnd)?;
+    *position += 1;
+    Ok(byte)
+}
+
+fn write_length(out: &mut Vec<u8>, mut length: usize) {
+    while length >= 0x80 {
+        out.push(length as u8 | 0x80);
+        length >>= 7;
+    }
+    out.push(length as u8);
+}
+
+fn read_length(data: &[u8], position: &mut usize) -> Result<usize, InternalFormatError> {
+    let start = *position;
+    let mut length = 0usize;
+    let mut shift = 0;
+    loop {
+        let byte = read_byte(data, position)?;
+        let bits = (byte & 0x7f) as usize;
+        if shift >= usize::BITS || (bits << shift) >> shift != bits {
+            return Err(InternalFormatError::LengthOverflow { position: start });
+        }
+        length |= bits << shift;
+        if byte < 0x80 {
+            return Ok(length);
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+    use crate::decode_whole_blob;
+
+    #[test]
+    fn round_trip() {
+        let tree = ParsedData::List(vec![
+            ParsedData::Byte(0x05),
+            ParsedData::Byte(0x80),
+            ParsedData::String(vec![0x05]),
+            ParsedData::String(vec![0xaa; 300]),
+            ParsedData::List(vec![
+                ParsedData::List(Vec::new()),
+                ParsedData::String(Vec::new()),
+            ]),
+        ]);
+        let encoded = tree.encode_internal();
+        assert_eq!(
+            encoded[..7],
+            [TAG_LIST, 5, TAG_BYTE, 0x05, TAG_BYTE, 0x80, TAG_STRING]
+        );
+        assert_eq!(ParsedData::decode_internal(&encoded), Ok(tree));
+
+        let raw = hex::decode("f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83").unwrap();
+        let parsed = decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        let encoded = parsed.encode_internal();
+        assert_eq!(ParsedData::decode_internal(&encoded), Ok(parsed));
+    }
+
+    #[test]
+    fn lengths() {
+        for length in [0, 0x7f, 0x80, 0x3fff, 0x4000, usize::MAX] {
+            let mut out = Vec::new();
+            write_length(&mut out, length);
+            let mut position = 0;
+            assert_eq!(read_length(&out, &mut position), Ok(length));
+            assert_eq!(position, out.len());
+        }
+        let mut too_long = vec![0xff; 10];
+        too_long.push(0x01);
+        assert_eq!(
+            read_length(&too_long, &mut 0),
+            Err(InternalFormatError::LengthOverflow { position: 0 })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            ParsedData::decode_internal(&[TAG_STRING, 3, 0xaa]),
+            Err(InternalFormatError::UnexpectedEnd)
+        );
+        assert_eq!(
+            ParsedData::decode_internal(&[TAG_LIST, 1, 0x07]),
+            Err(InternalFormatError::UnknownTag {
+                position: 2,
+                tag: 0x07
+            })
+        );
+        assert_eq!(
+            ParsedData::decode_internal(&[TAG_BYTE, 0x01, 0x00]),
+            Err(InternalFormatError::SomeDataUnused { from: 2 })
+        );
+        assert_eq!(
+            ParsedData::decode_internal(&[TAG_LIST, 0xff, 0xff, 0xff, 0x7f]),
+            Err(InternalFormatError::UnexpectedEnd)
+        );
+    }
+}
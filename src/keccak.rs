@@ -0,0 +1,28 @@
+//! Keccak-256 hashing, as used throughout Ethereum.
+use tiny_keccak::{Hasher, Keccak};
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    keccak256_concat(&[data])
+}
+
+/// Hash of concatenated `parts`, without building the concatenation.
+pub(crate) fn keccak256_concat(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    parts.iter().for_each(|part| hasher.update(part));
+    let mut output = [0; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(
+            hex::encode(keccak256(&[])),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+}
@@ -8,43 +8,237 @@ extern crate std;
 extern crate alloc;
 
 #[cfg(any(feature = "std", test))]
-use std::vec::Vec;
+use std::{boxed::Box, vec::Vec};
 
 #[cfg(all(not(feature = "std"), not(test)))]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 use external_memory_tools::{AddressableBuffer, BufferError, ExternalMemory};
 
+use crate::container::StringContainer;
+
+pub mod account;
+pub mod block;
+#[cfg(feature = "keccak")]
+pub mod bloom;
+pub mod buffer;
+pub mod calldata;
+pub mod container;
+pub mod cursor;
+pub mod encode;
+pub mod erase;
+pub mod eth;
+pub mod field;
+pub mod fork;
+pub mod internal;
+#[cfg(feature = "keccak")]
+pub mod keccak;
+pub mod receipt;
+#[cfg(feature = "recover")]
+pub mod recover;
+pub mod render;
+pub mod rlp_struct;
+pub mod span;
+pub mod transaction;
+pub mod tree;
+pub mod trie;
+mod uint;
+
+/// Decoded RLP item. String payloads are stored in `S`, see
+/// [`container`].
 #[derive(Debug, Eq, PartialEq)]
-pub enum ParsedData {
+pub enum ParsedData<S = Vec<u8>> {
     Byte(u8),
-    List(Vec<ParsedData>),
-    String(Vec<u8>),
+    List(Vec<ParsedData<S>>),
+    String(S),
 }
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error<E: ExternalMemory> {
     Buffer(BufferError<E>),
+    ExpectedList {
+        position: usize,
+    },
+    /// Error in decoding element `index` of a list nested at `depth`, top
+    /// level list being at depth `0`.
+    InList {
+        depth: usize,
+        index: usize,
+        source: Box<Error<E>>,
+    },
+    /// Long form length does not fit into 8 bytes.
+    LengthFieldTooLong {
+        position: usize,
+    },
+    /// Long form length added to the payload position overflows `usize`.
+    LengthOverflow {
+        position: usize,
+    },
+    /// Buffer limited to `border` came out only `length` long.
+    LimitedTooShort {
+        border: usize,
+        length: usize,
+    },
+    NonCanonical {
+        position: usize,
+        reason: NonCanonical,
+    },
     NotWorking,
-    SomeDataUnused { from: usize },
+    /// Decoding requested at `position` at or past the buffer end `len`.
+    PositionOutOfBounds {
+        position: usize,
+        len: usize,
+    },
+    /// Reading the item at `position` would exceed
+    /// [`DecodeConfig::max_bytes_read`].
+    ReadBudgetExceeded {
+        position: usize,
+    },
+    SomeDataUnused {
+        from: usize,
+    },
+    /// String payload does not fit into the string container.
+    StringCapacity {
+        position: usize,
+        length: usize,
+    },
+}
+
+/// Encodings that decode fine, but differ from the unique canonical encoding
+/// of the same data.
+#[derive(Debug, Eq, PartialEq)]
+pub enum NonCanonical {
+    /// Length of long form item starts with zero byte.
+    LeadingZeroInLength,
+    /// Long form used for length that fits into the short form.
+    LongFormForShortLength,
+    /// Single byte below `0x80` encoded as one byte string.
+    SingleByteString,
+}
+
+/// Decoding options.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DecodeConfig {
+    pub canonical: CanonicalPolicy,
+    pub trailing: TrailingPolicy,
+    /// Cap on bytes read from the buffer, prefixes included, for backends
+    /// where reads are slow or metered. `None` for no cap.
+    pub max_bytes_read: Option<usize>,
 }
 
+/// What to do with non-canonical encodings listed in [`NonCanonical`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CanonicalPolicy {
+    /// Fail with [`Error::NonCanonical`].
+    Reject,
+    /// Decode the data as encoded.
+    #[default]
+    Accept,
+    /// Decode the data as it would be encoded canonically, i.e. one byte
+    /// strings below `0x80` become [`ParsedData::Byte`].
+    Normalize,
+}
+
+/// What to do with bytes remaining after the decoded item.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TrailingPolicy {
+    /// Fail with [`Error::SomeDataUnused`].
+    #[default]
+    Reject,
+    /// Drop the remaining bytes.
+    Ignore,
+    /// Report position of the first remaining byte.
+    Return,
+}
+
+/// Longest payload that could be encoded in the short form.
+const SHORT_FORM_MAX: usize = 55;
+
 pub const BORDER_A: u8 = 0x80;
 pub const BORDER_B: u8 = 0xb8;
 pub const BORDER_C: u8 = 0xc0;
 pub const BORDER_D: u8 = 0xf8;
 
+/// Number of bytes taken by the prefix of an item starting with
+/// `first_byte`, length bytes of long forms included.
+///
+/// Single bytes below `0x80` are their own prefix, so the result is `1` for
+/// them as well as for short strings and lists.
+pub fn header_size(first_byte: u8) -> usize {
+    match first_byte {
+        a if (BORDER_B..BORDER_C).contains(&a) => 2 + (a - BORDER_B) as usize,
+        a if a >= BORDER_D => 2 + (a - BORDER_D) as usize,
+        _ => 1,
+    }
+}
+
 pub fn decode_whole_blob<B, E>(data: &B, ext_memory: &mut E) -> Result<ParsedData, Error<E>>
 where
     B: AddressableBuffer<E>,
     E: ExternalMemory,
+{
+    decode_whole_blob_with_config(data, ext_memory, &DecodeConfig::default())
+        .map(|(parsed_data, _)| parsed_data)
+}
+
+/// Decode separately framed blobs, each as with [`decode_whole_blob`].
+///
+/// Failure of one blob does not affect the others.
+pub fn decode_many<'a, E: ExternalMemory>(
+    blobs: &'a [&'a [u8]],
+    ext_memory: &mut E,
+) -> Vec<Result<ParsedData, Error<E>>> {
+    blobs
+        .iter()
+        .map(|blob| decode_whole_blob(blob, ext_memory))
+        .collect()
+}
+
+/// Decode blob starting at its first byte.
+///
+/// Second tuple element is the position of the first unused byte. It is set
+/// only with [`TrailingPolicy::Return`], and only if unused bytes exist.
+pub fn decode_whole_blob_with_config<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    config: &DecodeConfig,
+) -> Result<(ParsedData, Option<usize>), Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    decode_whole_blob_as(data, ext_memory, config)
+}
+
+/// Same as [`decode_whole_blob_with_config`], with strings stored in `S`,
+/// e.g. `&[u8]` slices borrowed from `&[u8]` input.
+pub fn decode_whole_blob_as<S, B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    config: &DecodeConfig,
+) -> Result<(ParsedData<S>, Option<usize>), Error<E>>
+where
+    S: StringContainer<B::ReadBuffer>,
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
 {
     let mut position = 0;
-    let parsed_data = decode_blob_portion_at_position(data, ext_memory, &mut position)?;
+    let parsed_data = decode_item(
+        data,
+        ext_memory,
+        &mut position,
+        config,
+        &mut DecodeState::new(),
+        0,
+    )?;
     if position < data.total_len() {
-        Err(Error::SomeDataUnused { from: position })
+        match config.trailing {
+            TrailingPolicy::Reject => Err(Error::SomeDataUnused { from: position }),
+            TrailingPolicy::Ignore => Ok((parsed_data, None)),
+            TrailingPolicy::Return => Ok((parsed_data, Some(position))),
+        }
     } else {
-        Ok(parsed_data)
+        Ok((parsed_data, None))
     }
 }
 
@@ -57,83 +251,447 @@ where
     B: AddressableBuffer<E>,
     E: ExternalMemory,
 {
-    let current_byte = data
-        .read_byte(ext_memory, *position)
-        .map_err(Error::Buffer)?;
-    *position += 1;
+    decode_blob_portion_at_position_with_config(
+        data,
+        ext_memory,
+        position,
+        &DecodeConfig::default(),
+    )
+}
 
-    match current_byte {
-        a if (..BORDER_A).contains(&a) => Ok(ParsedData::Byte(a)),
-        a if (BORDER_A..BORDER_B).contains(&a) => {
-            let string_length = (a - BORDER_A) as usize;
-            let slice = data
-                .read_slice(ext_memory, *position, string_length)
-                .map_err(Error::Buffer)?;
-            *position += string_length;
-            Ok(ParsedData::String(slice.as_ref().to_vec()))
+pub fn decode_blob_portion_at_position_with_config<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+    config: &DecodeConfig,
+) -> Result<ParsedData, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    check_position(data, *position)?;
+    decode_item(
+        data,
+        ext_memory,
+        position,
+        config,
+        &mut DecodeState::new(),
+        0,
+    )
+}
+
+/// Decode blob and hash its bytes with Keccak-256 in the same pass.
+///
+/// Each byte is read once, so for external memory this saves the second
+/// read of the blob. Unused bytes after the item are an error.
+#[cfg(feature = "keccak")]
+pub fn decode_and_hash<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+) -> Result<(ParsedData, [u8; 32]), Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = 0;
+    let mut state = DecodeState::new();
+    state.hasher = Some(tiny_keccak::Keccak::v256());
+    let parsed_data = decode_item(
+        data,
+        ext_memory,
+        &mut position,
+        &DecodeConfig::default(),
+        &mut state,
+        0,
+    )?;
+    if position < data.total_len() {
+        return Err(Error::SomeDataUnused { from: position });
+    }
+    let mut hash = [0; 32];
+    if let Some(hasher) = state.hasher {
+        tiny_keccak::Hasher::finalize(hasher, &mut hash);
+    }
+    Ok((parsed_data, hash))
+}
+
+/// Decode blob leniently, reporting whether its encoding was canonical.
+///
+/// Second tuple element is `false` if any non-canonical encoding listed in
+/// [`NonCanonical`] was met. Unused bytes after the item are an error.
+pub fn decode_whole_blob_checked<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+) -> Result<(ParsedData, bool), Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = 0;
+    let (parsed_data, canonical) = decode_checked_at_position(data, ext_memory, &mut position)?;
+    if position < data.total_len() {
+        Err(Error::SomeDataUnused { from: position })
+    } else {
+        Ok((parsed_data, canonical))
+    }
+}
+
+/// Lenient decoding of item at `position`, reporting whether its encoding
+/// was canonical.
+pub(crate) fn decode_checked_at_position<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+) -> Result<(ParsedData, bool), Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut state = DecodeState::new();
+    let parsed_data = decode_item(
+        data,
+        ext_memory,
+        position,
+        &DecodeConfig::default(),
+        &mut state,
+        0,
+    )?;
+    Ok((parsed_data, state.canonical))
+}
+
+/// Decoding progress shared by all items of a blob.
+struct DecodeState {
+    /// No non-canonical encodings met so far.
+    canonical: bool,
+    bytes_read: usize,
+    /// Hasher fed with the bytes read, see [`decode_and_hash`].
+    #[cfg(feature = "keccak")]
+    hasher: Option<tiny_keccak::Keccak>,
+}
+
+impl DecodeState {
+    fn new() -> Self {
+        Self {
+            canonical: true,
+            bytes_read: 0,
+            #[cfg(feature = "keccak")]
+            hasher: None,
         }
-        a if (BORDER_B..BORDER_C).contains(&a) => {
-            let string_length_info_length = (a + 1 - BORDER_B) as usize;
+    }
 
-            let string_length_slice = data
-                .read_slice(ext_memory, *position, string_length_info_length)
-                .map_err(Error::Buffer)?;
-            *position += string_length_info_length;
+    #[cfg(feature = "keccak")]
+    fn hash(&mut self, bytes: &[u8]) {
+        if let Some(hasher) = &mut self.hasher {
+            tiny_keccak::Hasher::update(hasher, bytes);
+        }
+    }
 
-            let mut string_length_bytes = [0; 8];
-            string_length_bytes[8 - string_length_info_length..8]
-                .copy_from_slice(string_length_slice.as_ref());
+    /// Hash prefix `prefix_len` bytes long, restored from its decoded form
+    /// rather than read again. Length bytes are restored as they were, with
+    /// leading zeros if any.
+    #[cfg(feature = "keccak")]
+    fn hash_prefix(&mut self, prefix: &Prefix, prefix_len: usize) {
+        let (short_base, length) = match prefix {
+            Prefix::Byte(a) => return self.hash(&[*a]),
+            Prefix::String(length) => (BORDER_A, *length),
+            Prefix::List(length) => (BORDER_C, *length),
+        };
+        if prefix_len == 1 {
+            return self.hash(&[short_base + length as u8]);
+        }
+        let length_bytes = prefix_len - 1;
+        self.hash(&[short_base + 55 + length_bytes as u8]);
+        self.hash(&(length as u64).to_be_bytes()[8 - length_bytes..]);
+    }
+
+    /// Account for `length` bytes about to be read for item at `position`.
+    fn charge<E: ExternalMemory>(
+        &mut self,
+        config: &DecodeConfig,
+        position: usize,
+        length: usize,
+    ) -> Result<(), Error<E>> {
+        self.bytes_read = self.bytes_read.saturating_add(length);
+        match config.max_bytes_read {
+            Some(max) if self.bytes_read > max => Err(Error::ReadBudgetExceeded { position }),
+            _ => Ok(()),
+        }
+    }
+}
 
-            let string_length = u64::from_be_bytes(string_length_bytes) as usize;
+/// Decode item at `position`.
+///
+/// Non-canonical encodings are errors with [`CanonicalPolicy::Reject`],
+/// otherwise they clear the `canonical` flag of `state`. Errors in list elements are wrapped into
+/// [`Error::InList`], `depth` being the depth of lists met at `position`.
+fn decode_item<S, B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+    config: &DecodeConfig,
+    state: &mut DecodeState,
+    depth: usize,
+) -> Result<ParsedData<S>, Error<E>>
+where
+    S: StringContainer<B::ReadBuffer>,
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let item_start = *position;
+    let (prefix, non_canonical) = read_prefix(data, ext_memory, position)?;
+    #[cfg(feature = "trace")]
+    trace_item(item_start, &prefix);
+    state.charge(config, item_start, *position - item_start)?;
+    #[cfg(feature = "keccak")]
+    state.hash_prefix(&prefix, *position - item_start);
+    if let Some(reason) = non_canonical {
+        mark_non_canonical(item_start, reason, config, &mut state.canonical)?;
+    }
+    match prefix {
+        Prefix::Byte(a) => Ok(ParsedData::Byte(a)),
+        Prefix::String(string_length) => {
+            state.charge(config, item_start, string_length)?;
             let slice = data
                 .read_slice(ext_memory, *position, string_length)
                 .map_err(Error::Buffer)?;
+            #[cfg(feature = "keccak")]
+            state.hash(slice.as_ref());
             *position += string_length;
-            Ok(ParsedData::String(slice.as_ref().to_vec()))
+            if let [a] = slice.as_ref() {
+                if *a < BORDER_A {
+                    mark_non_canonical(
+                        item_start,
+                        NonCanonical::SingleByteString,
+                        config,
+                        &mut state.canonical,
+                    )?;
+                    if config.canonical == CanonicalPolicy::Normalize {
+                        return Ok(ParsedData::Byte(*a));
+                    }
+                }
+            }
+            S::from_read(slice)
+                .map(ParsedData::String)
+                .ok_or(Error::StringCapacity {
+                    position: item_start,
+                    length: string_length,
+                })
         }
-        a if (BORDER_C..BORDER_D).contains(&a) => {
-            let list_length = (a - BORDER_C) as usize;
+        Prefix::List(list_length) => {
             let border_position = *position + list_length;
-            let mut list_content: Vec<ParsedData> = Vec::new();
+            let mut list_content: Vec<ParsedData<S>> = Vec::new();
 
-            let limited_data = data.limit_length(border_position).map_err(Error::Buffer)?;
+            let limited_data = limit_to(data, border_position)?;
 
             while *position < border_position {
-                let parsed_data =
-                    decode_blob_portion_at_position(&limited_data, ext_memory, position)?;
+                let parsed_data = decode_item(
+                    &limited_data,
+                    ext_memory,
+                    position,
+                    config,
+                    state,
+                    depth + 1,
+                )
+                .map_err(|source| Error::InList {
+                    depth,
+                    index: list_content.len(),
+                    source: Box::new(source),
+                })?;
                 list_content.push(parsed_data);
             }
 
             Ok(ParsedData::List(list_content))
         }
-        a => {
-            let list_length_info_length = (a + 1 - BORDER_D) as usize;
+    }
+}
 
-            let list_length_slice = data
-                .read_slice(ext_memory, *position, list_length_info_length)
-                .map_err(Error::Buffer)?;
-            *position += list_length_info_length;
+fn mark_non_canonical<E: ExternalMemory>(
+    position: usize,
+    reason: NonCanonical,
+    config: &DecodeConfig,
+    canonical: &mut bool,
+) -> Result<(), Error<E>> {
+    if config.canonical == CanonicalPolicy::Reject {
+        Err(Error::NonCanonical { position, reason })
+    } else {
+        *canonical = false;
+        Ok(())
+    }
+}
 
-            let mut list_length_bytes = [0; 8];
-            list_length_bytes[8 - list_length_info_length..8]
-                .copy_from_slice(list_length_slice.as_ref());
+/// Check if two blobs decode into the same data.
+///
+/// Lenient decoding tolerates alternative encodings of the same data, e.g.
+/// `0x05` and `0x8105` are equivalent, even though the latter decodes into a
+/// one byte [`ParsedData::String`]. With [`CanonicalPolicy::Reject`] only
+/// canonical blobs are accepted, and since canonical encoding is unique, this
+/// reduces to byte equality of successfully decoded blobs.
+pub fn blobs_equivalent<B, E>(a: &B, b: &B, ext_memory: &mut E) -> Result<bool, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    blobs_equivalent_with_config(a, b, ext_memory, &DecodeConfig::default())
+}
 
-            let list_length = u64::from_be_bytes(list_length_bytes) as usize;
+pub fn blobs_equivalent_with_config<B, E>(
+    a: &B,
+    b: &B,
+    ext_memory: &mut E,
+    config: &DecodeConfig,
+) -> Result<bool, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let (parsed_a, _) = decode_whole_blob_with_config(a, ext_memory, config)?;
+    let (parsed_b, _) = decode_whole_blob_with_config(b, ext_memory, config)?;
+    Ok(equivalent(&parsed_a, &parsed_b))
+}
 
-            let border_position = *position + list_length;
-            let mut list_content: Vec<ParsedData> = Vec::new();
+/// Structural equality, with single byte items equal to one byte strings.
+fn equivalent(a: &ParsedData, b: &ParsedData) -> bool {
+    match (a, b) {
+        (ParsedData::List(a), ParsedData::List(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| equivalent(a, b))
+        }
+        (ParsedData::List(_), _) | (_, ParsedData::List(_)) => false,
+        _ => a.as_bytes() == b.as_bytes(),
+    }
+}
 
-            let limited_data = data.limit_length(border_position).map_err(Error::Buffer)?;
+/// Item content as declared by its prefix.
+pub(crate) enum Prefix {
+    Byte(u8),
+    String(usize),
+    List(usize),
+}
 
-            while *position < border_position {
-                let parsed_data =
-                    decode_blob_portion_at_position(&limited_data, ext_memory, position)?;
-                list_content.push(parsed_data);
-            }
+/// Check that `position` is within `data`, before decoding an item there.
+pub(crate) fn check_position<B, E>(data: &B, position: usize) -> Result<(), Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    if position >= data.total_len() {
+        return Err(Error::PositionOutOfBounds {
+            position,
+            len: data.total_len(),
+        });
+    }
+    Ok(())
+}
 
-            Ok(ParsedData::List(list_content))
+/// `data` limited to `border`.
+///
+/// Nested items are kept within their list by decoding from the limited
+/// buffer, so a buffer that clamps the limit instead of failing is an error.
+pub(crate) fn limit_to<B, E>(data: &B, border: usize) -> Result<B, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let limited_data = data.limit_length(border).map_err(Error::Buffer)?;
+    if limited_data.total_len() < border {
+        return Err(Error::LimitedTooShort {
+            border,
+            length: limited_data.total_len(),
+        });
+    }
+    Ok(limited_data)
+}
+
+/// Read item prefix, moving `position` to the start of the item payload.
+///
+/// Also reports if the length is encoded non-canonically.
+pub(crate) fn read_prefix<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+) -> Result<(Prefix, Option<NonCanonical>), Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let current_byte = data
+        .read_byte(ext_memory, *position)
+        .map_err(Error::Buffer)?;
+    *position += 1;
+
+    match current_byte {
+        a if (..BORDER_A).contains(&a) => Ok((Prefix::Byte(a), None)),
+        a if (BORDER_A..BORDER_B).contains(&a) => {
+            Ok((Prefix::String((a - BORDER_A) as usize), None))
+        }
+        a if (BORDER_B..BORDER_C).contains(&a) => {
+            let (string_length, non_canonical) =
+                read_length(data, ext_memory, position, header_size(a) - 1)?;
+            Ok((Prefix::String(string_length), non_canonical))
         }
+        a if (BORDER_C..BORDER_D).contains(&a) => Ok((Prefix::List((a - BORDER_C) as usize), None)),
+        a => {
+            let (list_length, non_canonical) =
+                read_length(data, ext_memory, position, header_size(a) - 1)?;
+            Ok((Prefix::List(list_length), non_canonical))
+        }
+    }
+}
+
+/// Emit a `trace` level [`log`] record for an item about to be read.
+#[cfg(feature = "trace")]
+pub(crate) fn trace_item(position: usize, prefix: &Prefix) {
+    match prefix {
+        Prefix::Byte(a) => log::trace!("byte {a:#04x} at {position}"),
+        Prefix::String(length) => log::trace!("string at {position}, length {length}"),
+        Prefix::List(length) => log::trace!("list at {position}, length {length}"),
+    }
+}
+
+/// Read big-endian length of a long string or long list, checking that it
+/// is canonical.
+fn read_length<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+    length_info_length: usize,
+) -> Result<(usize, Option<NonCanonical>), Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let item_start = *position - 1;
+    // Border constants allow at most 8 bytes; guard against their change.
+    if length_info_length > 8 {
+        return Err(Error::LengthFieldTooLong {
+            position: item_start,
+        });
     }
+    let length_slice = data
+        .read_slice(ext_memory, *position, length_info_length)
+        .map_err(Error::Buffer)?;
+    *position += length_info_length;
+
+    let mut length_bytes = [0; 8];
+    length_bytes[8 - length_info_length..8].copy_from_slice(length_slice.as_ref());
+    // Lengths up to `2^64 - 1` are allowed by `0xbf` and `0xff` prefixes;
+    // payload end must still be addressable.
+    let length = usize::try_from(u64::from_be_bytes(length_bytes))
+        .ok()
+        .filter(|length| position.checked_add(*length).is_some())
+        .ok_or(Error::LengthOverflow {
+            position: item_start,
+        })?;
+
+    // Short lengths are reported as such even if written with leading
+    // zeroes, e.g. `0xb8 0x00`.
+    let non_canonical = if length <= SHORT_FORM_MAX {
+        Some(NonCanonical::LongFormForShortLength)
+    } else if length_slice.as_ref()[0] == 0 {
+        Some(NonCanonical::LeadingZeroInLength)
+    } else {
+        None
+    };
+
+    Ok((length, non_canonical))
 }
 
 #[cfg(any(feature = "std", test))]
@@ -241,4 +799,880 @@ mod tests {
             decode_whole_blob::<&[u8], ()>(&bytes_input.as_ref(), &mut ()).unwrap_err();
         assert_eq!(parsed_err, Error::SomeDataUnused { from: 1 });
     }
+
+    fn decode_strict(hex_input: &str) -> Result<ParsedData, Error<()>> {
+        let bytes_input = hex::decode(hex_input).unwrap();
+        decode_whole_blob_with_config::<&[u8], ()>(
+            &bytes_input.as_ref(),
+            &mut (),
+            &DecodeConfig {
+                canonical: CanonicalPolicy::Reject,
+                ..Default::default()
+            },
+        )
+        .map(|(parsed_data, _)| parsed_data)
+    }
+
+    #[test]
+    fn decode_strict_1() {
+        assert_eq!(
+            decode_strict("8105"),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::SingleByteString
+            })
+        );
+        assert_eq!(decode_strict("8180"), Ok(ParsedData::String(vec![0x80])));
+        assert_eq!(
+            decode_strict("c28105"),
+            Err(Error::InList {
+                depth: 0,
+                index: 0,
+                source: Box::new(Error::NonCanonical {
+                    position: 1,
+                    reason: NonCanonical::SingleByteString
+                })
+            })
+        );
+    }
+
+    #[test]
+    fn normalize_single_byte_strings() {
+        let normalize = DecodeConfig {
+            canonical: CanonicalPolicy::Normalize,
+            ..Default::default()
+        };
+        let decode = |hex_input: &str, config: &DecodeConfig| {
+            let bytes_input = hex::decode(hex_input).unwrap();
+            decode_whole_blob_with_config::<&[u8], ()>(&bytes_input.as_ref(), &mut (), config)
+                .map(|(parsed_data, _)| parsed_data)
+        };
+        // [0x8105, 0x8180, 0x05]
+        let input = "c581058180 05".replace(' ', "");
+        assert_eq!(
+            decode(&input, &DecodeConfig::default()),
+            Ok(ParsedData::List(vec![
+                ParsedData::String(vec![0x05]),
+                ParsedData::String(vec![0x80]),
+                ParsedData::Byte(0x05)
+            ]))
+        );
+        assert_eq!(
+            decode(&input, &normalize),
+            Ok(ParsedData::List(vec![
+                ParsedData::Byte(0x05),
+                ParsedData::String(vec![0x80]),
+                ParsedData::Byte(0x05)
+            ]))
+        );
+        // Normalized trees compare equal to the canonical ones.
+        assert_eq!(decode("c28105", &normalize), decode("c105", &normalize));
+        assert_eq!(
+            decode("c28105", &normalize),
+            Ok(ParsedData::List(vec![ParsedData::Byte(0x05)]))
+        );
+    }
+
+    #[test]
+    fn single_byte_list() {
+        let expected = ParsedData::List(vec![ParsedData::Byte(0x05)]);
+        assert_eq!(
+            decode_strict("c105"),
+            Ok(ParsedData::List(vec![ParsedData::Byte(0x05)]))
+        );
+
+        // Lenient decoding accepts the long form, strict one rejects it.
+        let long_form = hex::decode("f80105").unwrap();
+        assert_eq!(
+            decode_whole_blob::<&[u8], ()>(&long_form.as_ref(), &mut ()),
+            Ok(expected)
+        );
+        assert_eq!(
+            decode_strict("f80105"),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::LongFormForShortLength
+            })
+        );
+
+        // Declared length covers the prefix of the element only.
+        assert_eq!(
+            decode_strict("c181"),
+            Err(Error::InList {
+                depth: 0,
+                index: 0,
+                source: Box::new(Error::Buffer(BufferError::DataTooShort {
+                    position: 2,
+                    minimal_length: 1
+                }))
+            })
+        );
+        assert_eq!(
+            decode_strict("c1"),
+            Err(Error::Buffer(BufferError::DataTooShort {
+                position: 0,
+                minimal_length: 2
+            }))
+        );
+        assert_eq!(
+            decode_strict("c10506"),
+            Err(Error::SomeDataUnused { from: 2 })
+        );
+    }
+
+    #[test]
+    fn single_element_list_at_short_form_border() {
+        // 54 byte string makes 55 byte payload, the longest short form list.
+        let mut short = String::from("f7b6");
+        short.push_str(&"aa".repeat(54));
+        assert_eq!(
+            decode_strict(&short),
+            Ok(ParsedData::List(vec![ParsedData::String(vec![0xaa; 54])]))
+        );
+
+        let mut long_for_short = String::from("f837b6");
+        long_for_short.push_str(&"aa".repeat(54));
+        assert_eq!(
+            decode_strict(&long_for_short),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::LongFormForShortLength
+            })
+        );
+
+        // 55 byte string makes 56 byte payload, the shortest long form list.
+        let mut long = String::from("f838b7");
+        long.push_str(&"aa".repeat(55));
+        let parsed = decode_strict(&long).unwrap();
+        assert_eq!(
+            parsed,
+            ParsedData::List(vec![ParsedData::String(vec![0xaa; 55])])
+        );
+        assert_eq!(parsed.to_vec(), hex::decode(&long).unwrap());
+    }
+
+    #[test]
+    fn header_sizes() {
+        for (first_byte, size) in [
+            (0x00, 1),
+            (0x7f, 1),
+            (0x80, 1),
+            (0xb7, 1),
+            (0xb8, 2),
+            (0xbf, 9),
+            (0xc0, 1),
+            (0xf7, 1),
+            (0xf8, 2),
+            (0xff, 9),
+        ] {
+            assert_eq!(header_size(first_byte), size, "prefix {first_byte:#04x}");
+        }
+
+        let mut long_string = vec![0xb9, 0x01, 0x00];
+        long_string.extend_from_slice(&[0xaa; 256]);
+        let mut position = 0;
+        read_prefix::<&[u8], ()>(&long_string.as_ref(), &mut (), &mut position).unwrap();
+        assert_eq!(position, header_size(long_string[0]));
+    }
+
+    #[test]
+    fn error_in_nested_list() {
+        // Second element of the inner list declares 2 bytes, but the inner
+        // list ends after 1.
+        let bytes_input = hex::decode("c5c3018201c0").unwrap();
+        assert_eq!(
+            decode_whole_blob::<&[u8], ()>(&bytes_input.as_ref(), &mut ()),
+            Err(Error::InList {
+                depth: 0,
+                index: 0,
+                source: Box::new(Error::InList {
+                    depth: 1,
+                    index: 1,
+                    source: Box::new(Error::Buffer(BufferError::DataTooShort {
+                        position: 4,
+                        minimal_length: 2
+                    }))
+                })
+            })
+        );
+    }
+
+    #[test]
+    fn decode_strict_2() {
+        assert_eq!(
+            decode_strict("b803616263"),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::LongFormForShortLength
+            })
+        );
+        assert_eq!(
+            decode_strict("f803616263"),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::LongFormForShortLength
+            })
+        );
+
+        let mut long_with_zero = String::from("b90038");
+        long_with_zero.push_str(&"61".repeat(56));
+        assert_eq!(
+            decode_strict(&long_with_zero),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::LeadingZeroInLength
+            })
+        );
+    }
+
+    #[test]
+    fn length_field_too_long() {
+        let bytes_input = hex::decode("bf0102030405060708090a").unwrap();
+        let mut position = 1;
+        assert_eq!(
+            read_length::<&[u8], ()>(&bytes_input.as_ref(), &mut (), &mut position, 9),
+            Err(Error::LengthFieldTooLong { position: 0 })
+        );
+    }
+
+    #[test]
+    fn long_form_empty_string() {
+        assert_eq!(
+            decode_strict("b800"),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::LongFormForShortLength
+            })
+        );
+        let bytes_input = hex::decode("b800").unwrap();
+        let mut position = 0;
+        assert_eq!(
+            decode_blob_portion_at_position::<&[u8], ()>(
+                &bytes_input.as_ref(),
+                &mut (),
+                &mut position
+            ),
+            Ok(ParsedData::String(Vec::new()))
+        );
+        assert_eq!(position, 2);
+
+        // Same inside a list, followed by another item.
+        let bytes_input = hex::decode("c3b80001").unwrap();
+        assert_eq!(
+            decode_whole_blob::<&[u8], ()>(&bytes_input.as_ref(), &mut ()),
+            Ok(ParsedData::List(vec![
+                ParsedData::String(Vec::new()),
+                ParsedData::Byte(1)
+            ]))
+        );
+    }
+
+    #[test]
+    fn length_assembly() {
+        // Long form prefix, whether it is a list, declared length.
+        let cases = [
+            ("b838", false, 56),
+            ("f8ff", true, 255),
+            ("b9012c", false, 300),
+            ("f90100", true, 256),
+            ("b9ffff", false, 65_535),
+            ("ba010000", false, 65_536),
+            ("bb01020304", false, 0x0102_0304),
+            ("fb7fffffff", true, 0x7fff_ffff),
+        ];
+        #[cfg(target_pointer_width = "64")]
+        let cases = [
+            &cases[..],
+            &[
+                ("bf0102030405060708", false, 0x0102_0304_0506_0708),
+                ("ff0100000000000000", true, 1 << 56),
+            ],
+        ]
+        .concat();
+        for (header, list, length) in cases {
+            let bytes_input = hex::decode(header).unwrap();
+            assert_eq!(header_size(bytes_input[0]), bytes_input.len(), "{header}");
+            let mut position = 0;
+            let (prefix, non_canonical) =
+                read_prefix::<&[u8], ()>(&bytes_input.as_ref(), &mut (), &mut position).unwrap();
+            assert_eq!(position, bytes_input.len(), "{header}");
+            assert_eq!(non_canonical, None, "{header}");
+            match prefix {
+                Prefix::String(a) if !list => assert_eq!(a, length, "{header}"),
+                Prefix::List(a) if list => assert_eq!(a, length, "{header}"),
+                _ => panic!("wrong item kind for {header}"),
+            }
+        }
+    }
+
+    #[test]
+    fn longest_length_field() {
+        assert_eq!(header_size(0xff), 9);
+
+        // Big-endian assembly of all 8 bytes.
+        let bytes_input = hex::decode("ff0102030405060708").unwrap();
+        let mut position = 1;
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            read_length::<&[u8], ()>(&bytes_input.as_ref(), &mut (), &mut position, 8),
+            Ok((0x0102030405060708, None))
+        );
+        assert_eq!(position, 9);
+
+        // List of 56 bytes, length given in 8 bytes.
+        let mut list = String::from("ff0000000000000038");
+        list.push_str(&"01".repeat(56));
+        let bytes_input = hex::decode(&list).unwrap();
+        assert_eq!(
+            decode_whole_blob::<&[u8], ()>(&bytes_input.as_ref(), &mut ()),
+            Ok(ParsedData::List(
+                (0..56).map(|_| ParsedData::Byte(1)).collect()
+            ))
+        );
+        assert_eq!(
+            decode_strict(&list),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::LeadingZeroInLength
+            })
+        );
+
+        // Declared length beyond the data.
+        let bytes_input = hex::decode("ff0102030405060708c0").unwrap();
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            decode_whole_blob::<&[u8], ()>(&bytes_input.as_ref(), &mut ()),
+            Err(Error::Buffer(BufferError::DataTooShort {
+                position: 0,
+                minimal_length: 0x0102030405060708 + 9
+            }))
+        );
+
+        // Payload end not addressable.
+        for hex_input in ["ffffffffffffffffff", "bffffffffffffffff7"] {
+            let bytes_input = hex::decode(hex_input).unwrap();
+            assert_eq!(
+                decode_whole_blob::<&[u8], ()>(&bytes_input.as_ref(), &mut ()),
+                Err(Error::LengthOverflow { position: 0 })
+            );
+        }
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn trace_lines() {
+        use std::{cell::RefCell, string::ToString};
+
+        std::thread_local! {
+            static LINES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Collect;
+
+        impl log::Log for Collect {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                LINES.with(|lines| lines.borrow_mut().push(record.args().to_string()));
+            }
+
+            fn flush(&self) {}
+        }
+
+        let _ = log::set_logger(&Collect);
+        log::set_max_level(log::LevelFilter::Trace);
+
+        // [ "cat", [ 0x05 ], ... ] with the last string cut short
+        let bytes_input = hex::decode("c783636174c10583").unwrap();
+        assert!(decode_whole_blob::<&[u8], ()>(&bytes_input.as_ref(), &mut ()).is_err());
+        assert_eq!(
+            LINES.with(|lines| lines.take()),
+            vec![
+                "list at 0, length 7",
+                "string at 1, length 3",
+                "list at 5, length 1",
+                "byte 0x05 at 6",
+                "string at 7, length 3",
+            ]
+        );
+
+        let mut position = 0;
+        span::span_at_position::<&[u8], ()>(&bytes_input.as_ref(), &mut (), &mut position).unwrap();
+        assert_eq!(
+            LINES.with(|lines| lines.take()),
+            vec!["list at 0, length 7"]
+        );
+    }
+
+    #[test]
+    fn read_budget() {
+        let decode = |bytes_input: &[u8], max_bytes_read| {
+            decode_whole_blob_with_config::<&[u8], ()>(
+                &bytes_input,
+                &mut (),
+                &DecodeConfig {
+                    max_bytes_read: Some(max_bytes_read),
+                    ..Default::default()
+                },
+            )
+            .map(|(parsed_data, _)| parsed_data)
+        };
+        // [ "cat", [ 0x05 ] ]
+        let bytes_input = hex::decode("c683636174c105").unwrap();
+        assert!(decode(&bytes_input, 7).is_ok());
+        assert_eq!(
+            decode(&bytes_input, 6),
+            Err(Error::InList {
+                depth: 0,
+                index: 1,
+                source: Box::new(Error::InList {
+                    depth: 1,
+                    index: 0,
+                    source: Box::new(Error::ReadBudgetExceeded { position: 6 })
+                })
+            })
+        );
+        assert_eq!(
+            decode(&bytes_input, 4),
+            Err(Error::InList {
+                depth: 0,
+                index: 0,
+                source: Box::new(Error::ReadBudgetExceeded { position: 1 })
+            })
+        );
+
+        // Claimed length is checked against the budget before the read.
+        let mut claim = hex::decode("bb7fffffff").unwrap();
+        claim.extend_from_slice(&[0; 100]);
+        assert_eq!(
+            decode(&claim, 1024),
+            Err(Error::ReadBudgetExceeded { position: 0 })
+        );
+    }
+
+    #[test]
+    fn decode_checked() {
+        let decode = |hex_input: &str| {
+            let bytes_input = hex::decode(hex_input).unwrap();
+            decode_whole_blob_checked::<&[u8], ()>(&bytes_input.as_ref(), &mut ())
+        };
+        assert_eq!(
+            decode("c3058180"),
+            Ok((
+                ParsedData::List(vec![ParsedData::Byte(5), ParsedData::String(vec![0x80])]),
+                true
+            ))
+        );
+        assert_eq!(
+            decode("c28105"),
+            Ok((ParsedData::List(vec![ParsedData::String(vec![5])]), false))
+        );
+        assert_eq!(
+            decode("f803616263"),
+            Ok((
+                ParsedData::List(vec![
+                    ParsedData::Byte(0x61),
+                    ParsedData::Byte(0x62),
+                    ParsedData::Byte(0x63)
+                ]),
+                false
+            ))
+        );
+
+        let mut long_with_zero = String::from("b90038");
+        long_with_zero.push_str(&"61".repeat(56));
+        assert_eq!(
+            decode(&long_with_zero),
+            Ok((ParsedData::String(vec![0x61; 56]), false))
+        );
+        assert_eq!(decode("c20102ff"), Err(Error::SomeDataUnused { from: 3 }));
+    }
+
+    #[test]
+    fn blobs_equivalent_1() {
+        let canonical = hex::decode("c3058180").unwrap();
+        let alternative = hex::decode("f804810581 80".replace(' ', "")).unwrap();
+        assert!(
+            blobs_equivalent::<&[u8], ()>(&canonical.as_ref(), &alternative.as_ref(), &mut ())
+                .unwrap()
+        );
+
+        let different = hex::decode("c3068180").unwrap();
+        assert!(
+            !blobs_equivalent::<&[u8], ()>(&canonical.as_ref(), &different.as_ref(), &mut ())
+                .unwrap()
+        );
+
+        let strict = DecodeConfig {
+            canonical: CanonicalPolicy::Reject,
+            ..Default::default()
+        };
+        assert_eq!(
+            blobs_equivalent_with_config::<&[u8], ()>(
+                &canonical.as_ref(),
+                &alternative.as_ref(),
+                &mut (),
+                &strict
+            ),
+            Err(Error::NonCanonical {
+                position: 0,
+                reason: NonCanonical::LongFormForShortLength
+            })
+        );
+    }
+
+    #[test]
+    fn decode_trailing_policy() {
+        let bytes_input = hex::decode("c201020304").unwrap();
+        let decode = |trailing| {
+            decode_whole_blob_with_config::<&[u8], ()>(
+                &bytes_input.as_ref(),
+                &mut (),
+                &DecodeConfig {
+                    trailing,
+                    ..Default::default()
+                },
+            )
+        };
+        let list = ParsedData::List(vec![ParsedData::Byte(1), ParsedData::Byte(2)]);
+        assert_eq!(
+            decode(TrailingPolicy::Reject),
+            Err(Error::SomeDataUnused { from: 3 })
+        );
+        assert_eq!(decode(TrailingPolicy::Ignore), Ok((list, None)));
+        let list = ParsedData::List(vec![ParsedData::Byte(1), ParsedData::Byte(2)]);
+        assert_eq!(decode(TrailingPolicy::Return), Ok((list, Some(3))));
+    }
+
+    /// Slice buffer that clamps `limit_length` to its length instead of
+    /// failing.
+    struct Clamping<'a>(&'a [u8]);
+
+    impl<'a> AddressableBuffer<()> for Clamping<'a> {
+        type ReadBuffer = &'a [u8];
+
+        fn total_len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn read_slice(
+            &self,
+            ext_memory: &mut (),
+            position: usize,
+            slice_len: usize,
+        ) -> Result<Self::ReadBuffer, BufferError<()>> {
+            self.0.read_slice(ext_memory, position, slice_len)
+        }
+
+        fn limit_length(&self, new_len: usize) -> Result<Self, BufferError<()>> {
+            Ok(Self(&self.0[..new_len.min(self.0.len())]))
+        }
+    }
+
+    #[test]
+    fn list_borders() {
+        let decode = |bytes_input: &[u8]| decode_whole_blob::<&[u8], ()>(&bytes_input, &mut ());
+        // List declared 3 bytes long, 2 bytes available.
+        assert_eq!(
+            decode(&[0xc3, 0x01, 0x02]),
+            Err(Error::Buffer(BufferError::DataTooShort {
+                position: 0,
+                minimal_length: 4
+            }))
+        );
+        // Inner list fits into the buffer, but not into the outer list.
+        assert_eq!(
+            decode(&[0xc2, 0xc3, 0x01, 0x02, 0x03]),
+            Err(Error::InList {
+                depth: 0,
+                index: 0,
+                source: Box::new(Error::Buffer(BufferError::DataTooShort {
+                    position: 0,
+                    minimal_length: 5
+                }))
+            })
+        );
+        // Same for a string.
+        assert_eq!(
+            decode(&[0xc2, 0x82, 0x01, 0x02]),
+            Err(Error::InList {
+                depth: 0,
+                index: 0,
+                source: Box::new(Error::Buffer(BufferError::DataTooShort {
+                    position: 2,
+                    minimal_length: 2
+                }))
+            })
+        );
+        assert_eq!(
+            decode(&[0xc3, 0xc1, 0x01, 0x02]),
+            Ok(ParsedData::List(vec![
+                ParsedData::List(vec![ParsedData::Byte(1)]),
+                ParsedData::Byte(2)
+            ]))
+        );
+
+        assert_eq!(
+            decode_whole_blob::<Clamping, ()>(&Clamping(&[0xc3, 0x01, 0x02]), &mut ()),
+            Err(Error::LimitedTooShort {
+                border: 4,
+                length: 3
+            })
+        );
+        assert_eq!(
+            decode_whole_blob::<Clamping, ()>(&Clamping(&[0xc2, 0x01, 0x02]), &mut ()),
+            Ok(ParsedData::List(vec![
+                ParsedData::Byte(1),
+                ParsedData::Byte(2)
+            ]))
+        );
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn decode_and_hash_matches_keccak() {
+        use crate::keccak::keccak256;
+
+        let long_string = [0x5a; 60];
+        let mut long_list = vec![0xf8, 0x3e, 0xb8, 0x3c];
+        long_list.extend_from_slice(&long_string);
+        let inputs: [&[u8]; 6] = [
+            &[0x05],
+            &[0x80],
+            &[0xc6, 0x83, b'c', b'a', b't', 0xc1, 0x05],
+            &long_list,
+            // Long form for a short string.
+            &[0xb8, 0x03, b'c', b'a', b't'],
+            // Length with a leading zero.
+            &[0xf9, 0x00, 0x01, 0x05],
+        ];
+        for bytes_input in inputs {
+            let (parsed_data, hash) = decode_and_hash::<&[u8], ()>(&bytes_input, &mut ()).unwrap();
+            assert_eq!(
+                parsed_data,
+                decode_whole_blob::<&[u8], ()>(&bytes_input, &mut ()).unwrap()
+            );
+            assert_eq!(hash, keccak256(bytes_input));
+        }
+        assert_eq!(
+            decode_and_hash::<&[u8], ()>(&[0x05, 0x06].as_ref(), &mut ()),
+            Err(Error::SomeDataUnused { from: 1 })
+        );
+    }
+
+    #[test]
+    fn position_out_of_bounds() {
+        let data: &[u8] = &[0x05, 0x82, 0x01];
+        let decode = |mut position| {
+            decode_blob_portion_at_position::<&[u8], ()>(&data, &mut (), &mut position)
+        };
+        assert_eq!(decode(0), Ok(ParsedData::Byte(5)));
+        // Truncated item.
+        assert_eq!(
+            decode(1),
+            Err(Error::Buffer(BufferError::DataTooShort {
+                position: 2,
+                minimal_length: 2
+            }))
+        );
+        for position in [3, 10] {
+            assert_eq!(
+                decode(position),
+                Err(Error::PositionOutOfBounds { position, len: 3 })
+            );
+        }
+        assert_eq!(
+            span::span_at_position::<&[u8], ()>(&data, &mut (), &mut 3),
+            Err(Error::PositionOutOfBounds {
+                position: 3,
+                len: 3
+            })
+        );
+    }
+
+    #[test]
+    fn decode_many_blobs() {
+        let blobs: [&[u8]; 4] = [&[0x05], &[0xc2, 0x01, 0x02], &[0x82, 0x01], &[0x80, 0x00]];
+        assert_eq!(
+            decode_many::<()>(&blobs, &mut ()),
+            vec![
+                Ok(ParsedData::Byte(5)),
+                Ok(ParsedData::List(vec![
+                    ParsedData::Byte(1),
+                    ParsedData::Byte(2)
+                ])),
+                Err(Error::Buffer(BufferError::DataTooShort {
+                    position: 1,
+                    minimal_length: 2
+                })),
+                Err(Error::SomeDataUnused { from: 1 }),
+            ]
+        );
+        assert!(decode_many::<()>(&[], &mut ()).is_empty());
+    }
+
+    /// Reference decoding with `alloy_rlp::Header`, into the same tree.
+    fn alloy_decode(buf: &mut &[u8]) -> Result<ParsedData, alloy_rlp::Error> {
+        if let Some(&byte) = buf.first().filter(|a| **a < BORDER_A) {
+            *buf = &buf[1..];
+            return Ok(ParsedData::Byte(byte));
+        }
+        let header = alloy_rlp::Header::decode(buf)?;
+        let (mut payload, rest) = buf.split_at(header.payload_length);
+        *buf = rest;
+        if !header.list {
+            return Ok(ParsedData::String(payload.to_vec()));
+        }
+        let mut items = Vec::new();
+        while !payload.is_empty() {
+            items.push(alloy_decode(&mut payload)?);
+        }
+        Ok(ParsedData::List(items))
+    }
+
+    /// Decode `raw` with this crate, leniently and strictly, and with
+    /// alloy-rlp, and check that all agree. Returns whether `raw` is valid.
+    fn cross_check(raw: &[u8]) -> bool {
+        let mut buf = raw;
+        let reference = alloy_decode(&mut buf).and_then(|parsed_data| {
+            if buf.is_empty() {
+                Ok(parsed_data)
+            } else {
+                Err(alloy_rlp::Error::UnexpectedLength)
+            }
+        });
+        let strict = decode_whole_blob_with_config::<&[u8], ()>(
+            &raw,
+            &mut (),
+            &DecodeConfig {
+                canonical: CanonicalPolicy::Reject,
+                ..Default::default()
+            },
+        );
+        match reference {
+            Ok(reference) => {
+                assert_eq!(strict, Ok((reference, None)), "{}", hex::encode(raw));
+                let lenient = decode_whole_blob::<&[u8], ()>(&raw, &mut ()).unwrap();
+                assert!(
+                    blobs_equivalent::<&[u8], ()>(&raw, &lenient.to_vec().as_ref(), &mut ())
+                        .unwrap()
+                );
+                true
+            }
+            Err(error) => {
+                assert!(strict.is_err(), "{} {error:?}", hex::encode(raw));
+                false
+            }
+        }
+    }
+
+    fn alloy_encoded(item: &impl Encodable) -> Vec<u8> {
+        let mut raw = Vec::new();
+        item.encode(&mut raw);
+        raw
+    }
+
+    #[test]
+    fn cross_check_strings() {
+        use alloy_primitives::Bytes;
+
+        for length in [0, 1, 2, 54, 55, 56, 57, 255, 256, 257, 65_535, 65_536] {
+            for fill in [0x00, 0x7f, 0x80, 0xff] {
+                let raw = alloy_encoded(&Bytes::from(vec![fill; length]));
+                assert!(cross_check(&raw));
+            }
+        }
+        for byte in 0..=u8::MAX {
+            assert!(cross_check(&alloy_encoded(&Bytes::from(vec![byte]))));
+            assert!(cross_check(&alloy_encoded(&byte)));
+        }
+    }
+
+    #[test]
+    fn cross_check_scalars() {
+        use alloy_primitives::U256;
+
+        for shift in 0..64 {
+            for value in [1u64 << shift, (1u64 << shift) - 1, (1u64 << shift) + 1] {
+                assert!(cross_check(&alloy_encoded(&value)));
+            }
+        }
+        assert!(cross_check(&alloy_encoded(&u64::MAX)));
+        for shift in [64usize, 128, 200, 255] {
+            assert!(cross_check(&alloy_encoded(&(U256::from(1u64) << shift))));
+        }
+        assert!(cross_check(&alloy_encoded(&U256::MAX)));
+    }
+
+    #[test]
+    fn cross_check_lists() {
+        #[derive(RlpEncodable)]
+        struct Mixed {
+            nonce: u64,
+            to: [u8; 20],
+            data: alloy_primitives::Bytes,
+            values: Vec<u64>,
+            nested: Vec<Vec<u64>>,
+        }
+
+        // Lists with payloads around the short form border.
+        for count in 0..=60u64 {
+            assert!(cross_check(&alloy_encoded(&vec![0x42u64; count as usize])));
+            assert!(cross_check(&alloy_encoded(
+                &(0..count).collect::<Vec<u64>>()
+            )));
+        }
+        for length in [53, 54, 55, 56, 300, 70_000] {
+            let nested = vec![vec![0x7fu64; length]; 3];
+            assert!(cross_check(&alloy_encoded(&nested)));
+        }
+
+        let mut deep = <ParsedData>::List(Vec::new());
+        for depth in 0..64 {
+            deep = ParsedData::List(vec![deep, ParsedData::from_u64(depth)]);
+            assert!(cross_check(&deep.to_vec()));
+        }
+
+        let mixed = Mixed {
+            nonce: 1_000_000,
+            to: [0x35; 20],
+            data: vec![0xa9; 100].into(),
+            values: vec![0, 1, 0x80, u64::MAX],
+            nested: vec![Vec::new(), vec![1, 2, 3], vec![0x1234; 20]],
+        };
+        assert!(cross_check(&alloy_encoded(&mixed)));
+    }
+
+    #[test]
+    fn cross_check_invalid() {
+        let mut long_with_zero = String::from("b90038");
+        long_with_zero.push_str(&"61".repeat(56));
+        for hex_input in [
+            // Non-canonical.
+            "8105",
+            "8100",
+            "c28105",
+            "b803616263",
+            "f803616263",
+            long_with_zero.as_str(),
+            // Truncated.
+            "",
+            "83abcd",
+            "b8",
+            "b838",
+            "c30102",
+            "f838c0",
+            // Extra data.
+            "0101",
+            "c0c0",
+            "c3010203c0",
+        ] {
+            assert!(
+                !cross_check(&hex::decode(hex_input).unwrap()),
+                "{hex_input}"
+            );
+        }
+    }
 }
@@ -0,0 +1,174 @@
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::fork::Fork;
+use crate::receipt::{Receipt, ReceiptError};
+use crate::span::{ItemKind, ItemSpan};
+use crate::transaction::{
+    Eip1559Transaction, Eip2930Transaction, Eip4844Transaction, Eip7702Transaction,
+};
+use crate::{decode_blob_portion_at_position, limit_to, Error, BORDER_A, BORDER_C};
+
+/// Receipt of any supported transaction type, as defined by EIP-2718.
+///
+/// All current types share the receipt layout; the type matters for fee
+/// accounting.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReceiptEnvelope {
+    Legacy(Receipt),
+    Eip2930(Receipt),
+    Eip1559(Receipt),
+    Eip4844(Receipt),
+    Eip7702(Receipt),
+}
+
+impl ReceiptEnvelope {
+    /// Parse receipt of any supported transaction type.
+    ///
+    /// Input starting with a list prefix is a legacy receipt, otherwise the
+    /// first byte is the transaction type, followed by `rlp(receipt)`.
+    ///
+    /// Outcome kind is detected as in lenient [`Receipt::parse`].
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, ReceiptError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_at(data, ext_memory, 0, None)
+    }
+
+    /// Parse receipt produced under `fork` rules, see
+    /// [`Receipt::parse_for_fork`].
+    pub fn parse_for_fork<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fork: Fork,
+    ) -> Result<Self, ReceiptError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_at(data, ext_memory, 0, Some(fork))
+    }
+
+    /// Receipt in a list located at `span`: legacy receipts are list items,
+    /// typed ones are string items wrapping `type || rlp(receipt)`.
+    pub(crate) fn from_span<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        span: &ItemSpan,
+    ) -> Result<Self, ReceiptError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let data = limit_to(data, span.end)?;
+        let start = match span.kind {
+            ItemKind::List => span.start,
+            _ => span.payload_start,
+        };
+        Self::parse_at(&data, ext_memory, start, None)
+    }
+
+    /// Parse receipt occupying `data` from `start` to the end.
+    fn parse_at<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        start: usize,
+        fork: Option<Fork>,
+    ) -> Result<Self, ReceiptError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let first_byte = data
+            .read_byte(ext_memory, start)
+            .map_err(|e| ReceiptError::Decoding(Error::Buffer(e)))?;
+        let (wrap, mut position): (fn(Receipt) -> Self, usize) = match first_byte {
+            a if a >= BORDER_C => (Self::Legacy, start),
+            Eip2930Transaction::TX_TYPE => (Self::Eip2930, start + 1),
+            Eip1559Transaction::TX_TYPE => (Self::Eip1559, start + 1),
+            Eip4844Transaction::TX_TYPE => (Self::Eip4844, start + 1),
+            Eip7702Transaction::TX_TYPE => (Self::Eip7702, start + 1),
+            a if a >= BORDER_A => return Err(ReceiptError::NotAReceipt { first_byte: a }),
+            type_byte => return Err(ReceiptError::UnknownTransactionType { type_byte }),
+        };
+        let parsed_data = decode_blob_portion_at_position(data, ext_memory, &mut position)?;
+        if position < data.total_len() {
+            return Err(Error::SomeDataUnused { from: position }.into());
+        }
+        Ok(wrap(Receipt::from_parsed(&parsed_data, fork)?))
+    }
+
+    /// EIP-2718 transaction type, `0` for legacy receipts.
+    pub fn tx_type(&self) -> u8 {
+        match self {
+            Self::Legacy(_) => 0,
+            Self::Eip2930(_) => Eip2930Transaction::TX_TYPE,
+            Self::Eip1559(_) => Eip1559Transaction::TX_TYPE,
+            Self::Eip4844(_) => Eip4844Transaction::TX_TYPE,
+            Self::Eip7702(_) => Eip7702Transaction::TX_TYPE,
+        }
+    }
+
+    pub fn receipt(&self) -> &Receipt {
+        match self {
+            Self::Legacy(receipt)
+            | Self::Eip2930(receipt)
+            | Self::Eip1559(receipt)
+            | Self::Eip4844(receipt)
+            | Self::Eip7702(receipt) => receipt,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{Eip658Value, Receipt as AlloyReceipt, ReceiptEnvelope as AlloyEnvelope};
+    use alloy_eips::eip2718::Encodable2718;
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use crate::receipt::ReceiptOutcome;
+
+    fn alloy_receipt(cumulative_gas_used: u64) -> AlloyReceipt {
+        AlloyReceipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used,
+            logs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn all_kinds() {
+        let envelopes = [
+            (AlloyEnvelope::Legacy(alloy_receipt(1).with_bloom()), 0),
+            (AlloyEnvelope::Eip2930(alloy_receipt(2).with_bloom()), 1),
+            (AlloyEnvelope::Eip1559(alloy_receipt(3).with_bloom()), 2),
+            (AlloyEnvelope::Eip4844(alloy_receipt(4).with_bloom()), 3),
+            (AlloyEnvelope::Eip7702(alloy_receipt(5).with_bloom()), 4),
+        ];
+        for (envelope, tx_type) in envelopes {
+            let raw = envelope.encoded_2718();
+            let parsed = ReceiptEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+            assert_eq!(parsed.tx_type(), tx_type);
+            assert_eq!(parsed.receipt().cumulative_gas_used, tx_type as u64 + 1);
+            assert_eq!(parsed.receipt().outcome, ReceiptOutcome::Status(true));
+        }
+    }
+
+    #[test]
+    fn unknown_type() {
+        let mut raw = vec![0x05];
+        raw.extend_from_slice(
+            &AlloyEnvelope::Eip1559(alloy_receipt(1).with_bloom()).encoded_2718()[1..],
+        );
+        assert_eq!(
+            ReceiptEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(ReceiptError::UnknownTransactionType { type_byte: 0x05 })
+        );
+        assert_eq!(
+            ReceiptEnvelope::parse::<&[u8], ()>(&[0x82, 0x01, 0x02].as_ref(), &mut ()),
+            Err(ReceiptError::NotAReceipt { first_byte: 0x82 })
+        );
+    }
+}
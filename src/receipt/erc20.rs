@@ -0,0 +1,256 @@
+//! ERC-20 `Transfer` and `Approval` events.
+//!
+//! ERC-721 `Transfer` has the same signature hash, but its token id is
+//! indexed, so it comes with 4 topics and empty data.
+use crate::receipt::Log;
+
+/// `keccak256("Transfer(address,address,uint256)")`.
+pub const TRANSFER_TOPIC: [u8; 32] = [
+    0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37, 0x8d, 0xaa,
+    0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d, 0xf5, 0x23, 0xb3, 0xef,
+];
+
+/// `keccak256("Approval(address,address,uint256)")`.
+pub const APPROVAL_TOPIC: [u8; 32] = [
+    0x8c, 0x5b, 0xe1, 0xe5, 0xeb, 0xec, 0x7d, 0x5b, 0xd1, 0x4f, 0x71, 0x42, 0x7d, 0x1e, 0x84, 0xf3,
+    0xdd, 0x03, 0x14, 0xc0, 0xf7, 0xb2, 0x29, 0x1e, 0x5b, 0x20, 0x0a, 0xc8, 0xc7, 0xc3, 0xb9, 0x25,
+];
+
+/// Errors in reading an event from a log.
+#[derive(Debug, Eq, PartialEq)]
+pub enum EventError {
+    /// Indexed address topic `index` has non-zero padding bytes.
+    InvalidAddressTopic {
+        index: usize,
+    },
+    /// Data is not a single 32-byte word.
+    InvalidData {
+        length: usize,
+    },
+    /// First topic is not the event signature hash, or no topics at all.
+    SignatureMismatch,
+    WrongTopicCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// ERC-20 `Transfer(address indexed from, address indexed to, uint256 value)`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Erc20Transfer {
+    /// Emitting token contract.
+    pub token: [u8; 20],
+    pub from: [u8; 20],
+    pub to: [u8; 20],
+    /// Big-endian amount.
+    pub amount: [u8; 32],
+}
+
+/// ERC-20 `Approval(address indexed owner, address indexed spender, uint256 value)`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Erc20Approval {
+    /// Emitting token contract.
+    pub token: [u8; 20],
+    pub owner: [u8; 20],
+    pub spender: [u8; 20],
+    /// Big-endian amount.
+    pub amount: [u8; 32],
+}
+
+impl Erc20Transfer {
+    pub fn try_from_log(log: &Log) -> Result<Self, EventError> {
+        let amount = check_event(log, &TRANSFER_TOPIC)?;
+        Ok(Self {
+            token: log.address,
+            from: address_topic(log, 1)?,
+            to: address_topic(log, 2)?,
+            amount,
+        })
+    }
+}
+
+impl Erc20Approval {
+    pub fn try_from_log(log: &Log) -> Result<Self, EventError> {
+        let amount = check_event(log, &APPROVAL_TOPIC)?;
+        Ok(Self {
+            token: log.address,
+            owner: address_topic(log, 1)?,
+            spender: address_topic(log, 2)?,
+            amount,
+        })
+    }
+}
+
+/// Check that `log` is an event with signature `topic0`, two indexed
+/// arguments and a single word of data, and return the word.
+fn check_event(log: &Log, topic0: &[u8; 32]) -> Result<[u8; 32], EventError> {
+    if log.topics.first() != Some(topic0) {
+        return Err(EventError::SignatureMismatch);
+    }
+    if log.topics.len() != 3 {
+        return Err(EventError::WrongTopicCount {
+            expected: 3,
+            found: log.topics.len(),
+        });
+    }
+    log.data
+        .as_slice()
+        .try_into()
+        .map_err(|_| EventError::InvalidData {
+            length: log.data.len(),
+        })
+}
+
+/// Address in topic `index`, left-padded with 12 zero bytes.
+fn address_topic(log: &Log, index: usize) -> Result<[u8; 20], EventError> {
+    let (padding, address) = log.topics[index].split_at(12);
+    if padding.iter().any(|a| *a != 0) {
+        return Err(EventError::InvalidAddressTopic { index });
+    }
+    let mut out = [0; 20];
+    out.copy_from_slice(address);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    use super::*;
+
+    fn address_word(address: [u8; 20]) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[12..].copy_from_slice(&address);
+        word
+    }
+
+    fn amount(value: u64) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    fn log(topics: Vec<[u8; 32]>, data: Vec<u8>) -> Log {
+        Log {
+            address: [0xcc; 20],
+            topics,
+            data,
+        }
+    }
+
+    #[test]
+    fn transfer() {
+        let transfer = log(
+            vec![
+                TRANSFER_TOPIC,
+                address_word([0x11; 20]),
+                address_word([0x22; 20]),
+            ],
+            amount(1_000).to_vec(),
+        );
+        assert_eq!(
+            Erc20Transfer::try_from_log(&transfer),
+            Ok(Erc20Transfer {
+                token: [0xcc; 20],
+                from: [0x11; 20],
+                to: [0x22; 20],
+                amount: amount(1_000),
+            })
+        );
+        assert_eq!(
+            Erc20Approval::try_from_log(&transfer),
+            Err(EventError::SignatureMismatch)
+        );
+    }
+
+    #[test]
+    fn approval() {
+        let approval = log(
+            vec![
+                APPROVAL_TOPIC,
+                address_word([0x11; 20]),
+                address_word([0x22; 20]),
+            ],
+            [0xff; 32].to_vec(),
+        );
+        assert_eq!(
+            Erc20Approval::try_from_log(&approval),
+            Ok(Erc20Approval {
+                token: [0xcc; 20],
+                owner: [0x11; 20],
+                spender: [0x22; 20],
+                amount: [0xff; 32],
+            })
+        );
+        assert_eq!(
+            Erc20Transfer::try_from_log(&approval),
+            Err(EventError::SignatureMismatch)
+        );
+    }
+
+    #[test]
+    fn erc721_transfer() {
+        // Token id is the indexed fourth topic, data is empty.
+        let transfer = log(
+            vec![
+                TRANSFER_TOPIC,
+                address_word([0x11; 20]),
+                address_word([0x22; 20]),
+                amount(7),
+            ],
+            Vec::new(),
+        );
+        assert_eq!(
+            Erc20Transfer::try_from_log(&transfer),
+            Err(EventError::WrongTopicCount {
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let mut dirty_padding = address_word([0x22; 20]);
+        dirty_padding[0] = 0x01;
+        let cases = [
+            (log(Vec::new(), Vec::new()), EventError::SignatureMismatch),
+            (
+                log(
+                    vec![TRANSFER_TOPIC, address_word([0x11; 20]), dirty_padding],
+                    amount(1).to_vec(),
+                ),
+                EventError::InvalidAddressTopic { index: 2 },
+            ),
+            (
+                log(
+                    vec![
+                        TRANSFER_TOPIC,
+                        address_word([0x11; 20]),
+                        address_word([0x22; 20]),
+                    ],
+                    vec![0x01; 31],
+                ),
+                EventError::InvalidData { length: 31 },
+            ),
+        ];
+        for (log, error) in cases {
+            assert_eq!(Erc20Transfer::try_from_log(&log), Err(error));
+        }
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn signature_hashes() {
+        use crate::keccak::keccak256;
+
+        assert_eq!(
+            keccak256(b"Transfer(address,address,uint256)"),
+            TRANSFER_TOPIC
+        );
+        assert_eq!(
+            keccak256(b"Approval(address,address,uint256)"),
+            APPROVAL_TOPIC
+        );
+    }
+}
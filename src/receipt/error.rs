@@ -0,0 +1,89 @@
+use external_memory_tools::ExternalMemory;
+
+use crate::field::FieldError;
+use crate::fork::Fork;
+use crate::Error;
+
+/// Receipt fields, used to locate errors.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReceiptField {
+    CumulativeGasUsed,
+    Logs,
+    LogsBloom,
+    Outcome,
+}
+
+/// Errors in log entries.
+#[derive(Debug, Eq, PartialEq)]
+pub enum LogError {
+    InvalidAddress(FieldError),
+    InvalidData(FieldError),
+    InvalidTopic {
+        index: usize,
+        reason: FieldError,
+    },
+    NotAList,
+    TopicsNotAList,
+    /// More topics than `LOG4` emits.
+    TooManyTopics {
+        count: usize,
+    },
+    WrongFieldCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// Errors in parsing log entries without copying their data.
+#[derive(Debug, Eq, PartialEq)]
+pub enum LogSpanError<E: ExternalMemory> {
+    Decoding(Error<E>),
+    Log(LogError),
+}
+
+impl<E: ExternalMemory> From<Error<E>> for LogSpanError<E> {
+    fn from(error: Error<E>) -> Self {
+        LogSpanError::Decoding(error)
+    }
+}
+
+/// Errors in parsing receipts.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ReceiptError<E: ExternalMemory> {
+    Decoding(Error<E>),
+    InvalidField {
+        field: ReceiptField,
+        reason: FieldError,
+    },
+    InvalidLog {
+        index: usize,
+        reason: LogError,
+    },
+    /// First field is neither a 32 byte state root nor status `0` or `1`.
+    InvalidOutcome,
+    /// First field of `len` bytes is not a valid outcome under `fork`
+    /// rules: status `0` or `1` since Byzantium, 32-byte state root before.
+    InvalidStatusField {
+        len: usize,
+        fork: Fork,
+    },
+    NotAList,
+    /// Input starts with a string prefix; receipts are either lists or
+    /// typed envelopes.
+    NotAReceipt {
+        first_byte: u8,
+    },
+    UnknownTransactionType {
+        type_byte: u8,
+    },
+    WrongFieldCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<E: ExternalMemory> From<Error<E>> for ReceiptError<E> {
+    fn from(error: Error<E>) -> Self {
+        ReceiptError::Decoding(error)
+    }
+}
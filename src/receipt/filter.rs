@@ -0,0 +1,182 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::receipt::{Log, Receipt};
+
+/// Log filter following `eth_getLogs` semantics.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LogFilter {
+    /// Log address is any of these; empty list matches any address.
+    pub addresses: Vec<[u8; 20]>,
+    /// Log topic at each position is any of the listed ones; `None` matches
+    /// any topic, or no topic at all.
+    pub topics: [Option<Vec<[u8; 32]>>; 4],
+}
+
+impl LogFilter {
+    pub fn matches(&self, log: &Log) -> bool {
+        if !self.addresses.is_empty() && !self.addresses.contains(&log.address) {
+            return false;
+        }
+        self.topics
+            .iter()
+            .enumerate()
+            .all(|(position, allowed)| match allowed {
+                None => true,
+                Some(allowed) => log
+                    .topics
+                    .get(position)
+                    .is_some_and(|topic| allowed.contains(topic)),
+            })
+    }
+
+    /// Matching logs with their receipt index and log index within the
+    /// receipt.
+    pub fn filter_receipts<'a>(
+        &'a self,
+        receipts: &'a [Receipt],
+    ) -> impl Iterator<Item = (usize, usize, &'a Log)> + 'a {
+        receipts
+            .iter()
+            .enumerate()
+            .flat_map(|(receipt_index, receipt)| {
+                receipt
+                    .logs
+                    .iter()
+                    .enumerate()
+                    .map(move |(log_index, log)| (receipt_index, log_index, log))
+            })
+            .filter(|(_, _, log)| self.matches(log))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+    use crate::receipt::ReceiptOutcome;
+
+    const A: [u8; 20] = [0xaa; 20];
+    const B: [u8; 20] = [0xbb; 20];
+    const T1: [u8; 32] = [0x01; 32];
+    const T2: [u8; 32] = [0x02; 32];
+    const T3: [u8; 32] = [0x03; 32];
+
+    fn log(address: [u8; 20], topics: &[[u8; 32]]) -> Log {
+        Log {
+            address,
+            topics: topics.to_vec(),
+            data: Vec::new(),
+        }
+    }
+
+    fn filter(addresses: &[[u8; 20]], topics: [Option<&[[u8; 32]]>; 4]) -> LogFilter {
+        LogFilter {
+            addresses: addresses.to_vec(),
+            topics: topics.map(|position| position.map(<[[u8; 32]]>::to_vec)),
+        }
+    }
+
+    #[test]
+    fn matches() {
+        let any = [None, None, None, None];
+        let cases = [
+            // Wildcards everywhere.
+            (filter(&[], any), log(A, &[]), true),
+            (filter(&[], any), log(B, &[T1, T2]), true),
+            // Address OR-list.
+            (filter(&[A], any), log(A, &[T1]), true),
+            (filter(&[A], any), log(B, &[T1]), false),
+            (filter(&[A, B], any), log(B, &[T1]), true),
+            // Topic OR-list at the first position.
+            (
+                filter(&[], [Some(&[T1]), None, None, None]),
+                log(A, &[T1]),
+                true,
+            ),
+            (
+                filter(&[], [Some(&[T2, T1]), None, None, None]),
+                log(A, &[T1]),
+                true,
+            ),
+            (
+                filter(&[], [Some(&[T2]), None, None, None]),
+                log(A, &[T1]),
+                false,
+            ),
+            (
+                filter(&[], [Some(&[]), None, None, None]),
+                log(A, &[T1]),
+                false,
+            ),
+            // Wildcard before a fixed topic.
+            (
+                filter(&[], [None, Some(&[T2]), None, None]),
+                log(A, &[T3, T2]),
+                true,
+            ),
+            (
+                filter(&[], [None, Some(&[T2]), None, None]),
+                log(A, &[T2, T3]),
+                false,
+            ),
+            // Position beyond the log topics.
+            (
+                filter(&[], [None, Some(&[T2]), None, None]),
+                log(A, &[T1]),
+                false,
+            ),
+            (
+                filter(&[], [None, None, None, Some(&[T1])]),
+                log(A, &[T1, T1, T1]),
+                false,
+            ),
+            // Anonymous log against a topic wildcard and a fixed topic.
+            (filter(&[], [None, None, None, None]), log(A, &[]), true),
+            (
+                filter(&[], [Some(&[T1]), None, None, None]),
+                log(A, &[]),
+                false,
+            ),
+            // Address and topics together.
+            (
+                filter(&[A], [Some(&[T1]), Some(&[T2]), None, None]),
+                log(A, &[T1, T2, T3]),
+                true,
+            ),
+            (
+                filter(&[B], [Some(&[T1]), Some(&[T2]), None, None]),
+                log(A, &[T1, T2, T3]),
+                false,
+            ),
+        ];
+        for (index, (filter, log, expected)) in cases.iter().enumerate() {
+            assert_eq!(filter.matches(log), *expected, "case {index}");
+        }
+    }
+
+    #[test]
+    fn filter_receipts() {
+        let receipt = |logs| Receipt {
+            outcome: ReceiptOutcome::Status(true),
+            cumulative_gas_used: 21_000,
+            logs_bloom: [0; 256],
+            logs,
+        };
+        let receipts = [
+            receipt(vec![log(A, &[T1]), log(B, &[T1])]),
+            receipt(Vec::new()),
+            receipt(vec![log(B, &[T2]), log(A, &[T1, T2])]),
+        ];
+        let log_filter = filter(&[A], [Some(&[T1]), None, None, None]);
+        let found: Vec<(usize, usize, &Log)> = log_filter.filter_receipts(&receipts).collect();
+        assert_eq!(
+            found,
+            vec![(0, 0, &receipts[0].logs[0]), (2, 1, &receipts[2].logs[1])]
+        );
+    }
+}
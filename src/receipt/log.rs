@@ -0,0 +1,259 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::field::FieldError;
+use crate::receipt::{LogError, LogSpanError};
+use crate::span::{span_at_position, ItemKind, ItemSpan};
+use crate::{decode_blob_portion_at_position, Error, ParsedData};
+
+/// Log entry emitted by a transaction, `[address, [topic, ...], data]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Log {
+    pub address: [u8; 20],
+    pub topics: Vec<[u8; 32]>,
+    pub data: Vec<u8>,
+}
+
+/// Log entry with `data` left in the buffer, for logs with large data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogSpan {
+    pub address: [u8; 20],
+    pub topics: Vec<[u8; 32]>,
+    /// Location of the data string in the parsed buffer.
+    pub data: ItemSpan,
+}
+
+impl Log {
+    const FIELD_COUNT: usize = 3;
+
+    /// Most topics a log could have, emitted by `LOG4`.
+    pub const MAX_TOPICS: usize = 4;
+
+    /// Parse log entry with 20-byte address and at most 4 32-byte topics.
+    pub fn parse_from(parsed_data: &ParsedData) -> Result<Self, LogError> {
+        let fields = parsed_data.as_list().map_err(|_| LogError::NotAList)?;
+        if fields.len() != Self::FIELD_COUNT {
+            return Err(LogError::WrongFieldCount {
+                expected: Self::FIELD_COUNT,
+                found: fields.len(),
+            });
+        }
+        Ok(Self {
+            address: parse_address(&fields[0])?,
+            topics: parse_topics(&fields[1])?,
+            data: fields[2].to_bytes_vec().map_err(LogError::InvalidData)?,
+        })
+    }
+}
+
+impl LogSpan {
+    /// Parse log entry `rlp([address, [topic, ...], data])` without copying
+    /// `data`. Checks are the same as in [`Log::parse_from`].
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, LogSpanError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let mut position = 0;
+        let span = span_at_position(data, ext_memory, &mut position)?;
+        if position < data.total_len() {
+            return Err(Error::SomeDataUnused { from: position }.into());
+        }
+        if span.kind != ItemKind::List {
+            return Err(LogSpanError::Log(LogError::NotAList));
+        }
+        let fields = span.list_items(data, ext_memory)?;
+        if fields.len() != Log::FIELD_COUNT {
+            return Err(LogSpanError::Log(LogError::WrongFieldCount {
+                expected: Log::FIELD_COUNT,
+                found: fields.len(),
+            }));
+        }
+        if fields[2].kind == ItemKind::List {
+            return Err(LogSpanError::Log(LogError::InvalidData(
+                FieldError::ExpectedString,
+            )));
+        }
+        let mut position = fields[0].start;
+        let address = decode_blob_portion_at_position(data, ext_memory, &mut position)?;
+        let topics = decode_blob_portion_at_position(data, ext_memory, &mut position)?;
+        Ok(Self {
+            address: parse_address(&address).map_err(LogSpanError::Log)?,
+            topics: parse_topics(&topics).map_err(LogSpanError::Log)?,
+            data: fields[2],
+        })
+    }
+
+    /// Read log data from `data`, the buffer the log was parsed from.
+    pub fn read_data<B, E>(&self, data: &B, ext_memory: &mut E) -> Result<B::ReadBuffer, Error<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        self.data.read_payload(data, ext_memory)
+    }
+}
+
+fn parse_address(parsed_data: &ParsedData) -> Result<[u8; 20], LogError> {
+    parsed_data
+        .as_fixed_bytes::<20>()
+        .map_err(LogError::InvalidAddress)
+}
+
+fn parse_topics(parsed_data: &ParsedData) -> Result<Vec<[u8; 32]>, LogError> {
+    let topics = parsed_data
+        .as_list()
+        .map_err(|_| LogError::TopicsNotAList)?;
+    if topics.len() > Log::MAX_TOPICS {
+        return Err(LogError::TooManyTopics {
+            count: topics.len(),
+        });
+    }
+    topics
+        .iter()
+        .enumerate()
+        .map(|(index, topic)| {
+            topic
+                .as_fixed_bytes::<32>()
+                .map_err(|reason| LogError::InvalidTopic { index, reason })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, Log as AlloyLog, LogData, B256};
+    use alloy_rlp::Encodable;
+    use std::vec;
+
+    use super::*;
+    use crate::decode_whole_blob;
+
+    /// `Transfer(address,address,uint256)` event signature.
+    const TRANSFER: &str = "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+    fn encode(address: &str, topics: Vec<B256>, data: Vec<u8>) -> Vec<u8> {
+        let log = AlloyLog {
+            address: address.parse().unwrap(),
+            data: LogData::new_unchecked(topics, Bytes::from(data)),
+        };
+        let mut raw = Vec::new();
+        log.encode(&mut raw);
+        raw
+    }
+
+    fn parse(raw: &[u8]) -> Result<Log, LogError> {
+        Log::parse_from(&decode_whole_blob::<&[u8], ()>(&raw, &mut ()).unwrap())
+    }
+
+    fn parse_span(raw: &[u8]) -> Result<LogSpan, LogSpanError<()>> {
+        LogSpan::parse::<&[u8], ()>(&raw, &mut ())
+    }
+
+    #[test]
+    fn erc20_transfer() {
+        // ARB token `Transfer` of Arbitrum One transaction
+        // 0x5aeca744e0c1f6d7f68641aedd394ac4b6e18cbeac3f8b3c81056c0e51a61cf3.
+        let from = "000000000000000000000000e487d95426e55a29f2266e6788ab55608ebb829b";
+        let to = "0000000000000000000000009855134ed0c8b71266d9f3e15c0a518c07be5baf";
+        let amount = B256::left_padding_from(&0x09d4_0825_d5ee_8000u64.to_be_bytes());
+        let raw = encode(
+            "912ce59144191c1204e64559fe8253a0e49e6548",
+            vec![
+                TRANSFER.parse().unwrap(),
+                from.parse().unwrap(),
+                to.parse().unwrap(),
+            ],
+            amount.to_vec(),
+        );
+        let log = parse(&raw).unwrap();
+        assert_eq!(log.address[..4], [0x91, 0x2c, 0xe5, 0x91]);
+        assert_eq!(log.topics.len(), 3);
+        assert_eq!(log.topics[0], hex::decode(TRANSFER).unwrap()[..]);
+        assert_eq!(log.topics[1], hex::decode(from).unwrap()[..]);
+        assert_eq!(log.topics[2], hex::decode(to).unwrap()[..]);
+        assert_eq!(log.data, amount.to_vec());
+
+        let span = parse_span(&raw).unwrap();
+        assert_eq!(span.address, log.address);
+        assert_eq!(span.topics, log.topics);
+        assert_eq!(span.data.payload_len(), 32);
+        assert_eq!(
+            span.read_data(&raw.as_ref(), &mut ()),
+            Ok(amount.as_slice())
+        );
+    }
+
+    #[test]
+    fn anonymous() {
+        let raw = encode(
+            "0000000000000000000000000000000000001234",
+            Vec::new(),
+            vec![0x42; 100],
+        );
+        let log = parse(&raw).unwrap();
+        assert!(log.topics.is_empty());
+        assert_eq!(log.data, vec![0x42; 100]);
+        let span = parse_span(&raw).unwrap();
+        assert!(span.topics.is_empty());
+        assert_eq!(
+            span.read_data(&raw.as_ref(), &mut ()).unwrap(),
+            &[0x42; 100]
+        );
+
+        // Single byte data is its own payload.
+        let raw = encode(
+            "0000000000000000000000000000000000001234",
+            Vec::new(),
+            vec![7],
+        );
+        let span = parse_span(&raw).unwrap();
+        assert_eq!(span.read_data(&raw.as_ref(), &mut ()).unwrap(), &[7]);
+    }
+
+    #[test]
+    fn too_many_topics() {
+        let raw = encode(
+            "dac17f958d2ee523a2206206994597c13d831ec7",
+            vec![B256::repeat_byte(0x01); 5],
+            Vec::new(),
+        );
+        assert_eq!(parse(&raw), Err(LogError::TooManyTopics { count: 5 }));
+        assert_eq!(
+            parse_span(&raw),
+            Err(LogSpanError::Log(LogError::TooManyTopics { count: 5 }))
+        );
+    }
+
+    #[test]
+    fn span_errors() {
+        assert_eq!(
+            parse_span(&[0x80]),
+            Err(LogSpanError::Log(LogError::NotAList))
+        );
+        assert_eq!(
+            parse_span(&[0xc3, 0x80, 0xc0, 0xc0]),
+            Err(LogSpanError::Log(LogError::InvalidData(
+                FieldError::ExpectedString
+            )))
+        );
+        assert_eq!(
+            parse_span(&[0xc3, 0x80, 0xc0, 0x80]),
+            Err(LogSpanError::Log(LogError::InvalidAddress(
+                FieldError::WrongLength {
+                    expected: 20,
+                    found: 0
+                }
+            )))
+        );
+        assert_eq!(
+            parse_span(&[0xc0, 0x00]),
+            Err(LogSpanError::Decoding(Error::SomeDataUnused { from: 1 }))
+        );
+    }
+}
@@ -0,0 +1,381 @@
+//! Transaction receipts.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::field::FieldError;
+use crate::fork::Fork;
+use crate::{decode_whole_blob, ParsedData};
+
+mod envelope;
+mod erc20;
+mod error;
+mod filter;
+mod log;
+
+pub use envelope::ReceiptEnvelope;
+pub use erc20::{Erc20Approval, Erc20Transfer, EventError, APPROVAL_TOPIC, TRANSFER_TOPIC};
+pub use error::{LogError, LogSpanError, ReceiptError, ReceiptField};
+pub use filter::LogFilter;
+pub use log::{Log, LogSpan};
+
+/// Receipt of an executed transaction, `[outcome, cumulative_gas_used,
+/// logs_bloom, logs]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    pub outcome: ReceiptOutcome,
+    pub cumulative_gas_used: u64,
+    pub logs_bloom: [u8; 256],
+    pub logs: Vec<Log>,
+}
+
+/// First receipt field, its meaning changed in Byzantium (EIP-658).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReceiptOutcome {
+    /// State root after the transaction, before Byzantium.
+    StateRoot([u8; 32]),
+    /// Whether the transaction succeeded, since Byzantium.
+    Status(bool),
+}
+
+impl Receipt {
+    const FIELD_COUNT: usize = 4;
+
+    /// Parse receipt `rlp([...])` of unknown fork.
+    ///
+    /// Lenient: the outcome is taken for a state root if it is 32 bytes
+    /// long and for a status otherwise, so a malformed outcome of one kind
+    /// could pass as the other. Use [`parse_for_fork`](Self::parse_for_fork)
+    /// when the fork is known.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, ReceiptError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::from_parsed(&decode_whole_blob(data, ext_memory)?, None)
+    }
+
+    /// Parse receipt `rlp([...])` produced under `fork` rules: status `0` or
+    /// `1` since Byzantium, 32-byte state root before.
+    pub fn parse_for_fork<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        fork: Fork,
+    ) -> Result<Self, ReceiptError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::from_parsed(&decode_whole_blob(data, ext_memory)?, Some(fork))
+    }
+
+    /// Outcome kind is detected if `fork` is `None`.
+    pub(crate) fn from_parsed<E: ExternalMemory>(
+        parsed_data: &ParsedData,
+        fork: Option<Fork>,
+    ) -> Result<Self, ReceiptError<E>> {
+        let fields = parsed_data.as_list().map_err(|_| ReceiptError::NotAList)?;
+        if fields.len() != Self::FIELD_COUNT {
+            return Err(ReceiptError::WrongFieldCount {
+                expected: Self::FIELD_COUNT,
+                found: fields.len(),
+            });
+        }
+        let outcome = ReceiptOutcome::from_parsed(&fields[0], fork)?;
+        let logs = fields[3]
+            .as_list()
+            .map_err(invalid(ReceiptField::Logs))?
+            .iter()
+            .enumerate()
+            .map(|(index, log)| {
+                Log::parse_from(log).map_err(|reason| ReceiptError::InvalidLog { index, reason })
+            })
+            .collect::<Result<Vec<Log>, ReceiptError<E>>>()?;
+        Ok(Self {
+            outcome,
+            cumulative_gas_used: fields[1]
+                .as_strict_u64()
+                .map_err(invalid(ReceiptField::CumulativeGasUsed))?,
+            logs_bloom: fields[2]
+                .as_fixed_bytes::<256>()
+                .map_err(invalid(ReceiptField::LogsBloom))?,
+            logs,
+        })
+    }
+
+    /// Transaction success flag, `None` before Byzantium.
+    pub fn status(&self) -> Option<bool> {
+        match self.outcome {
+            ReceiptOutcome::Status(status) => Some(status),
+            ReceiptOutcome::StateRoot(_) => None,
+        }
+    }
+
+    /// Post-transaction state root, `None` since Byzantium.
+    pub fn state_root(&self) -> Option<&[u8; 32]> {
+        match &self.outcome {
+            ReceiptOutcome::StateRoot(root) => Some(root),
+            ReceiptOutcome::Status(_) => None,
+        }
+    }
+}
+
+impl ReceiptOutcome {
+    fn from_parsed<E: ExternalMemory>(
+        parsed_data: &ParsedData,
+        fork: Option<Fork>,
+    ) -> Result<Self, ReceiptError<E>> {
+        let bytes = parsed_data
+            .as_bytes()
+            .map_err(invalid(ReceiptField::Outcome))?;
+        let status = match bytes {
+            [] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        };
+        let root = <[u8; 32]>::try_from(bytes).ok();
+        let outcome = match fork {
+            None => status
+                .map(ReceiptOutcome::Status)
+                .or(root.map(ReceiptOutcome::StateRoot)),
+            Some(fork) if fork >= Fork::Byzantium => status.map(ReceiptOutcome::Status),
+            Some(_) => root.map(ReceiptOutcome::StateRoot),
+        };
+        outcome.ok_or(match fork {
+            None => ReceiptError::InvalidOutcome,
+            Some(fork) => ReceiptError::InvalidStatusField {
+                len: bytes.len(),
+                fork,
+            },
+        })
+    }
+}
+
+fn invalid<E: ExternalMemory>(field: ReceiptField) -> impl Fn(FieldError) -> ReceiptError<E> {
+    move |reason| ReceiptError::InvalidField { field, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{Eip658Value, Receipt as AlloyReceipt};
+    use alloy_primitives::{Bytes, Log as AlloyLog, LogData, B256};
+    use alloy_rlp::Encodable;
+    use std::vec;
+
+    use super::*;
+
+    fn hash(hex_str: &str) -> B256 {
+        hex_str.parse().unwrap()
+    }
+
+    fn log(address: &str, topics: &[&str], data: &str) -> AlloyLog {
+        AlloyLog {
+            address: address.parse().unwrap(),
+            data: LogData::new_unchecked(
+                topics.iter().map(|a| hash(a)).collect(),
+                Bytes::from(hex::decode(data).unwrap()),
+            ),
+        }
+    }
+
+    fn encode(receipt: &AlloyReceipt) -> Vec<u8> {
+        let mut raw = Vec::new();
+        receipt.clone().with_bloom().encode(&mut raw);
+        raw
+    }
+
+    fn assert_matches_alloy(receipt: AlloyReceipt) -> Receipt {
+        let raw = encode(&receipt);
+        let parsed = Receipt::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(parsed.cumulative_gas_used, receipt.cumulative_gas_used);
+        assert_eq!(parsed.logs_bloom, receipt.bloom_slow().0);
+        assert_eq!(parsed.logs.len(), receipt.logs.len());
+        for (parsed_log, log) in parsed.logs.iter().zip(receipt.logs.iter()) {
+            assert_eq!(parsed_log.address, log.address.into_array());
+            assert_eq!(
+                parsed_log.topics,
+                log.topics().iter().map(|a| a.0).collect::<Vec<_>>()
+            );
+            assert_eq!(parsed_log.data, log.data.data.to_vec());
+        }
+        parsed
+    }
+
+    /// Receipt of mainnet transaction
+    /// 0xea1093d492a1dcb1bef708f771a99a96ff05dcab81ca76c31940300177fcf49f in
+    /// block 1,000,000.
+    #[test]
+    fn pre_byzantium() {
+        let root = hash("284d35bf53b82ef480ab4208527325477439c64fb90ef518450f05ee151c8e10");
+        let parsed = assert_matches_alloy(AlloyReceipt {
+            status: Eip658Value::PostState(root),
+            cumulative_gas_used: 0x723c,
+            logs: vec![log(
+                "c083e9947cf02b8ffc7d3090ae9aea72df98fd47",
+                &["e1fffcc4923d04b559f4d29a8bfc6cda04eb5b0d3c460751c2402c5c5cc9109c"],
+                "00000000000000000000000039fa8c5f2793459d6622857e7d9fbb4bd91766d30000000000000000000000000000000000000000000000056bc75e2d63100000",
+            )],
+        });
+        assert_eq!(parsed.outcome, ReceiptOutcome::StateRoot(root.0));
+        assert_eq!(parsed.state_root(), Some(&root.0));
+        assert_eq!(parsed.status(), None);
+        assert_eq!(
+            hex::encode(parsed.logs_bloom),
+            "00000000000000000000000000000000000800000000000000000000000800000000000000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000400000000000000000"
+        );
+    }
+
+    /// Receipt of mainnet transaction
+    /// 0x21f6554c28453a01e7276c1db2fc1695bb512b170818bfa98fa8136433100616,
+    /// USDT `Approval`.
+    #[test]
+    fn success() {
+        let parsed = assert_matches_alloy(AlloyReceipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 0xa42aec,
+            logs: vec![log(
+                "dac17f958d2ee523a2206206994597c13d831ec7",
+                &[
+                    "8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925",
+                    "0000000000000000000000009a53bfba35269414f3b2d20b52ca01b15932c7b2",
+                    "00000000000000000000000039e5dbb9d2fead31234d7c647d6ce77d85826f76",
+                ],
+                "00000000000000000000000000000000000000000052b7d2dcc80cd2e4000000",
+            )],
+        });
+        assert_eq!(parsed.status(), Some(true));
+        assert_eq!(parsed.state_root(), None);
+        assert_eq!(
+            hex::encode(parsed.logs_bloom),
+            "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000200000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000400000800000000000000000000000000000000004000000000000000000800000000100000020000000000000000000080000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000010000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let parsed = assert_matches_alloy(AlloyReceipt {
+            status: Eip658Value::Eip658(false),
+            cumulative_gas_used: 3_021_402,
+            logs: Vec::new(),
+        });
+        assert_eq!(parsed.outcome, ReceiptOutcome::Status(false));
+    }
+
+    #[test]
+    fn fork_hint() {
+        let status = encode(&AlloyReceipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 21_000,
+            logs: Vec::new(),
+        });
+        let root = encode(&AlloyReceipt {
+            status: Eip658Value::PostState(B256::repeat_byte(0x96)),
+            cumulative_gas_used: 21_000,
+            logs: Vec::new(),
+        });
+        let parse = |raw: &[u8], fork| {
+            Receipt::parse_for_fork::<&[u8], ()>(&raw, &mut (), fork).map(|a| a.outcome)
+        };
+
+        assert_eq!(
+            parse(&status, Fork::Byzantium),
+            Ok(ReceiptOutcome::Status(true))
+        );
+        assert_eq!(
+            parse(&root, Fork::Byzantium),
+            Err(ReceiptError::InvalidStatusField {
+                len: 32,
+                fork: Fork::Byzantium
+            })
+        );
+        assert_eq!(
+            parse(&root, Fork::SpuriousDragon),
+            Ok(ReceiptOutcome::StateRoot([0x96; 32]))
+        );
+        assert_eq!(
+            parse(&status, Fork::SpuriousDragon),
+            Err(ReceiptError::InvalidStatusField {
+                len: 1,
+                fork: Fork::SpuriousDragon
+            })
+        );
+
+        // Status `0` is the empty string, `0x00` is not canonical.
+        let mut zero_byte = status.clone();
+        assert_eq!(zero_byte[3], 0x01);
+        zero_byte[3] = 0x00;
+        assert_eq!(
+            parse(&zero_byte, Fork::London),
+            Err(ReceiptError::InvalidStatusField {
+                len: 1,
+                fork: Fork::London
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let receipt = |outcome: ParsedData, bloom_len: usize, log: ParsedData| {
+            Receipt::from_parsed::<()>(
+                &ParsedData::List(vec![
+                    outcome,
+                    ParsedData::String(vec![0x52, 0x08]),
+                    ParsedData::String(vec![0; bloom_len]),
+                    ParsedData::List(vec![log]),
+                ]),
+                None,
+            )
+        };
+        let log = || {
+            ParsedData::List(vec![
+                ParsedData::String(vec![0x11; 20]),
+                ParsedData::List(vec![ParsedData::String(vec![0x22; 32])]),
+                ParsedData::String(Vec::new()),
+            ])
+        };
+        assert!(receipt(ParsedData::Byte(1), 256, log()).is_ok());
+        assert_eq!(
+            receipt(ParsedData::Byte(2), 256, log()),
+            Err(ReceiptError::InvalidOutcome)
+        );
+        assert_eq!(
+            receipt(ParsedData::String(vec![0x96; 31]), 256, log()),
+            Err(ReceiptError::InvalidOutcome)
+        );
+        assert_eq!(
+            receipt(ParsedData::Byte(1), 255, log()),
+            Err(ReceiptError::InvalidField {
+                field: ReceiptField::LogsBloom,
+                reason: FieldError::WrongLength {
+                    expected: 256,
+                    found: 255
+                }
+            })
+        );
+        assert_eq!(
+            receipt(
+                ParsedData::Byte(1),
+                256,
+                ParsedData::List(vec![
+                    ParsedData::String(vec![0x11; 20]),
+                    ParsedData::List(vec![ParsedData::String(vec![0x22; 31])]),
+                    ParsedData::String(Vec::new()),
+                ])
+            ),
+            Err(ReceiptError::InvalidLog {
+                index: 0,
+                reason: LogError::InvalidTopic {
+                    index: 0,
+                    reason: FieldError::WrongLength {
+                        expected: 32,
+                        found: 31
+                    }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Signer address recovery from secp256k1 signatures.
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+use crate::keccak::keccak256;
+use crate::transaction::{Signature as TxSignature, SignatureError, TransactionEnvelope};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum RecoveryError {
+    /// No public key corresponds to the signature and hash.
+    RecoveryFailed,
+    /// Signature fails structural checks of [`Signature::validate`](crate::transaction::Signature::validate).
+    Signature(SignatureError),
+}
+
+impl TransactionEnvelope {
+    /// Address of the transaction signer.
+    ///
+    /// Signature must pass structural checks, low `s` rule included, before
+    /// the recovery is attempted.
+    pub fn recover_sender(&self) -> Result<[u8; 20], RecoveryError> {
+        self.recover_sender_with(true)
+    }
+
+    /// Same as [`recover_sender`](Self::recover_sender), but legacy
+    /// transactions without chain id may have high `s`, as in blocks before
+    /// Homestead.
+    pub fn recover_sender_lenient(&self) -> Result<[u8; 20], RecoveryError> {
+        let pre_homestead =
+            matches!(self, TransactionEnvelope::Legacy(tx) if tx.chain_id.is_none());
+        self.recover_sender_with(!pre_homestead)
+    }
+
+    fn recover_sender_with(&self, low_s: bool) -> Result<[u8; 20], RecoveryError> {
+        let signature = self.signature();
+        if low_s {
+            signature.validate().map_err(RecoveryError::Signature)?;
+        }
+        let prehash = keccak256(&self.signing_payload());
+        recover_address(&prehash, &signature.r, &signature.s, signature.y_parity)
+    }
+}
+
+/// Recover address of the signer of 32-byte `prehash`.
+///
+/// Address is the last 20 bytes of keccak hash of the uncompressed public key
+/// (without `0x04` tag). High `s` is accepted.
+pub fn recover_address(
+    prehash: &[u8; 32],
+    r: &[u8; 32],
+    s: &[u8; 32],
+    y_parity: u8,
+) -> Result<[u8; 20], RecoveryError> {
+    if y_parity > 1 {
+        return Err(RecoveryError::Signature(SignatureError::InvalidYParity(
+            y_parity as u64,
+        )));
+    }
+    TxSignature {
+        r: *r,
+        s: *s,
+        y_parity,
+    }
+    .validate_lenient()
+    .map_err(RecoveryError::Signature)?;
+    let signature = Signature::from_scalars(*r, *s).map_err(|_| RecoveryError::RecoveryFailed)?;
+    // Negating `s` flips the parity of the recovered point, key is the same.
+    let (signature, y_parity) = match signature.normalize_s() {
+        Some(normalized) => (normalized, 1 - y_parity),
+        None => (signature, y_parity),
+    };
+    let recovery_id = RecoveryId::new(y_parity == 1, false);
+    let verifying_key = VerifyingKey::recover_from_prehash(prehash, &signature, recovery_id)
+        .map_err(|_| RecoveryError::RecoveryFailed)?;
+    Ok(address_from_key(&verifying_key))
+}
+
+fn address_from_key(verifying_key: &VerifyingKey) -> [u8; 20] {
+    let point = verifying_key.to_encoded_point(false);
+    let hash = keccak256(&point.as_bytes()[1..]);
+    let mut address = [0; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+    use k256::ecdsa::SigningKey;
+
+    use super::*;
+    use crate::transaction::fixtures::{
+        DEVNET_EIP7702, MAINNET_EIP1559, MAINNET_EIP4844, MAINNET_LEGACY,
+    };
+
+    /// Sepolia legacy transaction with `v` of 28 and no chain id,
+    /// 0xe5b458ba9de30b47cb7c0ea836bec7b072053123a7416c5082c97f959a4eebd6.
+    const SEPOLIA_PRE_EIP155: &str = "f8aa0285018ef61d0a832dc6c094cb33aa5b38d79e3d9fa8b10aff38aa201399a7e380b844af7b421018842e4628f3d9ee0e2c7679e29ed5dbaa75be75efecd392943503c9c68adce800000000000000000000000000000000000000000000000000000000000000641ca05e28679806caa50d25e9cb16aef8c0c08b235241b8f6e9d86faadf70421ba664a02353bba82ef2c7ce4dd6695942399163160000272b14f9aa6cbadf011b76efa4";
+
+    fn parse(raw: &str) -> TransactionEnvelope {
+        let raw = hex::decode(raw).unwrap();
+        TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+    }
+
+    /// Same signature with `s` negated and parity flipped, high `s` form of
+    /// the low `s` original.
+    fn with_high_s(tx: TransactionEnvelope) -> TransactionEnvelope {
+        let TransactionEnvelope::Legacy(mut tx) = tx else {
+            panic!("legacy transaction expected")
+        };
+        let s = k256::NonZeroScalar::try_from(&tx.signature.s[..]).unwrap();
+        tx.signature.s = (-s).to_bytes().into();
+        tx.signature.y_parity ^= 1;
+        TransactionEnvelope::Legacy(tx)
+    }
+
+    #[test]
+    fn public_networks() {
+        for (raw, sender) in [
+            (MAINNET_LEGACY, "a12e1462d0ced572f396f58b6e2d03894cd7c8a4"),
+            (MAINNET_EIP1559, "001e2b7de757ba469a57bf6b23d982458a07efce"),
+            (MAINNET_EIP4844, "0d3250c3d5facb74ac15834096397a3ef790ec99"),
+            (DEVNET_EIP7702, "6d2d4e1c2326a069f36f5d6337470dc26adb7156"),
+        ] {
+            let tx = parse(raw);
+            assert_eq!(tx.recover_sender().map(hex::encode), Ok(sender.into()));
+            assert_eq!(
+                tx.recover_sender_lenient().map(hex::encode),
+                Ok(sender.into())
+            );
+        }
+    }
+
+    #[test]
+    fn envelope_eip155_example() {
+        let raw = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+        assert_eq!(
+            parse(raw).recover_sender().map(hex::encode),
+            Ok("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f".into())
+        );
+    }
+
+    #[test]
+    fn pre_homestead_high_s() {
+        let tx = parse(SEPOLIA_PRE_EIP155);
+        let signature = tx.signature();
+        let expected = alloy_primitives::Signature::from_scalars_and_parity(
+            B256::from(signature.r),
+            B256::from(signature.s),
+            signature.y_parity == 1,
+        )
+        .recover_address_from_prehash(&B256::from(keccak256(&tx.signing_payload())))
+        .unwrap();
+        assert_eq!(tx.recover_sender(), Ok(expected.0 .0));
+
+        let high_s = with_high_s(tx);
+        assert_eq!(
+            high_s.recover_sender(),
+            Err(RecoveryError::Signature(SignatureError::HighS))
+        );
+        assert_eq!(high_s.recover_sender_lenient(), Ok(expected.0 .0));
+
+        // Chain id in `v` dates the transaction after Homestead.
+        assert_eq!(
+            with_high_s(parse(MAINNET_LEGACY)).recover_sender_lenient(),
+            Err(RecoveryError::Signature(SignatureError::HighS))
+        );
+    }
+
+    #[test]
+    fn eip155_example() {
+        // Signing hash and signature of the example transaction from EIP-155,
+        // signed by private key `0x4646..46`.
+        let prehash: [u8; 32] =
+            hex::decode("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let r: [u8; 32] =
+            hex::decode("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let s: [u8; 32] =
+            hex::decode("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let address = recover_address(&prehash, &r, &s, 0).unwrap();
+        assert_eq!(
+            hex::encode(address),
+            "9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f"
+        );
+
+        let signing_key = SigningKey::from_bytes(&[0x46; 32].into()).unwrap();
+        assert_eq!(address, address_from_key(signing_key.verifying_key()));
+    }
+
+    #[test]
+    fn wrong_parity() {
+        let tx = parse(MAINNET_EIP1559);
+        let prehash = keccak256(&tx.signing_payload());
+        let signature = tx.signature();
+        let (r, s, parity) = (signature.r, signature.s, signature.y_parity);
+
+        let expected = tx.recover_sender().unwrap();
+        assert_eq!(recover_address(&prehash, &r, &s, parity), Ok(expected));
+        assert_ne!(recover_address(&prehash, &r, &s, 1 - parity), Ok(expected));
+        assert_eq!(
+            recover_address(&prehash, &r, &s, 27),
+            Err(RecoveryError::Signature(SignatureError::InvalidYParity(27)))
+        );
+        assert_eq!(
+            recover_address(&prehash, &[0; 32], &s, parity),
+            Err(RecoveryError::Signature(SignatureError::ZeroR))
+        );
+        assert_eq!(
+            recover_address(&prehash, &r, &[0xff; 32], parity),
+            Err(RecoveryError::Signature(SignatureError::SOutOfRange))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Allocation-free hex rendering of [`ParsedData`] trees.
+//!
+//! Strings and single bytes are written as `0x`-prefixed lowercase hex,
+//! lists as `[item, item]`, e.g. `[0x0a, 0x414243, []]`.
+use core::fmt::{self, Write};
+
+use crate::ParsedData;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Output buffer cannot hold the rendering.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BufferTooSmall {
+    /// Length of the complete rendering.
+    pub required: usize,
+}
+
+impl<S: AsRef<[u8]>> ParsedData<S> {
+    /// Render the tree into any [`fmt::Write`] sink.
+    pub fn write_hex<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            ParsedData::Byte(a) => write_bytes(w, core::slice::from_ref(a)),
+            ParsedData::List(a) => {
+                w.write_char('[')?;
+                for (index, item) in a.iter().enumerate() {
+                    if index != 0 {
+                        w.write_str(", ")?;
+                    }
+                    item.write_hex(w)?;
+                }
+                w.write_char(']')
+            }
+            ParsedData::String(a) => write_bytes(w, a.as_ref()),
+        }
+    }
+
+    /// Render the tree into `out`, returning the number of bytes written.
+    pub fn write_hex_to_slice(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut writer = SliceWriter { out, written: 0 };
+        match self.write_hex(&mut writer) {
+            Ok(()) => Ok(writer.written),
+            Err(fmt::Error) => Err(BufferTooSmall {
+                required: self.hex_len(),
+            }),
+        }
+    }
+
+    /// Length of the rendering in bytes.
+    pub fn hex_len(&self) -> usize {
+        match self {
+            ParsedData::Byte(_) => 4,
+            ParsedData::List(a) => {
+                2 + a.iter().map(Self::hex_len).sum::<usize>() + 2 * a.len().saturating_sub(1)
+            }
+            ParsedData::String(a) => 2 + 2 * a.as_ref().len(),
+        }
+    }
+}
+
+fn write_bytes<W: Write>(w: &mut W, bytes: &[u8]) -> fmt::Result {
+    w.write_str("0x")?;
+    for a in bytes {
+        w.write_char(HEX_DIGITS[(a >> 4) as usize] as char)?;
+        w.write_char(HEX_DIGITS[(a & 0x0f) as usize] as char)?;
+    }
+    Ok(())
+}
+
+struct SliceWriter<'a> {
+    out: &'a mut [u8],
+    written: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.written + s.len();
+        let target = self.out.get_mut(self.written..end).ok_or(fmt::Error)?;
+        target.copy_from_slice(s.as_bytes());
+        self.written = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, vec, vec::Vec};
+
+    use super::*;
+
+    fn sample() -> ParsedData {
+        ParsedData::List(vec![
+            ParsedData::Byte(0x0a),
+            ParsedData::String(b"ABC".to_vec()),
+            ParsedData::List(vec![
+                ParsedData::String(Vec::new()),
+                ParsedData::List(Vec::new()),
+            ]),
+        ])
+    }
+
+    const SAMPLE_HEX: &str = "[0x0a, 0x414243, [0x, []]]";
+
+    #[test]
+    fn fmt_write() {
+        let mut rendered = String::new();
+        sample().write_hex(&mut rendered).unwrap();
+        assert_eq!(rendered, SAMPLE_HEX);
+        assert_eq!(sample().hex_len(), SAMPLE_HEX.len());
+    }
+
+    #[test]
+    fn slice() {
+        let mut out = [0; 64];
+        let written = sample().write_hex_to_slice(&mut out).unwrap();
+        assert_eq!(&out[..written], SAMPLE_HEX.as_bytes());
+
+        let mut exact = [0; SAMPLE_HEX.len()];
+        assert_eq!(
+            sample().write_hex_to_slice(&mut exact),
+            Ok(SAMPLE_HEX.len())
+        );
+
+        let mut short = [0; SAMPLE_HEX.len() - 1];
+        assert_eq!(
+            sample().write_hex_to_slice(&mut short),
+            Err(BufferTooSmall {
+                required: SAMPLE_HEX.len()
+            })
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Declarative decoders for fixed layout lists, see [`rlp_struct!`](crate::rlp_struct).
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::field::FieldError;
+use crate::transaction::TxKind;
+use crate::ParsedData;
+
+/// Field types decodable without an explicit accessor.
+///
+/// Integers are decoded as canonical scalars, byte arrays must have exact
+/// length. Left-padded 32-byte scalars need an explicit accessor
+/// `as_strict_u256`, as `[u8; 32]` means exactly 32 bytes here.
+pub trait FromParsed: Sized {
+    fn from_parsed(parsed_data: &ParsedData) -> Result<Self, FieldError>;
+}
+
+impl FromParsed for u8 {
+    fn from_parsed(parsed_data: &ParsedData) -> Result<Self, FieldError> {
+        parsed_data.as_strict_u8()
+    }
+}
+
+impl FromParsed for u64 {
+    fn from_parsed(parsed_data: &ParsedData) -> Result<Self, FieldError> {
+        parsed_data.as_strict_u64()
+    }
+}
+
+impl FromParsed for Vec<u8> {
+    fn from_parsed(parsed_data: &ParsedData) -> Result<Self, FieldError> {
+        parsed_data.to_bytes_vec()
+    }
+}
+
+impl<const N: usize> FromParsed for [u8; N] {
+    fn from_parsed(parsed_data: &ParsedData) -> Result<Self, FieldError> {
+        parsed_data.as_fixed_bytes::<N>()
+    }
+}
+
+impl FromParsed for TxKind {
+    fn from_parsed(parsed_data: &ParsedData) -> Result<Self, FieldError> {
+        TxKind::parse_from(parsed_data)
+    }
+}
+
+/// Errors in decoding structures defined with [`rlp_struct!`](crate::rlp_struct).
+#[derive(Debug, Eq, PartialEq)]
+pub enum StructError {
+    InvalidField {
+        field: &'static str,
+        reason: FieldError,
+    },
+    NotAList,
+    WrongFieldCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// Define a struct decoded from an RLP list with one item per field.
+///
+/// Fields are decoded in order with [`FromParsed`], or with a
+/// [`ParsedData`] accessor given after `=>`:
+///
+/// ```
+/// eth_parser::rlp_struct! {
+///     pub struct Withdrawal {
+///         pub index: u64,
+///         pub validator_index: u64,
+///         pub address: [u8; 20],
+///         pub amount: [u8; 32] => as_strict_u256,
+///     }
+/// }
+/// ```
+///
+/// Generates `FIELD_COUNT` and `from_parsed(&ParsedData) -> Result<Self,
+/// StructError>`, which checks the list arity first.
+#[macro_export]
+macro_rules! rlp_struct {
+    (@decode $item:expr, $ty:ty) => {
+        <$ty as $crate::rlp_struct::FromParsed>::from_parsed($item)
+    };
+    (@decode $item:expr, $ty:ty, $accessor:ident) => {
+        $item.$accessor()
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty $(=> $accessor:ident)?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $name {
+            pub const FIELD_COUNT: usize = [$(stringify!($field)),*].len();
+
+            pub fn from_parsed(
+                parsed_data: &$crate::ParsedData,
+            ) -> Result<Self, $crate::rlp_struct::StructError> {
+                let fields = parsed_data
+                    .as_list()
+                    .map_err(|_| $crate::rlp_struct::StructError::NotAList)?;
+                if fields.len() != Self::FIELD_COUNT {
+                    return Err($crate::rlp_struct::StructError::WrongFieldCount {
+                        expected: Self::FIELD_COUNT,
+                        found: fields.len(),
+                    });
+                }
+                let mut fields = fields.iter();
+                Ok(Self {
+                    $(
+                        $field: $crate::rlp_struct!(
+                            @decode fields.next().unwrap(), $ty $(, $accessor)?
+                        )
+                        .map_err(|reason| $crate::rlp_struct::StructError::InvalidField {
+                            field: stringify!($field),
+                            reason,
+                        })?,
+                    )*
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+    use crate::decode_whole_blob;
+
+    crate::rlp_struct! {
+        /// Withdrawal, EIP-4895.
+        #[derive(Debug, Eq, PartialEq)]
+        struct Withdrawal {
+            index: u64,
+            validator_index: u64,
+            address: [u8; 20],
+            amount: [u8; 32] => as_strict_u256,
+        }
+    }
+
+    crate::rlp_struct! {
+        #[derive(Debug, Eq, PartialEq)]
+        pub(crate) struct Call {
+            pub to: TxKind,
+            pub data: Vec<u8>,
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let mut amount = [0; 32];
+        amount[30..].copy_from_slice(&[0x03, 0xe8]);
+        let raw = hex::decode("da01029411111111111111111111111111111111111111118203e8").unwrap();
+        let parsed = decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(Withdrawal::FIELD_COUNT, 4);
+        assert_eq!(
+            Withdrawal::from_parsed(&parsed),
+            Ok(Withdrawal {
+                index: 1,
+                validator_index: 2,
+                address: [0x11; 20],
+                amount,
+            })
+        );
+
+        let parsed = ParsedData::List(vec![ParsedData::String(vec![]), ParsedData::Byte(0x05)]);
+        assert_eq!(
+            Call::from_parsed(&parsed),
+            Ok(Call {
+                to: TxKind::Create,
+                data: vec![0x05],
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Call::from_parsed(&ParsedData::String(vec![])),
+            Err(StructError::NotAList)
+        );
+        assert_eq!(
+            Call::from_parsed(&ParsedData::List(vec![ParsedData::String(vec![])])),
+            Err(StructError::WrongFieldCount {
+                expected: 2,
+                found: 1
+            })
+        );
+        let parsed = ParsedData::List(vec![
+            ParsedData::Byte(1),
+            ParsedData::String(vec![0, 2]),
+            ParsedData::String(vec![0x11; 20]),
+            ParsedData::String(vec![]),
+        ]);
+        assert_eq!(
+            Withdrawal::from_parsed(&parsed),
+            Err(StructError::InvalidField {
+                field: "validator_index",
+                reason: FieldError::LeadingZero
+            })
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Item locations within the buffer, found without copying item payloads.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, BufferError, ExternalMemory};
+
+use crate::{
+    check_position, decode_blob_portion_at_position, limit_to, read_prefix, Error, ParsedData,
+    Prefix,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ItemKind {
+    Byte,
+    String,
+    List,
+}
+
+/// Location of a single item.
+///
+/// Item occupies `start..end`, its payload occupies `payload_start..end`. For
+/// single byte items the payload is the byte itself.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ItemSpan {
+    pub kind: ItemKind,
+    pub start: usize,
+    pub payload_start: usize,
+    pub end: usize,
+}
+
+impl ItemSpan {
+    pub fn payload_len(&self) -> usize {
+        self.end - self.payload_start
+    }
+
+    /// Read item payload; for external memory this is the only read of it.
+    pub fn read_payload<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+    ) -> Result<B::ReadBuffer, Error<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        data.read_slice(ext_memory, self.payload_start, self.payload_len())
+            .map_err(Error::Buffer)
+    }
+
+    /// Read whole item, prefix included.
+    pub fn read_raw<B, E>(&self, data: &B, ext_memory: &mut E) -> Result<B::ReadBuffer, Error<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        data.read_slice(ext_memory, self.start, self.end - self.start)
+            .map_err(Error::Buffer)
+    }
+
+    /// Spans of list elements.
+    pub fn list_items<B, E>(&self, data: &B, ext_memory: &mut E) -> Result<Vec<ItemSpan>, Error<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        if self.kind != ItemKind::List {
+            return Err(Error::ExpectedList {
+                position: self.start,
+            });
+        }
+        let limited_data = limit_to(data, self.end)?;
+        let mut position = self.payload_start;
+        let mut items = Vec::new();
+        while position < self.end {
+            items.push(span_at_position(&limited_data, ext_memory, &mut position)?);
+        }
+        Ok(items)
+    }
+}
+
+/// Locate the item starting at `position`, moving `position` past it.
+///
+/// Only the item prefix is read. The declared item length is checked against
+/// the buffer length.
+pub fn span_at_position<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+) -> Result<ItemSpan, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    check_position(data, *position)?;
+    let start = *position;
+    let prefix = read_prefix(data, ext_memory, position)?.0;
+    #[cfg(feature = "trace")]
+    crate::trace_item(start, &prefix);
+    let (kind, payload_start, payload_len) = match prefix {
+        Prefix::Byte(_) => (ItemKind::Byte, start, 1),
+        Prefix::String(length) => (ItemKind::String, *position, length),
+        Prefix::List(length) => (ItemKind::List, *position, length),
+    };
+    let end = payload_start + payload_len;
+    if end > data.total_len() {
+        return Err(Error::Buffer(BufferError::DataTooShort {
+            position: payload_start,
+            minimal_length: payload_len,
+        }));
+    }
+    *position = end;
+    Ok(ItemSpan {
+        kind,
+        start,
+        payload_start,
+        end,
+    })
+}
+
+/// List with its elements located, but not decoded.
+///
+/// Elements are decoded or read raw on request, by index. Methods taking
+/// `index` return `None` if it is out of range of [`items`](Self::items).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpannedList {
+    pub span: ItemSpan,
+    pub items: Vec<ItemSpan>,
+}
+
+impl SpannedList {
+    /// Locate elements of the list taking the whole `data`.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, Error<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let mut position = 0;
+        let list = Self::at_position(data, ext_memory, &mut position)?;
+        if position < data.total_len() {
+            return Err(Error::SomeDataUnused { from: position });
+        }
+        Ok(list)
+    }
+
+    /// Locate elements of the list starting at `position`, moving
+    /// `position` past the list.
+    pub fn at_position<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        position: &mut usize,
+    ) -> Result<Self, Error<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let span = span_at_position(data, ext_memory, position)?;
+        let items = span.list_items(data, ext_memory)?;
+        Ok(Self { span, items })
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Encoded element `index`, prefix included.
+    pub fn raw<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        index: usize,
+    ) -> Option<Result<B::ReadBuffer, Error<E>>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let item = self.items.get(index)?;
+        Some(item.read_raw(data, ext_memory))
+    }
+
+    /// Decode element `index` in full.
+    pub fn decode<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        index: usize,
+    ) -> Option<Result<ParsedData, Error<E>>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let item = self.items.get(index)?;
+        let mut position = item.start;
+        Some(limit_to(data, item.end).and_then(|limited_data| {
+            decode_blob_portion_at_position(&limited_data, ext_memory, &mut position)
+        }))
+    }
+
+    /// Locate elements of element `index`, which must be a list.
+    pub fn nested<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        index: usize,
+    ) -> Option<Result<Self, Error<E>>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let span = *self.items.get(index)?;
+        Some(
+            span.list_items(data, ext_memory)
+                .map(|items| Self { span, items }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+
+    #[test]
+    fn spans_of_nested_list() {
+        // [ "cat", 0x05, [ "" ] ]
+        let data = [0xc7, 0x83, b'c', b'a', b't', 0x05, 0xc1, 0x80];
+        let mut position = 0;
+        let list = span_at_position::<&[u8], ()>(&data.as_ref(), &mut (), &mut position).unwrap();
+        assert_eq!(position, 8);
+        assert_eq!(
+            list,
+            ItemSpan {
+                kind: ItemKind::List,
+                start: 0,
+                payload_start: 1,
+                end: 8
+            }
+        );
+
+        let items = list.list_items(&data.as_ref(), &mut ()).unwrap();
+        assert_eq!(
+            items,
+            vec![
+                ItemSpan {
+                    kind: ItemKind::String,
+                    start: 1,
+                    payload_start: 2,
+                    end: 5
+                },
+                ItemSpan {
+                    kind: ItemKind::Byte,
+                    start: 5,
+                    payload_start: 5,
+                    end: 6
+                },
+                ItemSpan {
+                    kind: ItemKind::List,
+                    start: 6,
+                    payload_start: 7,
+                    end: 8
+                },
+            ]
+        );
+        assert_eq!(
+            items[0].read_payload(&data.as_ref(), &mut ()).unwrap(),
+            b"cat"
+        );
+        assert_eq!(
+            items[1].read_payload(&data.as_ref(), &mut ()).unwrap(),
+            [0x05]
+        );
+        assert_eq!(
+            items[2].read_raw(&data.as_ref(), &mut ()).unwrap(),
+            [0xc1, 0x80]
+        );
+    }
+
+    #[test]
+    fn declared_length_past_end() {
+        let data = [0x83, b'c', b'a'];
+        let error = span_at_position::<&[u8], ()>(&data.as_ref(), &mut (), &mut 0).unwrap_err();
+        assert_eq!(
+            error,
+            Error::Buffer(BufferError::DataTooShort {
+                position: 1,
+                minimal_length: 3
+            })
+        );
+    }
+
+    #[test]
+    fn spanned_list() {
+        // [ "cat", 0x05, [ "", [] ] ]
+        let data = [0xc8, 0x83, b'c', b'a', b't', 0x05, 0xc2, 0x80, 0xc0];
+        let list = SpannedList::parse::<&[u8], ()>(&data.as_ref(), &mut ()).unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list.raw::<&[u8], ()>(&data.as_ref(), &mut (), 0),
+            Some(Ok(&data[1..5]))
+        );
+        assert_eq!(
+            list.decode::<&[u8], ()>(&data.as_ref(), &mut (), 1),
+            Some(Ok(ParsedData::Byte(0x05)))
+        );
+        assert_eq!(
+            list.decode::<&[u8], ()>(&data.as_ref(), &mut (), 2),
+            Some(Ok(ParsedData::List(vec![
+                ParsedData::String(Vec::new()),
+                ParsedData::List(Vec::new())
+            ])))
+        );
+        let nested = list
+            .nested::<&[u8], ()>(&data.as_ref(), &mut (), 2)
+            .unwrap()
+            .unwrap();
+        assert_eq!(nested.len(), 2);
+        assert_eq!(nested.span, list.items[2]);
+        assert!(nested
+            .nested::<&[u8], ()>(&data.as_ref(), &mut (), 1)
+            .unwrap()
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            list.nested::<&[u8], ()>(&data.as_ref(), &mut (), 0),
+            Some(Err(Error::ExpectedList { position: 1 }))
+        );
+
+        // Out of range.
+        assert_eq!(list.raw::<&[u8], ()>(&data.as_ref(), &mut (), 3), None);
+        assert_eq!(list.decode::<&[u8], ()>(&data.as_ref(), &mut (), 3), None);
+        assert_eq!(list.nested::<&[u8], ()>(&data.as_ref(), &mut (), 3), None);
+
+        assert_eq!(
+            SpannedList::parse::<&[u8], ()>(&[0xc0, 0x01].as_ref(), &mut ()),
+            Err(Error::SomeDataUnused { from: 1 })
+        );
+        assert_eq!(
+            SpannedList::parse::<&[u8], ()>(&[0x80].as_ref(), &mut ()),
+            Err(Error::ExpectedList { position: 0 })
+        );
+    }
+}
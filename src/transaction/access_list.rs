@@ -0,0 +1,204 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use core::ops::Deref;
+
+use crate::field::FieldError;
+use crate::ParsedData;
+
+/// EIP-2930 gas cost per access list entry.
+pub const ACCESS_LIST_ADDRESS_COST: u64 = 2400;
+
+/// EIP-2930 gas cost per storage key in access list.
+pub const ACCESS_LIST_STORAGE_KEY_COST: u64 = 1900;
+
+/// Addresses and storage keys a transaction plans to access, EIP-2930.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccessList(pub Vec<AccessListItem>);
+
+/// Single access list entry: an address and the storage keys it touches.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessListItem {
+    pub address: [u8; 20],
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+/// Errors in access list entries, with the index of the failing entry.
+#[derive(Debug, Eq, PartialEq)]
+pub enum AccessListError {
+    InvalidAddress {
+        entry: usize,
+        reason: FieldError,
+    },
+    InvalidStorageKey {
+        entry: usize,
+        key: usize,
+        reason: FieldError,
+    },
+    MalformedEntry {
+        entry: usize,
+    },
+    NotAList,
+    StorageKeysNotAList {
+        entry: usize,
+    },
+}
+
+impl AccessList {
+    /// Parse list of `[address, [storage_key, ...]]` pairs, with 20-byte
+    /// addresses and 32-byte storage keys.
+    pub fn parse_from(parsed_data: &ParsedData) -> Result<Self, AccessListError> {
+        let entries = parsed_data
+            .as_list()
+            .map_err(|_| AccessListError::NotAList)?;
+        let mut access_list = Vec::with_capacity(entries.len());
+        for (entry, item) in entries.iter().enumerate() {
+            let pair = match item {
+                ParsedData::List(pair) if pair.len() == 2 => pair,
+                _ => return Err(AccessListError::MalformedEntry { entry }),
+            };
+            let address = pair[0]
+                .as_fixed_bytes::<20>()
+                .map_err(|reason| AccessListError::InvalidAddress { entry, reason })?;
+            let keys = pair[1]
+                .as_list()
+                .map_err(|_| AccessListError::StorageKeysNotAList { entry })?;
+            let mut storage_keys = Vec::with_capacity(keys.len());
+            for (key, storage_key) in keys.iter().enumerate() {
+                storage_keys.push(
+                    storage_key.as_fixed_bytes::<32>().map_err(|reason| {
+                        AccessListError::InvalidStorageKey { entry, key, reason }
+                    })?,
+                );
+            }
+            access_list.push(AccessListItem {
+                address,
+                storage_keys,
+            });
+        }
+        Ok(Self(access_list))
+    }
+
+    /// Tree of `[address, [storage_key, ...]]` pairs, as in transactions.
+    pub(crate) fn to_parsed(&self) -> ParsedData {
+        ParsedData::List(
+            self.0
+                .iter()
+                .map(|item| {
+                    ParsedData::List(Vec::from([
+                        ParsedData::String(item.address.to_vec()),
+                        ParsedData::List(
+                            item.storage_keys
+                                .iter()
+                                .map(|key| ParsedData::String(key.to_vec()))
+                                .collect(),
+                        ),
+                    ]))
+                })
+                .collect(),
+        )
+    }
+
+    /// Total number of storage keys over all entries.
+    pub fn storage_key_count(&self) -> usize {
+        self.0.iter().map(|item| item.storage_keys.len()).sum()
+    }
+
+    /// Intrinsic gas charged for the access list.
+    pub fn gas_cost(&self) -> u64 {
+        ACCESS_LIST_ADDRESS_COST * self.0.len() as u64
+            + ACCESS_LIST_STORAGE_KEY_COST * self.storage_key_count() as u64
+    }
+}
+
+impl Deref for AccessList {
+    type Target = [AccessListItem];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+
+    fn entry(address: u8, keys: Vec<Vec<u8>>) -> ParsedData {
+        ParsedData::List(vec![
+            ParsedData::String(vec![address; 20]),
+            ParsedData::List(keys.into_iter().map(ParsedData::String).collect()),
+        ])
+    }
+
+    #[test]
+    fn empty() {
+        let access_list = AccessList::parse_from(&ParsedData::List(Vec::new())).unwrap();
+        assert!(access_list.is_empty());
+        assert_eq!(access_list.gas_cost(), 0);
+    }
+
+    #[test]
+    fn multiple_entries() {
+        let parsed_data = ParsedData::List(vec![
+            entry(0x01, vec![vec![0x0a; 32], vec![0x0b; 32]]),
+            entry(0x02, vec![]),
+            entry(0x03, vec![vec![0x0c; 32]]),
+        ]);
+        let access_list = AccessList::parse_from(&parsed_data).unwrap();
+        assert_eq!(
+            access_list,
+            AccessList(vec![
+                AccessListItem {
+                    address: [0x01; 20],
+                    storage_keys: vec![[0x0a; 32], [0x0b; 32]],
+                },
+                AccessListItem {
+                    address: [0x02; 20],
+                    storage_keys: vec![],
+                },
+                AccessListItem {
+                    address: [0x03; 20],
+                    storage_keys: vec![[0x0c; 32]],
+                },
+            ])
+        );
+        assert_eq!(access_list.storage_key_count(), 3);
+        // 3 * 2400 + 3 * 1900
+        assert_eq!(access_list.gas_cost(), 12_900);
+    }
+
+    #[test]
+    fn short_storage_key() {
+        let parsed_data = ParsedData::List(vec![
+            entry(0x01, vec![]),
+            entry(0x02, vec![vec![0x0a; 32]]),
+            entry(0x03, vec![vec![0x0b; 32], vec![0x0c; 31]]),
+        ]);
+        assert_eq!(
+            AccessList::parse_from(&parsed_data),
+            Err(AccessListError::InvalidStorageKey {
+                entry: 2,
+                key: 1,
+                reason: FieldError::WrongLength {
+                    expected: 32,
+                    found: 31
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn gas_cost_single_entry() {
+        let access_list = AccessList(vec![AccessListItem {
+            address: [0; 20],
+            storage_keys: vec![[0; 32]; 5],
+        }]);
+        // 2400 + 5 * 1900
+        assert_eq!(access_list.gas_cost(), 11_900);
+    }
+}
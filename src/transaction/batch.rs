@@ -0,0 +1,442 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::span::{span_at_position, ItemKind, ItemSpan};
+use crate::transaction::pooled::is_blob_wrapper;
+use crate::transaction::{BatchError, LegacyTransaction, TransactionEnvelope, TxConfig, TxError};
+use crate::{decode_blob_portion_at_position, limit_to, Error, BORDER_A};
+
+/// Decode the list of transactions starting at `position`, as found in a
+/// block body, moving `position` past the list.
+///
+/// Legacy transactions are list items, typed transactions are string items
+/// wrapping `type || payload`. The first invalid transaction aborts decoding.
+pub fn decode_transactions<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+) -> Result<Vec<TransactionEnvelope>, BatchError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    decode_transactions_with_config(data, ext_memory, position, &TxConfig::default())
+}
+
+/// Same as [`decode_transactions`] with given options. Each transaction is
+/// checked against `config.limits` before it is decoded.
+pub fn decode_transactions_with_config<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+    config: &TxConfig,
+) -> Result<Vec<TransactionEnvelope>, BatchError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    decode_transactions_with_spans(data, ext_memory, position, config)
+        .map(|(transactions, _)| transactions)
+}
+
+/// Same as [`decode_transactions_with_config`], also returning the
+/// transaction spans.
+pub(crate) fn decode_transactions_with_spans<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+    config: &TxConfig,
+) -> Result<(Vec<TransactionEnvelope>, Vec<ItemSpan>), BatchError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let (list_data, spans) = transaction_spans(data, ext_memory, position)?;
+    let transactions = spans
+        .iter()
+        .enumerate()
+        .map(|(index, span)| {
+            decode_item(&list_data, ext_memory, span, config).map_err(|source| {
+                BatchError::Transaction {
+                    index,
+                    source,
+                    span: *span,
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((transactions, spans))
+}
+
+/// Same as [`decode_transactions`], but invalid transactions do not abort
+/// decoding and are reported in place.
+///
+/// Only errors in the list structure itself, including item prefixes, fail
+/// the whole batch.
+pub fn decode_transactions_lenient<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+) -> Result<Vec<Result<TransactionEnvelope, TxError<E>>>, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    decode_transactions_lenient_with_config(data, ext_memory, position, &TxConfig::default())
+}
+
+/// Same as [`decode_transactions_lenient`] with given options. Transactions
+/// over `config.limits` are reported in place.
+pub fn decode_transactions_lenient_with_config<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+    config: &TxConfig,
+) -> Result<Vec<Result<TransactionEnvelope, TxError<E>>>, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let (list_data, spans) = transaction_spans(data, ext_memory, position)?;
+    Ok(spans
+        .iter()
+        .map(|span| decode_item(&list_data, ext_memory, span, config))
+        .collect())
+}
+
+/// Spans of list items and the buffer limited to the list end.
+fn transaction_spans<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    position: &mut usize,
+) -> Result<(B, Vec<ItemSpan>), Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let list = span_at_position(data, ext_memory, position)?;
+    let spans = list.list_items(data, ext_memory)?;
+    let list_data = limit_to(data, list.end)?;
+    Ok((list_data, spans))
+}
+
+/// Decode transaction in its block body form located at `span`.
+pub(crate) fn decode_item<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    span: &ItemSpan,
+    config: &TxConfig,
+) -> Result<TransactionEnvelope, TxError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let data = limit_to(data, span.end)?;
+    let mut position = span.start;
+    if span.kind == ItemKind::List {
+        config.limits.check_at(
+            &data,
+            ext_memory,
+            LegacyTransaction::TX_TYPE,
+            position,
+            span.end - span.start,
+        )?;
+        let parsed_data = decode_blob_portion_at_position(&data, ext_memory, &mut position)?;
+        return Ok(TransactionEnvelope::Legacy(LegacyTransaction::from_parsed(
+            &parsed_data,
+            config,
+        )?));
+    }
+    position = span.payload_start;
+    if is_blob_wrapper(&data, ext_memory, position) {
+        return Err(TxError::WrapperNotAllowedHere);
+    }
+    let tx_type = data
+        .read_byte(ext_memory, position)
+        .map_err(Error::Buffer)?;
+    if tx_type >= BORDER_A {
+        return Err(TxError::NotATransaction {
+            first_byte: tx_type,
+        });
+    }
+    position += 1;
+    config.limits.check_at(
+        &data,
+        ext_memory,
+        tx_type,
+        position,
+        span.end - span.payload_start,
+    )?;
+    let parsed_data = decode_blob_portion_at_position(&data, ext_memory, &mut position)?;
+    if position < span.end {
+        return Err(TxError::Decoding(Error::SomeDataUnused { from: position }));
+    }
+    TransactionEnvelope::from_typed_parsed(tx_type, &parsed_data, config)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+    use crate::transaction::{
+        AccessList, LimitField, Signature, TxBuilder, TxField, TxKind, TxLimits,
+    };
+    use crate::{decode_whole_blob, field::FieldError, ParsedData};
+
+    const LEGACY: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    /// Mainnet genesis block, `[header, transactions, ommers]`.
+    const GENESIS_BLOCK: &str = "f90219f90214a00000000000000000000000000000000000000000000000000000000000000000a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347940000000000000000000000000000000000000000a0d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000850400000000808213888080a011bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82faa00000000000000000000000000000000000000000000000000000000000000000880000000000000042c0c0";
+
+    const GENESIS_HASH: &str = "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3";
+
+    fn signature() -> Signature {
+        Signature {
+            r: [0x01; 32],
+            s: [0x01; 32],
+            y_parity: 1,
+        }
+    }
+
+    fn typed() -> [TransactionEnvelope; 2] {
+        let eip2930 = TxBuilder::eip2930()
+            .chain_id(1)
+            .nonce(1)
+            .gas_price([0x01; 32])
+            .gas_limit(30_000)
+            .to(TxKind::Call([0x11; 20]))
+            .access_list(AccessList(Vec::new()))
+            .build_signed(signature())
+            .unwrap();
+        let eip1559 = TxBuilder::eip1559()
+            .chain_id(1)
+            .nonce(2)
+            .max_priority_fee_per_gas([0x01; 32])
+            .max_fee_per_gas([0x02; 32])
+            .gas_limit(21_000)
+            .to(TxKind::Call([0x22; 20]))
+            .data(vec![0xde, 0xad])
+            .build_signed(signature())
+            .unwrap();
+        [eip2930, eip1559]
+    }
+
+    /// Block body transactions list `[legacy, eip2930, eip1559]`, with a
+    /// leading byte to check positions.
+    fn body(typed: &[Vec<u8>]) -> Vec<u8> {
+        let legacy = hex::decode(LEGACY).unwrap();
+        let mut items = vec![decode_whole_blob::<&[u8], ()>(&legacy.as_ref(), &mut ()).unwrap()];
+        items.extend(typed.iter().map(|raw| ParsedData::String(raw.clone())));
+        let mut data = vec![0x01];
+        data.extend(ParsedData::List(items).to_vec());
+        data
+    }
+
+    #[test]
+    fn mixed_types() {
+        let typed = typed();
+        let data = body(&[typed[0].encode(), typed[1].encode()]);
+        let mut position = 1;
+        let decoded =
+            decode_transactions::<&[u8], ()>(&data.as_ref(), &mut (), &mut position).unwrap();
+        assert_eq!(position, data.len());
+        let legacy = TransactionEnvelope::parse::<&[u8], ()>(
+            &hex::decode(LEGACY).unwrap().as_ref(),
+            &mut (),
+        )
+        .unwrap();
+        assert_eq!(decoded, vec![legacy, typed[0].clone(), typed[1].clone()]);
+    }
+
+    #[test]
+    fn corrupted_element() {
+        let typed = typed();
+        let mut eip2930 = typed[0].encode();
+        // Gas limit of two bytes, `0x82 0x75 0x30`, turned into a list.
+        let gas_limit = eip2930
+            .windows(3)
+            .position(|a| a == [0x82, 0x75, 0x30])
+            .unwrap();
+        eip2930[gas_limit] = 0xc2;
+        let data = body(&[eip2930, typed[1].encode()]);
+
+        let mut position = 1;
+        let error =
+            decode_transactions::<&[u8], ()>(&data.as_ref(), &mut (), &mut position).unwrap_err();
+        let BatchError::Transaction {
+            index,
+            source,
+            span,
+        } = error
+        else {
+            panic!("expected transaction error, got {error:?}");
+        };
+        assert_eq!(index, 1);
+        assert_eq!(
+            source,
+            TxError::InvalidField {
+                field: TxField::GasLimit,
+                reason: FieldError::ExpectedString
+            }
+        );
+        assert_eq!(span.kind, ItemKind::String);
+        assert_eq!(span.start, 1 + 3 + 0x6e);
+        assert_eq!(data[span.payload_start], 0x01);
+
+        let mut position = 1;
+        let decoded =
+            decode_transactions_lenient::<&[u8], ()>(&data.as_ref(), &mut (), &mut position)
+                .unwrap();
+        assert_eq!(decoded.len(), 3);
+        assert!(decoded[0].is_ok());
+        assert!(decoded[1].is_err());
+        assert_eq!(decoded[2], Ok(typed[1].clone()));
+    }
+
+    #[test]
+    fn limits() {
+        let typed = typed();
+        let raw = [typed[0].encode(), typed[1].encode()];
+        let data = body(&raw);
+        let config = |limits| TxConfig {
+            limits,
+            ..Default::default()
+        };
+
+        // Only the EIP-1559 transaction has data.
+        let data_limit = config(TxLimits {
+            max_data_bytes: 1,
+            ..Default::default()
+        });
+        let error = decode_transactions_with_config::<&[u8], ()>(
+            &data.as_ref(),
+            &mut (),
+            &mut 1,
+            &data_limit,
+        )
+        .unwrap_err();
+        let BatchError::Transaction { index, source, .. } = error else {
+            panic!("expected transaction error, got {error:?}");
+        };
+        assert_eq!(index, 2);
+        assert_eq!(
+            source,
+            TxError::LimitExceeded {
+                field: LimitField::Data,
+                limit: 1,
+                actual: 2
+            }
+        );
+
+        // Total size counts the type byte of typed transactions.
+        let legacy_len = hex::decode(LEGACY).unwrap().len();
+        let total_limit = config(TxLimits {
+            max_total_bytes: legacy_len,
+            ..Default::default()
+        });
+        let decoded = decode_transactions_lenient_with_config::<&[u8], ()>(
+            &data.as_ref(),
+            &mut (),
+            &mut 1,
+            &total_limit,
+        )
+        .unwrap();
+        assert!(decoded[0].is_ok());
+        for (result, raw) in decoded[1..].iter().zip(&raw) {
+            if raw.len() > legacy_len {
+                assert_eq!(
+                    result,
+                    &Err(TxError::LimitExceeded {
+                        field: LimitField::Total,
+                        limit: legacy_len,
+                        actual: raw.len()
+                    })
+                );
+            } else {
+                assert!(result.is_ok());
+            }
+        }
+        assert_eq!(
+            decode_transactions::<&[u8], ()>(&data.as_ref(), &mut (), &mut 1).map(|a| a.len()),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn malformed_items() {
+        // String wrapping a legacy transaction, and trailing bytes after a
+        // typed payload.
+        let legacy = hex::decode(LEGACY).unwrap();
+        let mut trailing = typed()[1].encode();
+        trailing.push(0x00);
+        let data = ParsedData::List(vec![
+            ParsedData::String(legacy),
+            ParsedData::String(trailing.clone()),
+            ParsedData::String(Vec::new()),
+        ])
+        .to_vec();
+        let decoded =
+            decode_transactions_lenient::<&[u8], ()>(&data.as_ref(), &mut (), &mut 0).unwrap();
+        assert_eq!(
+            decoded[0],
+            Err(TxError::NotATransaction { first_byte: 0xf8 })
+        );
+        assert!(matches!(
+            decoded[1],
+            Err(TxError::Decoding(Error::SomeDataUnused { .. }))
+        ));
+        assert!(matches!(
+            decoded[2],
+            Err(TxError::Decoding(Error::Buffer(_)))
+        ));
+
+        assert_eq!(
+            decode_transactions::<&[u8], ()>(&[0x80].as_ref(), &mut (), &mut 0),
+            Err(BatchError::Decoding(Error::ExpectedList { position: 0 }))
+        );
+    }
+
+    #[test]
+    fn mainnet_genesis_block() {
+        let data = hex::decode(GENESIS_BLOCK).unwrap();
+        let mut position = 0;
+        let block = span_at_position::<&[u8], ()>(&data.as_ref(), &mut (), &mut position).unwrap();
+        assert_eq!(position, data.len());
+        let items = block.list_items(&data.as_ref(), &mut ()).unwrap();
+        assert_eq!(items.len(), 3);
+
+        // Block hash is the keccak of the header as encoded.
+        let header_raw = items[0].read_raw(&data.as_ref(), &mut ()).unwrap();
+        assert_eq!(
+            alloy_primitives::keccak256(header_raw).to_vec(),
+            hex::decode(GENESIS_HASH).unwrap()
+        );
+
+        let header = decode_whole_blob::<&[u8], ()>(&header_raw, &mut ()).unwrap();
+        let fields = header.as_list().unwrap();
+        assert_eq!(fields.len(), 15);
+        assert_eq!(fields[7].as_strict_u64(), Ok(0x04_0000_0000));
+        assert_eq!(fields[8].as_strict_u64(), Ok(0));
+        assert_eq!(fields[9].as_strict_u64(), Ok(5000));
+        assert_eq!(
+            fields[12].as_bytes().unwrap(),
+            hex::decode("11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa")
+                .unwrap()
+        );
+        assert_eq!(fields[14].as_fixed_bytes::<8>(), Ok(0x42u64.to_be_bytes()));
+
+        let mut position = items[1].start;
+        assert_eq!(
+            decode_transactions::<&[u8], ()>(&data.as_ref(), &mut (), &mut position),
+            Ok(Vec::new())
+        );
+        assert_eq!(position, items[2].start);
+        assert_eq!(items[2].payload_len(), 0);
+    }
+}
@@ -0,0 +1,750 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::transaction::{
+    AccessList, Authorization, BuildError, Eip1559Transaction, Eip2930Transaction,
+    Eip4844Transaction, Eip7702Transaction, LegacyTransaction, Signature, SignatureError,
+    TransactionEnvelope, TxField, TxKind, UnsignedTransaction, VersionedHash, MAX_EIP155_CHAIN_ID,
+};
+
+/// Builder of new transactions of any supported type.
+///
+/// Fields not used by the selected type are rejected by
+/// [`build`](Self::build). `value`, `data` and `access_list` default to
+/// empty; all other fields used by the type must be set, except `chain_id`
+/// of legacy transactions, which selects EIP-155 signing if set.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TxBuilder {
+    tx_type: u8,
+    chain_id: Option<u64>,
+    nonce: Option<u64>,
+    gas_price: Option<[u8; 32]>,
+    max_priority_fee_per_gas: Option<[u8; 32]>,
+    max_fee_per_gas: Option<[u8; 32]>,
+    gas_limit: Option<u64>,
+    to: Option<TxKind>,
+    value: [u8; 32],
+    data: Vec<u8>,
+    access_list: Option<AccessList>,
+    max_fee_per_blob_gas: Option<[u8; 32]>,
+    blob_versioned_hashes: Option<Vec<[u8; 32]>>,
+    authorization_list: Option<Vec<Authorization>>,
+}
+
+impl TxBuilder {
+    pub fn legacy() -> Self {
+        Self::new(LegacyTransaction::TX_TYPE)
+    }
+
+    pub fn eip2930() -> Self {
+        Self::new(Eip2930Transaction::TX_TYPE)
+    }
+
+    pub fn eip1559() -> Self {
+        Self::new(Eip1559Transaction::TX_TYPE)
+    }
+
+    pub fn eip4844() -> Self {
+        Self::new(Eip4844Transaction::TX_TYPE)
+    }
+
+    pub fn eip7702() -> Self {
+        Self::new(Eip7702Transaction::TX_TYPE)
+    }
+
+    fn new(tx_type: u8) -> Self {
+        Self {
+            tx_type,
+            chain_id: None,
+            nonce: None,
+            gas_price: None,
+            max_priority_fee_per_gas: None,
+            max_fee_per_gas: None,
+            gas_limit: None,
+            to: None,
+            value: [0; 32],
+            data: Vec::new(),
+            access_list: None,
+            max_fee_per_blob_gas: None,
+            blob_versioned_hashes: None,
+            authorization_list: None,
+        }
+    }
+
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    pub fn gas_price(mut self, gas_price: [u8; 32]) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    pub fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: [u8; 32]) -> Self {
+        self.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+        self
+    }
+
+    pub fn max_fee_per_gas(mut self, max_fee_per_gas: [u8; 32]) -> Self {
+        self.max_fee_per_gas = Some(max_fee_per_gas);
+        self
+    }
+
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    pub fn to(mut self, to: TxKind) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn value(mut self, value: [u8; 32]) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn data(mut self, data: Vec<u8>) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn access_list(mut self, access_list: AccessList) -> Self {
+        self.access_list = Some(access_list);
+        self
+    }
+
+    pub fn max_fee_per_blob_gas(mut self, max_fee_per_blob_gas: [u8; 32]) -> Self {
+        self.max_fee_per_blob_gas = Some(max_fee_per_blob_gas);
+        self
+    }
+
+    pub fn blob_versioned_hashes(mut self, blob_versioned_hashes: Vec<[u8; 32]>) -> Self {
+        self.blob_versioned_hashes = Some(blob_versioned_hashes);
+        self
+    }
+
+    pub fn authorization_list(mut self, authorization_list: Vec<Authorization>) -> Self {
+        self.authorization_list = Some(authorization_list);
+        self
+    }
+
+    /// Validate the fields and produce the payload to sign.
+    pub fn build(self) -> Result<UnsignedTransaction, BuildError> {
+        // Signature is not part of the signing payload.
+        let envelope = self.into_envelope(Signature {
+            r: [0; 32],
+            s: [0; 32],
+            y_parity: 0,
+        })?;
+        Ok(UnsignedTransaction {
+            payload: envelope.into_unsigned_bytes(),
+            chain_id: envelope.chain_id(),
+        })
+    }
+
+    /// Validate the fields and the signature and produce the signed
+    /// transaction; [`TransactionEnvelope::encode`] gives the raw bytes.
+    pub fn build_signed(self, signature: Signature) -> Result<TransactionEnvelope, BuildError> {
+        if signature.y_parity > 1 {
+            return Err(BuildError::InvalidSignature(
+                SignatureError::InvalidYParity(signature.y_parity as u64),
+            ));
+        }
+        signature.validate().map_err(BuildError::InvalidSignature)?;
+        self.into_envelope(signature)
+    }
+
+    fn into_envelope(self, signature: Signature) -> Result<TransactionEnvelope, BuildError> {
+        self.check_unused_fields()?;
+        let access_list = self.access_list.unwrap_or_default();
+        let nonce = required(self.nonce, TxField::Nonce)?;
+        let gas_limit = required(self.gas_limit, TxField::GasLimit)?;
+        let to = required(self.to, TxField::To)?;
+        let envelope = match self.tx_type {
+            LegacyTransaction::TX_TYPE => TransactionEnvelope::Legacy(LegacyTransaction {
+                chain_id: match self.chain_id {
+                    Some(found) if found > MAX_EIP155_CHAIN_ID => {
+                        return Err(BuildError::ChainIdTooHigh { found })
+                    }
+                    chain_id => chain_id,
+                },
+                nonce,
+                gas_price: required(self.gas_price, TxField::GasPrice)?,
+                gas_limit,
+                to,
+                value: self.value,
+                data: self.data,
+                signature,
+            }),
+            Eip2930Transaction::TX_TYPE => TransactionEnvelope::Eip2930(Eip2930Transaction {
+                chain_id: required(self.chain_id, TxField::ChainId)?,
+                nonce,
+                gas_price: required(self.gas_price, TxField::GasPrice)?,
+                gas_limit,
+                to,
+                value: self.value,
+                data: self.data,
+                access_list,
+                signature,
+            }),
+            Eip1559Transaction::TX_TYPE => TransactionEnvelope::Eip1559(Eip1559Transaction {
+                chain_id: required(self.chain_id, TxField::ChainId)?,
+                nonce,
+                max_priority_fee_per_gas: required(
+                    self.max_priority_fee_per_gas,
+                    TxField::MaxPriorityFeePerGas,
+                )?,
+                max_fee_per_gas: required(self.max_fee_per_gas, TxField::MaxFeePerGas)?,
+                gas_limit,
+                to,
+                value: self.value,
+                data: self.data,
+                access_list,
+                signature,
+            }),
+            Eip4844Transaction::TX_TYPE => {
+                let blob_versioned_hashes =
+                    required(self.blob_versioned_hashes, TxField::BlobVersionedHashes)?;
+                if blob_versioned_hashes.is_empty() {
+                    return Err(BuildError::NoBlobVersionedHashes);
+                }
+                for (index, hash) in blob_versioned_hashes.iter().enumerate() {
+                    VersionedHash::new(*hash)
+                        .map_err(|reason| BuildError::InvalidVersionedHash { index, reason })?;
+                }
+                TransactionEnvelope::Eip4844(Eip4844Transaction {
+                    chain_id: required(self.chain_id, TxField::ChainId)?,
+                    nonce,
+                    max_priority_fee_per_gas: required(
+                        self.max_priority_fee_per_gas,
+                        TxField::MaxPriorityFeePerGas,
+                    )?,
+                    max_fee_per_gas: required(self.max_fee_per_gas, TxField::MaxFeePerGas)?,
+                    gas_limit,
+                    to: call_only(to)?,
+                    value: self.value,
+                    data: self.data,
+                    access_list,
+                    max_fee_per_blob_gas: required(
+                        self.max_fee_per_blob_gas,
+                        TxField::MaxFeePerBlobGas,
+                    )?,
+                    blob_versioned_hashes,
+                    signature,
+                })
+            }
+            _ => {
+                let authorization_list =
+                    required(self.authorization_list, TxField::AuthorizationList)?;
+                if authorization_list.is_empty() {
+                    return Err(BuildError::EmptyAuthorizationList);
+                }
+                if let Some((index, authorization)) = authorization_list
+                    .iter()
+                    .enumerate()
+                    .find(|(_, authorization)| authorization.y_parity > 1)
+                {
+                    return Err(BuildError::InvalidAuthorizationYParity {
+                        index,
+                        value: authorization.y_parity,
+                    });
+                }
+                TransactionEnvelope::Eip7702(Eip7702Transaction {
+                    chain_id: required(self.chain_id, TxField::ChainId)?,
+                    nonce,
+                    max_priority_fee_per_gas: required(
+                        self.max_priority_fee_per_gas,
+                        TxField::MaxPriorityFeePerGas,
+                    )?,
+                    max_fee_per_gas: required(self.max_fee_per_gas, TxField::MaxFeePerGas)?,
+                    gas_limit,
+                    to: call_only(to)?,
+                    value: self.value,
+                    data: self.data,
+                    access_list,
+                    authorization_list,
+                    signature,
+                })
+            }
+        };
+        Ok(envelope)
+    }
+
+    /// Reject fields set, but not used by the transaction type.
+    fn check_unused_fields(&self) -> Result<(), BuildError> {
+        let has_gas_price = matches!(
+            self.tx_type,
+            LegacyTransaction::TX_TYPE | Eip2930Transaction::TX_TYPE
+        );
+        let fields = [
+            (TxField::GasPrice, self.gas_price.is_some(), has_gas_price),
+            (
+                TxField::MaxPriorityFeePerGas,
+                self.max_priority_fee_per_gas.is_some(),
+                !has_gas_price,
+            ),
+            (
+                TxField::MaxFeePerGas,
+                self.max_fee_per_gas.is_some(),
+                !has_gas_price,
+            ),
+            (
+                TxField::AccessList,
+                self.access_list.is_some(),
+                self.tx_type != LegacyTransaction::TX_TYPE,
+            ),
+            (
+                TxField::MaxFeePerBlobGas,
+                self.max_fee_per_blob_gas.is_some(),
+                self.tx_type == Eip4844Transaction::TX_TYPE,
+            ),
+            (
+                TxField::BlobVersionedHashes,
+                self.blob_versioned_hashes.is_some(),
+                self.tx_type == Eip4844Transaction::TX_TYPE,
+            ),
+            (
+                TxField::AuthorizationList,
+                self.authorization_list.is_some(),
+                self.tx_type == Eip7702Transaction::TX_TYPE,
+            ),
+        ];
+        match fields
+            .iter()
+            .find(|(_, is_set, is_used)| *is_set && !is_used)
+        {
+            Some((field, _, _)) => Err(BuildError::UnusedField(*field)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn required<T>(value: Option<T>, field: TxField) -> Result<T, BuildError> {
+    value.ok_or(BuildError::MissingField(field))
+}
+
+/// Blob and set code transactions cannot create contracts.
+fn call_only(to: TxKind) -> Result<TxKind, BuildError> {
+    if to.is_create() {
+        Err(BuildError::CreateNotAllowed)
+    } else {
+        Ok(to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::transaction::RlpEcdsaEncodableTx;
+    use alloy_consensus::{
+        SignableTransaction, TxEip1559, TxEip2930, TxEip4844, TxEip7702, TxLegacy,
+    };
+    use alloy_eips::eip2930::{
+        AccessList as AlloyAccessList, AccessListItem as AlloyAccessListItem,
+    };
+    use alloy_eips::eip7702::{Authorization as AlloyAuthorization, SignedAuthorization};
+    use alloy_primitives::{
+        Address, Bytes, Signature as AlloySignature, TxKind as AlloyTxKind, B256, U256,
+    };
+    use std::vec;
+
+    use super::*;
+    use crate::transaction::{AccessListItem, VersionedHashError};
+
+    fn alloy_signature() -> AlloySignature {
+        AlloySignature::new(U256::from(0xabcdu64), U256::from(0x1234u64), true)
+    }
+
+    fn signature() -> Signature {
+        Signature {
+            r: U256::from(0xabcdu64).to_be_bytes(),
+            s: U256::from(0x1234u64).to_be_bytes(),
+            y_parity: 1,
+        }
+    }
+
+    fn scalar(value: u64) -> [u8; 32] {
+        U256::from(value).to_be_bytes()
+    }
+
+    /// Unsigned and signed encodings must match alloy.
+    fn check<T>(builder: TxBuilder, tx: T)
+    where
+        T: SignableTransaction<AlloySignature> + RlpEcdsaEncodableTx + Clone,
+    {
+        let unsigned = builder.clone().build().unwrap();
+        assert_eq!(unsigned.payload, tx.encoded_for_signing());
+
+        let mut raw = Vec::new();
+        tx.into_signed(alloy_signature()).eip2718_encode(&mut raw);
+        assert_eq!(builder.build_signed(signature()).unwrap().encode(), raw);
+        assert_eq!(
+            unsigned.attach_signature(signature()).unwrap().encode(),
+            raw
+        );
+    }
+
+    #[test]
+    fn legacy() {
+        let builder = TxBuilder::legacy()
+            .nonce(1)
+            .gas_price(scalar(20_000_000_000))
+            .gas_limit(21_000)
+            .to(TxKind::Call([0x01; 20]))
+            .value(scalar(1_000_000));
+        let tx = TxLegacy {
+            chain_id: None,
+            nonce: 1,
+            gas_price: 20_000_000_000,
+            gas_limit: 21_000,
+            to: AlloyTxKind::Call(Address::repeat_byte(0x01)),
+            value: U256::from(1_000_000u64),
+            input: Bytes::new(),
+        };
+        check(builder.clone(), tx.clone());
+        check(
+            builder.chain_id(1),
+            TxLegacy {
+                chain_id: Some(1),
+                ..tx
+            },
+        );
+    }
+
+    #[test]
+    fn eip2930() {
+        check(
+            TxBuilder::eip2930()
+                .chain_id(1)
+                .nonce(2)
+                .gas_price(scalar(10))
+                .gas_limit(60_000)
+                .to(TxKind::Create)
+                .data(vec![0x60, 0x00])
+                .access_list(AccessList(vec![AccessListItem {
+                    address: [0x22; 20],
+                    storage_keys: vec![[0x01; 32]],
+                }])),
+            TxEip2930 {
+                chain_id: 1,
+                nonce: 2,
+                gas_price: 10,
+                gas_limit: 60_000,
+                to: AlloyTxKind::Create,
+                value: U256::ZERO,
+                access_list: AlloyAccessList(vec![AlloyAccessListItem {
+                    address: Address::repeat_byte(0x22),
+                    storage_keys: vec![B256::repeat_byte(0x01)],
+                }]),
+                input: Bytes::from(vec![0x60, 0x00]),
+            },
+        );
+    }
+
+    #[test]
+    fn eip1559() {
+        check(
+            TxBuilder::eip1559()
+                .chain_id(10)
+                .nonce(3)
+                .max_priority_fee_per_gas(scalar(2))
+                .max_fee_per_gas(scalar(100))
+                .gas_limit(40_000)
+                .to(TxKind::Call([0x03; 20]))
+                .value(scalar(7)),
+            TxEip1559 {
+                chain_id: 10,
+                nonce: 3,
+                gas_limit: 40_000,
+                max_fee_per_gas: 100,
+                max_priority_fee_per_gas: 2,
+                to: AlloyTxKind::Call(Address::repeat_byte(0x03)),
+                value: U256::from(7u64),
+                access_list: AlloyAccessList::default(),
+                input: Bytes::new(),
+            },
+        );
+    }
+
+    #[test]
+    fn eip4844() {
+        check(
+            TxBuilder::eip4844()
+                .chain_id(1)
+                .nonce(4)
+                .max_priority_fee_per_gas(scalar(2))
+                .max_fee_per_gas(scalar(100))
+                .gas_limit(50_000)
+                .to(TxKind::Call([0x04; 20]))
+                .max_fee_per_blob_gas(scalar(1))
+                .blob_versioned_hashes(vec![[0x01; 32], *B256::right_padding_from(&[0x01, 0x02])]),
+            TxEip4844 {
+                chain_id: 1,
+                nonce: 4,
+                gas_limit: 50_000,
+                max_fee_per_gas: 100,
+                max_priority_fee_per_gas: 2,
+                to: Address::repeat_byte(0x04),
+                value: U256::ZERO,
+                access_list: AlloyAccessList::default(),
+                blob_versioned_hashes: vec![
+                    B256::repeat_byte(0x01),
+                    B256::right_padding_from(&[0x01, 0x02]),
+                ],
+                max_fee_per_blob_gas: 1,
+                input: Bytes::new(),
+            },
+        );
+    }
+
+    #[test]
+    fn eip7702() {
+        check(
+            TxBuilder::eip7702()
+                .chain_id(1)
+                .nonce(5)
+                .max_priority_fee_per_gas(scalar(2))
+                .max_fee_per_gas(scalar(100))
+                .gas_limit(60_000)
+                .to(TxKind::Call([0x05; 20]))
+                .authorization_list(vec![Authorization {
+                    chain_id: scalar(1),
+                    address: [0xaa; 20],
+                    nonce: 0,
+                    y_parity: 0,
+                    r: scalar(1),
+                    s: scalar(1),
+                }]),
+            TxEip7702 {
+                chain_id: 1,
+                nonce: 5,
+                gas_limit: 60_000,
+                max_fee_per_gas: 100,
+                max_priority_fee_per_gas: 2,
+                to: Address::repeat_byte(0x05),
+                value: U256::ZERO,
+                access_list: AlloyAccessList::default(),
+                authorization_list: vec![SignedAuthorization::new_unchecked(
+                    AlloyAuthorization {
+                        chain_id: U256::from(1u64),
+                        address: Address::repeat_byte(0xaa),
+                        nonce: 0,
+                    },
+                    0,
+                    U256::from(1u64),
+                    U256::from(1u64),
+                )],
+                input: Bytes::new(),
+            },
+        );
+    }
+
+    /// Builders of every type, set fields varied.
+    fn builders() -> Vec<TxBuilder> {
+        let access_list = AccessList(vec![AccessListItem {
+            address: [0x22; 20],
+            storage_keys: vec![[0x01; 32], [0x02; 32]],
+        }]);
+        let authorization = |y_parity| Authorization {
+            chain_id: scalar(1),
+            address: [0xaa; 20],
+            nonce: 7,
+            y_parity,
+            r: scalar(1),
+            s: scalar(2),
+        };
+        let mut builders = Vec::new();
+        for to in [TxKind::Call([0x01; 20]), TxKind::Create] {
+            for data in [Vec::new(), vec![0x60, 0x00]] {
+                let legacy = TxBuilder::legacy()
+                    .nonce(0)
+                    .gas_price(scalar(1))
+                    .gas_limit(21_000)
+                    .to(to)
+                    .data(data.clone());
+                builders.push(legacy.clone());
+                builders.push(legacy.clone().chain_id(1));
+                builders.push(legacy.value(scalar(u64::MAX)).chain_id(MAX_EIP155_CHAIN_ID));
+                builders.push(
+                    TxBuilder::eip2930()
+                        .chain_id(5)
+                        .nonce(u64::MAX)
+                        .gas_price([0xff; 32])
+                        .gas_limit(0)
+                        .to(to)
+                        .data(data.clone())
+                        .access_list(access_list.clone()),
+                );
+                builders.push(
+                    TxBuilder::eip1559()
+                        .chain_id(10)
+                        .nonce(3)
+                        .max_priority_fee_per_gas(scalar(0))
+                        .max_fee_per_gas(scalar(100))
+                        .gas_limit(40_000)
+                        .to(to)
+                        .data(data.clone()),
+                );
+            }
+        }
+        builders.push(
+            TxBuilder::eip4844()
+                .chain_id(1)
+                .nonce(4)
+                .max_priority_fee_per_gas(scalar(2))
+                .max_fee_per_gas(scalar(100))
+                .gas_limit(50_000)
+                .to(TxKind::Call([0x04; 20]))
+                .access_list(access_list.clone())
+                .max_fee_per_blob_gas(scalar(1))
+                .blob_versioned_hashes(vec![[0x01; 32]; 6]),
+        );
+        builders.push(
+            TxBuilder::eip7702()
+                .chain_id(1)
+                .nonce(5)
+                .max_priority_fee_per_gas(scalar(2))
+                .max_fee_per_gas(scalar(100))
+                .gas_limit(60_000)
+                .to(TxKind::Call([0x05; 20]))
+                .authorization_list(vec![authorization(0), authorization(1)]),
+        );
+        builders
+    }
+
+    #[test]
+    fn round_trip() {
+        for builder in builders() {
+            let envelope = builder.clone().build_signed(signature()).unwrap();
+            let raw = envelope.encode();
+            assert_eq!(
+                TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+                Ok(envelope.clone()),
+                "{builder:?}"
+            );
+            assert_eq!(
+                builder
+                    .clone()
+                    .build()
+                    .unwrap()
+                    .attach_signature(signature()),
+                Ok(envelope),
+                "{builder:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let eip1559 = TxBuilder::eip1559()
+            .chain_id(1)
+            .nonce(0)
+            .max_priority_fee_per_gas(scalar(1))
+            .max_fee_per_gas(scalar(2))
+            .gas_limit(21_000)
+            .to(TxKind::Call([0x01; 20]));
+        assert_eq!(
+            eip1559.clone().gas_price(scalar(1)).build(),
+            Err(BuildError::UnusedField(TxField::GasPrice))
+        );
+        assert_eq!(
+            TxBuilder::eip1559().nonce(0).build(),
+            Err(BuildError::MissingField(TxField::GasLimit))
+        );
+        assert_eq!(
+            TxBuilder::legacy()
+                .nonce(0)
+                .gas_limit(21_000)
+                .to(TxKind::Create)
+                .access_list(AccessList::default())
+                .build(),
+            Err(BuildError::UnusedField(TxField::AccessList))
+        );
+        let legacy = TxBuilder::legacy()
+            .nonce(0)
+            .gas_price(scalar(1))
+            .gas_limit(21_000)
+            .to(TxKind::Create);
+        assert!(legacy.clone().chain_id(MAX_EIP155_CHAIN_ID).build().is_ok());
+        assert_eq!(
+            legacy.chain_id(MAX_EIP155_CHAIN_ID + 1).build(),
+            Err(BuildError::ChainIdTooHigh {
+                found: MAX_EIP155_CHAIN_ID + 1
+            })
+        );
+        assert_eq!(
+            eip1559.clone().build_signed(Signature {
+                y_parity: 2,
+                ..signature()
+            }),
+            Err(BuildError::InvalidSignature(
+                SignatureError::InvalidYParity(2)
+            ))
+        );
+
+        let eip4844 = TxBuilder::eip4844()
+            .chain_id(1)
+            .nonce(0)
+            .max_priority_fee_per_gas(scalar(1))
+            .max_fee_per_gas(scalar(2))
+            .max_fee_per_blob_gas(scalar(1))
+            .gas_limit(21_000);
+        assert_eq!(
+            eip4844
+                .clone()
+                .to(TxKind::Call([0x01; 20]))
+                .blob_versioned_hashes(Vec::new())
+                .build(),
+            Err(BuildError::NoBlobVersionedHashes)
+        );
+        assert_eq!(
+            eip4844
+                .clone()
+                .to(TxKind::Create)
+                .blob_versioned_hashes(vec![[0x01; 32]])
+                .build(),
+            Err(BuildError::CreateNotAllowed)
+        );
+        assert_eq!(
+            eip4844
+                .to(TxKind::Call([0x01; 20]))
+                .blob_versioned_hashes(vec![[0x01; 32], [0x02; 32]])
+                .build(),
+            Err(BuildError::InvalidVersionedHash {
+                index: 1,
+                reason: VersionedHashError::UnsupportedVersion(0x02)
+            })
+        );
+
+        let authorization = Authorization {
+            chain_id: scalar(1),
+            address: [0xaa; 20],
+            nonce: 0,
+            y_parity: 2,
+            r: scalar(1),
+            s: scalar(1),
+        };
+        assert_eq!(
+            TxBuilder::eip7702()
+                .chain_id(1)
+                .nonce(0)
+                .max_priority_fee_per_gas(scalar(1))
+                .max_fee_per_gas(scalar(2))
+                .gas_limit(60_000)
+                .to(TxKind::Call([0x01; 20]))
+                .authorization_list(vec![authorization])
+                .build(),
+            Err(BuildError::InvalidAuthorizationYParity { index: 0, value: 2 })
+        );
+    }
+}
@@ -0,0 +1,211 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::transaction::{FeeBumpError, Signature, TransactionEnvelope, TxError};
+use crate::uint::mul_div_ceil;
+
+/// Transaction without a valid signature, produced by editing a signed one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnsignedTransaction {
+    /// Bytes to sign, see [`TransactionEnvelope::into_unsigned_bytes`].
+    pub payload: Vec<u8>,
+    pub chain_id: Option<u64>,
+}
+
+impl UnsignedTransaction {
+    /// Signed transaction, see [`TransactionEnvelope::attach_signature`].
+    pub fn attach_signature(
+        &self,
+        signature: Signature,
+    ) -> Result<TransactionEnvelope, TxError<()>> {
+        TransactionEnvelope::attach_signature(
+            &self.payload.as_ref(),
+            &mut (),
+            signature,
+            self.chain_id,
+        )
+    }
+}
+
+impl TransactionEnvelope {
+    /// Same transaction with all fees multiplied by `multiplier_num /
+    /// multiplier_den`, for replacing a pending transaction.
+    ///
+    /// Bumped are `gas_price` of legacy and EIP-2930 transactions, and both
+    /// `max_fee_per_gas` and `max_priority_fee_per_gas` of later types, with
+    /// `max_fee_per_blob_gas` for blob transactions. Results are rounded up,
+    /// so `11 / 10` always meets the 10% replacement rule, and saturate at
+    /// `2^256 - 1`.
+    pub fn with_bumped_fees(
+        &self,
+        multiplier_num: u64,
+        multiplier_den: u64,
+    ) -> Result<UnsignedTransaction, FeeBumpError> {
+        if multiplier_den == 0 {
+            return Err(FeeBumpError::ZeroDenominator);
+        }
+        if multiplier_num < multiplier_den {
+            return Err(FeeBumpError::MultiplierBelowOne);
+        }
+        let bump = |fee: &mut [u8; 32]| *fee = mul_div_ceil(fee, multiplier_num, multiplier_den);
+        let mut transaction = self.clone();
+        match &mut transaction {
+            Self::Legacy(tx) => bump(&mut tx.gas_price),
+            Self::Eip2930(tx) => bump(&mut tx.gas_price),
+            Self::Eip1559(tx) => {
+                bump(&mut tx.max_fee_per_gas);
+                bump(&mut tx.max_priority_fee_per_gas);
+            }
+            Self::Eip4844(tx) => {
+                bump(&mut tx.max_fee_per_gas);
+                bump(&mut tx.max_priority_fee_per_gas);
+                bump(&mut tx.max_fee_per_blob_gas);
+            }
+            Self::Eip7702(tx) => {
+                bump(&mut tx.max_fee_per_gas);
+                bump(&mut tx.max_priority_fee_per_gas);
+            }
+        }
+        Ok(UnsignedTransaction {
+            payload: transaction.into_unsigned_bytes(),
+            chain_id: transaction.chain_id(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::transaction::RlpEcdsaEncodableTx;
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxEip4844, TxLegacy};
+    use alloy_eips::eip2930::AccessList;
+    use alloy_primitives::{Address, Bytes, Signature as AlloySignature, TxKind, B256, U256};
+    use std::vec;
+
+    use super::*;
+
+    fn parse<T>(tx: T) -> TransactionEnvelope
+    where
+        T: SignableTransaction<AlloySignature> + RlpEcdsaEncodableTx,
+    {
+        let signature = AlloySignature::new(U256::from(0xabcdu64), U256::from(0x1234u64), true);
+        let mut raw = Vec::new();
+        tx.into_signed(signature).eip2718_encode(&mut raw);
+        TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+    }
+
+    fn scalar(value: u64) -> [u8; 32] {
+        U256::from(value).to_be_bytes::<32>()
+    }
+
+    /// Bump by 10% and sign again with the old signature.
+    fn bump(envelope: &TransactionEnvelope) -> TransactionEnvelope {
+        envelope
+            .with_bumped_fees(11, 10)
+            .unwrap()
+            .attach_signature(*envelope.signature())
+            .unwrap()
+    }
+
+    #[test]
+    fn legacy() {
+        let envelope = parse(TxLegacy {
+            chain_id: Some(1),
+            nonce: 1,
+            gas_price: 20_000_000_001,
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::repeat_byte(0x01)),
+            value: U256::from(100u64),
+            input: Bytes::new(),
+        });
+        match bump(&envelope) {
+            TransactionEnvelope::Legacy(tx) => {
+                assert_eq!(tx.gas_price, scalar(22_000_000_002));
+                assert_eq!(tx.chain_id, Some(1));
+            }
+            _ => panic!("expected legacy transaction"),
+        }
+    }
+
+    #[test]
+    fn eip1559() {
+        let envelope = parse(TxEip1559 {
+            chain_id: 1,
+            nonce: 3,
+            gas_limit: 40_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 2,
+            to: TxKind::Call(Address::repeat_byte(0x03)),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            input: Bytes::new(),
+        });
+        let bumped = bump(&envelope);
+        match &bumped {
+            TransactionEnvelope::Eip1559(tx) => {
+                assert_eq!(tx.max_fee_per_gas, scalar(110));
+                assert_eq!(tx.max_priority_fee_per_gas, scalar(3));
+            }
+            _ => panic!("expected EIP-1559 transaction"),
+        }
+        assert_eq!(bumped.nonce(), envelope.nonce());
+        assert_eq!(bumped.to(), envelope.to());
+    }
+
+    #[test]
+    fn eip4844() {
+        let envelope = parse(TxEip4844 {
+            chain_id: 1,
+            nonce: 4,
+            gas_limit: 50_000,
+            max_fee_per_gas: 1_000,
+            max_priority_fee_per_gas: 0,
+            to: Address::repeat_byte(0x04),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            blob_versioned_hashes: vec![B256::repeat_byte(0x01)],
+            max_fee_per_blob_gas: 1,
+            input: Bytes::new(),
+        });
+        match bump(&envelope) {
+            TransactionEnvelope::Eip4844(tx) => {
+                assert_eq!(tx.max_fee_per_gas, scalar(1_100));
+                assert_eq!(tx.max_priority_fee_per_gas, scalar(0));
+                assert_eq!(tx.max_fee_per_blob_gas, scalar(2));
+            }
+            _ => panic!("expected EIP-4844 transaction"),
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(mul_div_ceil(&scalar(7), 3, 2), scalar(11));
+        assert_eq!(mul_div_ceil(&scalar(10), 11, 10), scalar(11));
+        assert_eq!(mul_div_ceil(&scalar(0), 11, 10), scalar(0));
+        assert_eq!(mul_div_ceil(&[0xff; 32], 1, 1), [0xff; 32]);
+        assert_eq!(mul_div_ceil(&[0xff; 32], 11, 10), [0xff; 32]);
+
+        let mut high = [0; 32];
+        high[0] = 0x80;
+        assert_eq!(mul_div_ceil(&high, 2, 1), [0xff; 32]);
+        let mut expected = [0xcc; 32];
+        expected[0] = 0x8c;
+        expected[31] = 0xcd;
+        assert_eq!(mul_div_ceil(&high, 11, 10), expected);
+    }
+
+    #[test]
+    fn invalid_multiplier() {
+        let envelope = parse(TxLegacy::default());
+        assert_eq!(
+            envelope.with_bumped_fees(11, 0),
+            Err(FeeBumpError::ZeroDenominator)
+        );
+        assert_eq!(
+            envelope.with_bumped_fees(9, 10),
+            Err(FeeBumpError::MultiplierBelowOne)
+        );
+    }
+}
@@ -0,0 +1,418 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::encode::{leaf, scalar};
+use crate::transaction::access_list::AccessList;
+use crate::transaction::{
+    decode_payload, decode_typed, encode_typed, encode_typed_signature, fields, invalid,
+    parse_kind, typed_signature, Signature, TxConfig, TxError, TxField, TxKind,
+};
+use crate::ParsedData;
+
+/// EIP-1559 transaction (type `0x02`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eip1559Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: [u8; 32],
+    pub max_fee_per_gas: [u8; 32],
+    pub gas_limit: u64,
+    pub to: TxKind,
+    pub value: [u8; 32],
+    pub data: Vec<u8>,
+    pub access_list: AccessList,
+    pub signature: Signature,
+}
+
+impl Eip1559Transaction {
+    pub const TX_TYPE: u8 = 0x02;
+    const FIELD_COUNT: usize = 12;
+
+    /// Parse full typed transaction `0x02 || rlp([...])`.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse full typed transaction `0x02 || rlp([...])` with given options.
+    pub fn parse_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        config.limits.check(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_typed(data, ext_memory, Self::TX_TYPE)?, config)
+    }
+
+    /// Parse the inner RLP list only, without the type byte.
+    pub fn parse_payload<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_payload_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse the inner RLP list only, without the type byte, with given
+    /// options.
+    pub fn parse_payload_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        config
+            .limits
+            .check_payload(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_payload(data, ext_memory)?, config)
+    }
+
+    /// Bytes hashed for signing: `0x02 || rlp([...])` without the signature
+    /// fields.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        encode_typed(Self::TX_TYPE, self.unsigned_fields())
+    }
+
+    /// Canonical encoding of the signed transaction, `0x02 || rlp([...])`.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut fields = self.unsigned_fields();
+        fields.extend(encode_typed_signature(&self.signature));
+        encode_typed(Self::TX_TYPE, fields)
+    }
+
+    pub(crate) fn from_parsed<E: ExternalMemory>(
+        parsed_data: &ParsedData,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>> {
+        let fields = fields(parsed_data, Self::FIELD_COUNT)?;
+        Ok(Self {
+            chain_id: config
+                .scalar_u64(&fields[0])
+                .map_err(invalid(TxField::ChainId))?,
+            nonce: config
+                .scalar_u64(&fields[1])
+                .map_err(invalid(TxField::Nonce))?,
+            max_priority_fee_per_gas: config
+                .scalar_u256(&fields[2])
+                .map_err(invalid(TxField::MaxPriorityFeePerGas))?,
+            max_fee_per_gas: config
+                .scalar_u256(&fields[3])
+                .map_err(invalid(TxField::MaxFeePerGas))?,
+            gas_limit: config
+                .scalar_u64(&fields[4])
+                .map_err(invalid(TxField::GasLimit))?,
+            to: parse_kind(Self::TX_TYPE, &fields[5])?,
+            value: config
+                .scalar_u256(&fields[6])
+                .map_err(invalid(TxField::Value))?,
+            data: fields[7].to_bytes_vec().map_err(invalid(TxField::Data))?,
+            access_list: AccessList::parse_from(&fields[8]).map_err(TxError::InvalidAccessList)?,
+            signature: typed_signature(config, &fields[9..])?,
+        })
+    }
+
+    /// Fields covered by the signature.
+    fn unsigned_fields(&self) -> Vec<ParsedData> {
+        Vec::from([
+            scalar(&self.chain_id.to_be_bytes()),
+            scalar(&self.nonce.to_be_bytes()),
+            scalar(&self.max_priority_fee_per_gas),
+            scalar(&self.max_fee_per_gas),
+            scalar(&self.gas_limit.to_be_bytes()),
+            self.to.to_parsed(),
+            scalar(&self.value),
+            leaf(self.data.clone()),
+            self.access_list.to_parsed(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxEip2930};
+    use alloy_eips::eip2930::{AccessList, AccessListItem as AlloyAccessListItem};
+    use alloy_primitives::{Address, Bytes, Signature, TxKind, B256, U256};
+    use alloy_rlp::{Encodable, RlpEncodable};
+    use std::vec;
+
+    use super::*;
+    use crate::field::FieldError;
+
+    fn signature() -> Signature {
+        Signature::new(
+            U256::from_be_bytes([0x7f; 32]),
+            U256::from_be_bytes([0x3c; 32]),
+            false,
+        )
+    }
+
+    fn encode(tx: TxEip1559) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        tx.into_signed(signature()).eip2718_encode(&mut buffer);
+        buffer
+    }
+
+    fn assert_matches_alloy(tx: TxEip1559) {
+        let raw = encode(tx.clone());
+        let parsed = Eip1559Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+
+        assert_eq!(parsed.chain_id, tx.chain_id);
+        assert_eq!(parsed.nonce, tx.nonce);
+        assert_eq!(
+            parsed.max_priority_fee_per_gas,
+            U256::from(tx.max_priority_fee_per_gas).to_be_bytes::<32>()
+        );
+        assert_eq!(
+            parsed.max_fee_per_gas,
+            U256::from(tx.max_fee_per_gas).to_be_bytes::<32>()
+        );
+        assert_eq!(parsed.gas_limit, tx.gas_limit);
+        assert_eq!(parsed.to.to(), tx.to.to().map(|a| a.into_array()));
+        assert_eq!(parsed.value, tx.value.to_be_bytes::<32>());
+        assert_eq!(parsed.data, tx.input.to_vec());
+        assert_eq!(parsed.access_list.len(), tx.access_list.len());
+        for (parsed_item, item) in parsed.access_list.iter().zip(tx.access_list.iter()) {
+            assert_eq!(parsed_item.address, item.address.into_array());
+            assert_eq!(
+                parsed_item.storage_keys,
+                item.storage_keys
+                    .iter()
+                    .map(|a| a.0)
+                    .collect::<Vec<[u8; 32]>>()
+            );
+        }
+        assert_eq!(parsed.signature.y_parity, 0);
+        assert_eq!(parsed.signature.r, [0x7f; 32]);
+        assert_eq!(parsed.signature.s, [0x3c; 32]);
+    }
+
+    #[test]
+    fn eth_transfer() {
+        assert_matches_alloy(TxEip1559 {
+            chain_id: 1,
+            nonce: 1337,
+            gas_limit: 21_000,
+            max_fee_per_gas: 40_000_000_000,
+            max_priority_fee_per_gas: 1_500_000_000,
+            to: TxKind::Call(Address::repeat_byte(0xd8)),
+            value: U256::from(250_000_000_000_000_000u128),
+            access_list: AccessList::default(),
+            input: Bytes::new(),
+        });
+    }
+
+    #[test]
+    fn contract_deployment() {
+        assert_matches_alloy(TxEip1559 {
+            chain_id: 11_155_111,
+            nonce: 0,
+            gas_limit: 1_200_000,
+            max_fee_per_gas: 2_000_000_000,
+            max_priority_fee_per_gas: 100_000_000,
+            to: TxKind::Create,
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            input: Bytes::from(vec![0x60, 0x80, 0x60, 0x40, 0x52, 0x34, 0x80, 0x15]),
+        });
+    }
+
+    #[test]
+    fn large_calldata_call() {
+        let input: Vec<u8> = (0..40_000u32).map(|a| (a % 251) as u8).collect();
+        assert_matches_alloy(TxEip1559 {
+            chain_id: 1,
+            nonce: 7,
+            gas_limit: 3_000_000,
+            max_fee_per_gas: 25_000_000_000,
+            max_priority_fee_per_gas: 2_000_000_000,
+            to: TxKind::Call(Address::repeat_byte(0x7a)),
+            value: U256::from(1u64),
+            access_list: AccessList(vec![AlloyAccessListItem {
+                address: Address::repeat_byte(0x7a),
+                storage_keys: vec![B256::repeat_byte(0x05)],
+            }]),
+            input: Bytes::from(input),
+        });
+    }
+
+    #[test]
+    fn eip2930_body_has_wrong_arity() {
+        let tx = TxEip2930 {
+            chain_id: 1,
+            nonce: 1,
+            gas_price: 1,
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::repeat_byte(0x01)),
+            value: U256::ZERO,
+            input: Bytes::new(),
+            access_list: AccessList::default(),
+        };
+        let mut raw = Vec::new();
+        tx.into_signed(signature()).eip2718_encode(&mut raw);
+
+        let error =
+            Eip1559Transaction::parse_payload::<&[u8], ()>(&&raw[1..], &mut ()).unwrap_err();
+        assert_eq!(
+            error,
+            TxError::WrongFieldCount {
+                expected: 12,
+                found: 11
+            }
+        );
+    }
+
+    #[derive(RlpEncodable)]
+    struct RawEip1559 {
+        chain_id: u64,
+        nonce: Bytes,
+        max_priority_fee_per_gas: u64,
+        max_fee_per_gas: u64,
+        gas_limit: u64,
+        to: Bytes,
+        value: Bytes,
+        data: Bytes,
+        access_list: AccessList,
+        y_parity: Bytes,
+        r: U256,
+        s: U256,
+    }
+
+    fn raw_with(nonce: &[u8], value: &[u8]) -> Vec<u8> {
+        raw_with_y_parity(nonce, value, &[0x01])
+    }
+
+    fn raw_with_y_parity(nonce: &[u8], value: &[u8], y_parity: &[u8]) -> Vec<u8> {
+        let tx = RawEip1559 {
+            chain_id: 1,
+            nonce: Bytes::copy_from_slice(nonce),
+            max_priority_fee_per_gas: 1,
+            max_fee_per_gas: 2,
+            gas_limit: 21_000,
+            to: Bytes::from(vec![0x44; 20]),
+            value: Bytes::copy_from_slice(value),
+            data: Bytes::new(),
+            access_list: AccessList::default(),
+            y_parity: Bytes::copy_from_slice(y_parity),
+            r: U256::from(1),
+            s: U256::from(1),
+        };
+        let mut raw = vec![0x02];
+        tx.encode(&mut raw);
+        raw
+    }
+
+    #[test]
+    fn canonical_integers() {
+        let raw = raw_with(&[], &[0x03, 0xe8]);
+        let parsed = Eip1559Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(parsed.nonce, 0);
+        assert_eq!(parsed.value[30..], [0x03, 0xe8]);
+    }
+
+    #[test]
+    fn lenient_scalars() {
+        let raw = raw_with(&[0x00, 0x07], &[0x03, 0xe8]);
+        let error = Eip1559Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(
+            error,
+            TxError::InvalidField {
+                field: TxField::Nonce,
+                reason: FieldError::LeadingZero
+            }
+        );
+
+        let lenient = TxConfig {
+            strict_scalars: false,
+            ..Default::default()
+        };
+        let parsed =
+            Eip1559Transaction::parse_with_config::<&[u8], ()>(&raw.as_ref(), &mut (), &lenient)
+                .unwrap();
+        assert_eq!(parsed.nonce, 7);
+        assert_eq!(parsed.value[30..], [0x03, 0xe8]);
+
+        let raw = raw_with(&[0x07], &[0x03, 0xe8]);
+        let canonical = Eip1559Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(parsed, canonical);
+    }
+
+    #[test]
+    fn zero_byte_nonce_rejected() {
+        let raw = raw_with(&[0x00], &[]);
+        let error = Eip1559Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(
+            error,
+            TxError::InvalidField {
+                field: TxField::Nonce,
+                reason: FieldError::LeadingZero
+            }
+        );
+    }
+
+    #[test]
+    fn zero_padded_value_rejected() {
+        let raw = raw_with(&[0x05], &[0x00, 0x01]);
+        let error = Eip1559Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(
+            error,
+            TxError::InvalidField {
+                field: TxField::Value,
+                reason: FieldError::LeadingZero
+            }
+        );
+    }
+
+    #[test]
+    fn y_parity() {
+        let parse = |y_parity: &[u8], config: &TxConfig| {
+            let raw = raw_with_y_parity(&[0x05], &[], y_parity);
+            Eip1559Transaction::parse_with_config::<&[u8], ()>(&raw.as_ref(), &mut (), config)
+                .map(|tx| tx.signature.y_parity)
+        };
+        let strict = TxConfig::default();
+        let lenient = TxConfig {
+            strict_scalars: false,
+            ..Default::default()
+        };
+        assert_eq!(parse(&[], &strict), Ok(0));
+        assert_eq!(parse(&[0x01], &strict), Ok(1));
+        for value in [2, 27] {
+            assert_eq!(
+                parse(&[value], &strict),
+                Err(TxError::InvalidYParity {
+                    value: value as u64
+                })
+            );
+        }
+        for encoding in [&[0x00][..], &[0x00, 0x01]] {
+            assert_eq!(
+                parse(encoding, &strict),
+                Err(TxError::InvalidField {
+                    field: TxField::YParity,
+                    reason: FieldError::LeadingZero
+                })
+            );
+        }
+        assert_eq!(parse(&[0x00], &lenient), Ok(0));
+        assert_eq!(parse(&[0x00, 0x01], &lenient), Ok(1));
+        assert_eq!(
+            parse(&[0x00, 0x1b], &lenient),
+            Err(TxError::InvalidYParity { value: 27 })
+        );
+    }
+}
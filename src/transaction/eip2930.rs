@@ -0,0 +1,311 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::encode::{leaf, scalar};
+use crate::transaction::access_list::AccessList;
+use crate::transaction::{
+    decode_payload, decode_typed, encode_typed, encode_typed_signature, fields, invalid,
+    parse_kind, typed_signature, Signature, TxConfig, TxError, TxField, TxKind,
+};
+use crate::ParsedData;
+
+/// EIP-2930 transaction (type `0x01`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eip2930Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_price: [u8; 32],
+    pub gas_limit: u64,
+    pub to: TxKind,
+    pub value: [u8; 32],
+    pub data: Vec<u8>,
+    pub access_list: AccessList,
+    pub signature: Signature,
+}
+
+impl Eip2930Transaction {
+    pub const TX_TYPE: u8 = 0x01;
+    const FIELD_COUNT: usize = 11;
+
+    /// Parse full typed transaction `0x01 || rlp([...])`.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse full typed transaction `0x01 || rlp([...])` with given options.
+    pub fn parse_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        config.limits.check(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_typed(data, ext_memory, Self::TX_TYPE)?, config)
+    }
+
+    /// Parse the inner RLP list only, without the type byte.
+    pub fn parse_payload<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_payload_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse the inner RLP list only, without the type byte, with given
+    /// options.
+    pub fn parse_payload_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        config
+            .limits
+            .check_payload(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_payload(data, ext_memory)?, config)
+    }
+
+    /// Bytes hashed for signing: `0x01 || rlp([...])` without the signature
+    /// fields.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        encode_typed(Self::TX_TYPE, self.unsigned_fields())
+    }
+
+    /// Canonical encoding of the signed transaction, `0x01 || rlp([...])`.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut fields = self.unsigned_fields();
+        fields.extend(encode_typed_signature(&self.signature));
+        encode_typed(Self::TX_TYPE, fields)
+    }
+
+    pub(crate) fn from_parsed<E: ExternalMemory>(
+        parsed_data: &ParsedData,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>> {
+        let fields = fields(parsed_data, Self::FIELD_COUNT)?;
+        Ok(Self {
+            chain_id: config
+                .scalar_u64(&fields[0])
+                .map_err(invalid(TxField::ChainId))?,
+            nonce: config
+                .scalar_u64(&fields[1])
+                .map_err(invalid(TxField::Nonce))?,
+            gas_price: config
+                .scalar_u256(&fields[2])
+                .map_err(invalid(TxField::GasPrice))?,
+            gas_limit: config
+                .scalar_u64(&fields[3])
+                .map_err(invalid(TxField::GasLimit))?,
+            to: parse_kind(Self::TX_TYPE, &fields[4])?,
+            value: config
+                .scalar_u256(&fields[5])
+                .map_err(invalid(TxField::Value))?,
+            data: fields[6].to_bytes_vec().map_err(invalid(TxField::Data))?,
+            access_list: AccessList::parse_from(&fields[7]).map_err(TxError::InvalidAccessList)?,
+            signature: typed_signature(config, &fields[8..])?,
+        })
+    }
+
+    /// Fields covered by the signature.
+    fn unsigned_fields(&self) -> Vec<ParsedData> {
+        Vec::from([
+            scalar(&self.chain_id.to_be_bytes()),
+            scalar(&self.nonce.to_be_bytes()),
+            scalar(&self.gas_price),
+            scalar(&self.gas_limit.to_be_bytes()),
+            self.to.to_parsed(),
+            scalar(&self.value),
+            leaf(self.data.clone()),
+            self.access_list.to_parsed(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{SignableTransaction, TxEip2930};
+    use alloy_eips::eip2930::{
+        AccessList as AlloyAccessList, AccessListItem as AlloyAccessListItem,
+    };
+    use alloy_primitives::{
+        Address, Bytes, Signature as AlloySignature, TxKind as AlloyTxKind, B256, U256,
+    };
+    use alloy_rlp::{Encodable, RlpEncodable};
+    use std::vec;
+
+    use super::*;
+    use crate::field::FieldError;
+    use crate::transaction::{AccessListError, AccessListItem};
+
+    fn signed_fixture() -> Vec<u8> {
+        let tx = TxEip2930 {
+            chain_id: 1,
+            nonce: 42,
+            gas_price: 30_000_000_000,
+            gas_limit: 120_000,
+            to: AlloyTxKind::Call(Address::repeat_byte(0x11)),
+            value: U256::from(1_000_000_000_000_000_000u128),
+            input: Bytes::from(vec![0xa9, 0x05, 0x9c, 0xbb, 0x00, 0x01]),
+            access_list: AlloyAccessList(vec![
+                AlloyAccessListItem {
+                    address: Address::repeat_byte(0x22),
+                    storage_keys: vec![B256::repeat_byte(0x01), B256::repeat_byte(0x02)],
+                },
+                AlloyAccessListItem {
+                    address: Address::repeat_byte(0x33),
+                    storage_keys: vec![],
+                },
+            ]),
+        };
+        let signature = AlloySignature::new(
+            U256::from_be_bytes([0x5a; 32]),
+            U256::from(0x1234_5678u64),
+            true,
+        );
+        let mut buffer = Vec::new();
+        tx.into_signed(signature).eip2718_encode(&mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn parse_signed() {
+        let raw = signed_fixture();
+        let tx = Eip2930Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+
+        let mut value = [0; 32];
+        value[24..].copy_from_slice(&1_000_000_000_000_000_000u64.to_be_bytes());
+        let mut gas_price = [0; 32];
+        gas_price[24..].copy_from_slice(&30_000_000_000u64.to_be_bytes());
+        let mut s = [0; 32];
+        s[28..].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+
+        assert_eq!(
+            tx,
+            Eip2930Transaction {
+                chain_id: 1,
+                nonce: 42,
+                gas_price,
+                gas_limit: 120_000,
+                to: TxKind::Call([0x11; 20]),
+                value,
+                data: vec![0xa9, 0x05, 0x9c, 0xbb, 0x00, 0x01],
+                access_list: AccessList(vec![
+                    AccessListItem {
+                        address: [0x22; 20],
+                        storage_keys: vec![[0x01; 32], [0x02; 32]],
+                    },
+                    AccessListItem {
+                        address: [0x33; 20],
+                        storage_keys: vec![],
+                    },
+                ]),
+                signature: Signature {
+                    r: [0x5a; 32],
+                    s,
+                    y_parity: 1,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn parse_payload_without_type_byte() {
+        let raw = signed_fixture();
+        let from_envelope = Eip2930Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        let from_payload =
+            Eip2930Transaction::parse_payload::<&[u8], ()>(&&raw[1..], &mut ()).unwrap();
+        assert_eq!(from_envelope, from_payload);
+    }
+
+    #[test]
+    fn wrong_type_byte() {
+        let mut raw = signed_fixture();
+        raw[0] = 0x02;
+        let error = Eip2930Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(
+            error,
+            TxError::UnexpectedType {
+                expected: 0x01,
+                found: 0x02
+            }
+        );
+    }
+
+    #[test]
+    fn short_storage_key() {
+        #[derive(RlpEncodable)]
+        struct MalformedEntry {
+            address: [u8; 20],
+            storage_keys: Vec<[u8; 31]>,
+        }
+
+        #[derive(RlpEncodable)]
+        struct MalformedTx {
+            chain_id: u64,
+            nonce: u64,
+            gas_price: u64,
+            gas_limit: u64,
+            to: [u8; 20],
+            value: u64,
+            data: Bytes,
+            access_list: Vec<MalformedEntry>,
+            y_parity: u8,
+            r: U256,
+            s: U256,
+        }
+
+        let tx = MalformedTx {
+            chain_id: 1,
+            nonce: 0,
+            gas_price: 1,
+            gas_limit: 21000,
+            to: [0x11; 20],
+            value: 0,
+            data: Bytes::new(),
+            access_list: vec![
+                MalformedEntry {
+                    address: [0x22; 20],
+                    storage_keys: vec![],
+                },
+                MalformedEntry {
+                    address: [0x33; 20],
+                    storage_keys: vec![[0x01; 31], [0x02; 31]],
+                },
+            ],
+            y_parity: 0,
+            r: U256::from(1),
+            s: U256::from(1),
+        };
+        let mut raw = vec![0x01];
+        tx.encode(&mut raw);
+
+        let error = Eip2930Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(
+            error,
+            TxError::InvalidAccessList(AccessListError::InvalidStorageKey {
+                entry: 1,
+                key: 0,
+                reason: FieldError::WrongLength {
+                    expected: 32,
+                    found: 31
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,349 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::encode::{leaf, scalar};
+use crate::transaction::access_list::AccessList;
+use crate::transaction::pooled::{is_blob_wrapper, is_wrapper_payload};
+use crate::transaction::{
+    decode_payload, decode_typed, encode_typed, encode_typed_signature, fields, invalid,
+    parse_kind, typed_signature, Signature, TxConfig, TxError, TxField, TxKind, VersionedHash,
+};
+use crate::ParsedData;
+
+/// EIP-4844 blob transaction (type `0x03`), in its canonical form without
+/// the network sidecar.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eip4844Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: [u8; 32],
+    pub max_fee_per_gas: [u8; 32],
+    pub gas_limit: u64,
+    pub to: TxKind,
+    pub value: [u8; 32],
+    pub data: Vec<u8>,
+    pub access_list: AccessList,
+    pub max_fee_per_blob_gas: [u8; 32],
+    pub blob_versioned_hashes: Vec<[u8; 32]>,
+    pub signature: Signature,
+}
+
+impl Eip4844Transaction {
+    pub const TX_TYPE: u8 = 0x03;
+    const FIELD_COUNT: usize = 14;
+
+    /// Parse full typed transaction `0x03 || rlp([...])`.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse full typed transaction `0x03 || rlp([...])` with given options.
+    pub fn parse_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        if is_blob_wrapper(data, ext_memory, 0) {
+            return Err(TxError::WrapperNotAllowedHere);
+        }
+        config.limits.check(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_typed(data, ext_memory, Self::TX_TYPE)?, config)
+    }
+
+    /// Parse the inner RLP list only, without the type byte.
+    pub fn parse_payload<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_payload_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse the inner RLP list only, without the type byte, with given
+    /// options.
+    pub fn parse_payload_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        if is_wrapper_payload(data, ext_memory, 0) {
+            return Err(TxError::WrapperNotAllowedHere);
+        }
+        config
+            .limits
+            .check_payload(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_payload(data, ext_memory)?, config)
+    }
+
+    /// Bytes hashed for signing: `0x03 || rlp([...])` without the signature
+    /// fields.
+    ///
+    /// Blob transactions sign over the canonical body only; the network
+    /// sidecar of [`PooledBlobTransaction`](crate::transaction::PooledBlobTransaction)
+    /// is not covered by the signature.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        encode_typed(Self::TX_TYPE, self.unsigned_fields())
+    }
+
+    /// Canonical encoding of the signed transaction, `0x03 || rlp([...])`.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut fields = self.unsigned_fields();
+        fields.extend(encode_typed_signature(&self.signature));
+        encode_typed(Self::TX_TYPE, fields)
+    }
+
+    pub(crate) fn from_parsed<E: ExternalMemory>(
+        parsed_data: &ParsedData,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>> {
+        let fields = fields(parsed_data, Self::FIELD_COUNT)?;
+        Ok(Self {
+            chain_id: config
+                .scalar_u64(&fields[0])
+                .map_err(invalid(TxField::ChainId))?,
+            nonce: config
+                .scalar_u64(&fields[1])
+                .map_err(invalid(TxField::Nonce))?,
+            max_priority_fee_per_gas: config
+                .scalar_u256(&fields[2])
+                .map_err(invalid(TxField::MaxPriorityFeePerGas))?,
+            max_fee_per_gas: config
+                .scalar_u256(&fields[3])
+                .map_err(invalid(TxField::MaxFeePerGas))?,
+            gas_limit: config
+                .scalar_u64(&fields[4])
+                .map_err(invalid(TxField::GasLimit))?,
+            to: parse_kind(Self::TX_TYPE, &fields[5])?,
+            value: config
+                .scalar_u256(&fields[6])
+                .map_err(invalid(TxField::Value))?,
+            data: fields[7].to_bytes_vec().map_err(invalid(TxField::Data))?,
+            access_list: AccessList::parse_from(&fields[8]).map_err(TxError::InvalidAccessList)?,
+            max_fee_per_blob_gas: config
+                .scalar_u256(&fields[9])
+                .map_err(invalid(TxField::MaxFeePerBlobGas))?,
+            blob_versioned_hashes: parse_versioned_hashes(&fields[10])?,
+            signature: typed_signature(config, &fields[11..])?,
+        })
+    }
+
+    /// Fields covered by the signature.
+    fn unsigned_fields(&self) -> Vec<ParsedData> {
+        Vec::from([
+            scalar(&self.chain_id.to_be_bytes()),
+            scalar(&self.nonce.to_be_bytes()),
+            scalar(&self.max_priority_fee_per_gas),
+            scalar(&self.max_fee_per_gas),
+            scalar(&self.gas_limit.to_be_bytes()),
+            self.to.to_parsed(),
+            scalar(&self.value),
+            leaf(self.data.clone()),
+            self.access_list.to_parsed(),
+            scalar(&self.max_fee_per_blob_gas),
+            ParsedData::List(
+                self.blob_versioned_hashes
+                    .iter()
+                    .map(|hash| ParsedData::String(hash.to_vec()))
+                    .collect(),
+            ),
+        ])
+    }
+}
+
+fn parse_versioned_hashes<E: ExternalMemory>(
+    parsed_data: &ParsedData,
+) -> Result<Vec<[u8; 32]>, TxError<E>> {
+    let hashes = parsed_data
+        .as_list()
+        .map_err(invalid(TxField::BlobVersionedHashes))?;
+    if hashes.is_empty() {
+        return Err(TxError::NoBlobVersionedHashes);
+    }
+    hashes
+        .iter()
+        .enumerate()
+        .map(|(index, hash)| {
+            VersionedHash::from_parsed(hash)
+                .map(|hash| hash.0)
+                .map_err(|reason| TxError::InvalidVersionedHash { index, reason })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{SignableTransaction, TxEip4844};
+    use alloy_eips::eip2930::AccessList;
+    use alloy_primitives::{Address, Bytes, Signature, B256, U256};
+    use alloy_rlp::{Encodable, RlpEncodable};
+    use std::vec;
+
+    use super::*;
+    use crate::decode_whole_blob;
+    use crate::field::FieldError;
+    use crate::transaction::VersionedHashError;
+
+    fn alloy_fixture(blob_versioned_hashes: Vec<B256>) -> TxEip4844 {
+        TxEip4844 {
+            chain_id: 1,
+            nonce: 101,
+            gas_limit: 50_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            to: Address::repeat_byte(0xbb),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            blob_versioned_hashes,
+            max_fee_per_blob_gas: 3_000_000_000,
+            input: Bytes::from(vec![0xde, 0xad]),
+        }
+    }
+
+    fn encode(tx: TxEip4844) -> Vec<u8> {
+        let signature = Signature::new(U256::from(0xaau64), U256::from(0x55u64), true);
+        let mut buffer = Vec::new();
+        tx.into_signed(signature).eip2718_encode(&mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn two_blobs() {
+        let hashes = vec![
+            B256::repeat_byte(0x01),
+            B256::right_padding_from(&[0x01, 0x02]),
+        ];
+        let tx = alloy_fixture(hashes.clone());
+        let raw = encode(tx.clone());
+        let parsed = Eip4844Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+
+        assert_eq!(parsed.chain_id, tx.chain_id);
+        assert_eq!(parsed.nonce, tx.nonce);
+        assert_eq!(
+            parsed.max_priority_fee_per_gas,
+            U256::from(tx.max_priority_fee_per_gas).to_be_bytes::<32>()
+        );
+        assert_eq!(
+            parsed.max_fee_per_gas,
+            U256::from(tx.max_fee_per_gas).to_be_bytes::<32>()
+        );
+        assert_eq!(parsed.gas_limit, tx.gas_limit);
+        assert_eq!(parsed.to, TxKind::Call(tx.to.into_array()));
+        assert_eq!(parsed.value, [0; 32]);
+        assert_eq!(parsed.data, tx.input.to_vec());
+        assert!(parsed.access_list.is_empty());
+        assert_eq!(
+            parsed.max_fee_per_blob_gas,
+            U256::from(tx.max_fee_per_blob_gas).to_be_bytes::<32>()
+        );
+        assert_eq!(
+            parsed.blob_versioned_hashes,
+            hashes.iter().map(|a| a.0).collect::<Vec<_>>()
+        );
+        assert_eq!(parsed.signature.y_parity, 1);
+        assert_eq!(parsed.signature.r, U256::from(0xaau64).to_be_bytes::<32>());
+        assert_eq!(parsed.signature.s, U256::from(0x55u64).to_be_bytes::<32>());
+    }
+
+    #[test]
+    fn empty_hash_list() {
+        let raw = encode(alloy_fixture(Vec::new()));
+        let error = Eip4844Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(error, TxError::NoBlobVersionedHashes);
+    }
+
+    #[test]
+    fn versioned_hashes() {
+        let raw = encode(alloy_fixture(vec![
+            B256::repeat_byte(0x01),
+            B256::repeat_byte(0x02),
+        ]));
+        let error = Eip4844Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(
+            error,
+            TxError::InvalidVersionedHash {
+                index: 1,
+                reason: VersionedHashError::UnsupportedVersion(0x02)
+            }
+        );
+
+        let raw = encode(alloy_fixture(vec![B256::repeat_byte(0x01)]));
+        let mut parsed = decode_whole_blob::<&[u8], ()>(&&raw[1..], &mut ()).unwrap();
+        let ParsedData::List(fields) = &mut parsed else {
+            panic!("expected list");
+        };
+        fields[10] = ParsedData::List(vec![ParsedData::String(vec![0x01; 31])]);
+        assert_eq!(
+            Eip4844Transaction::from_parsed::<()>(&parsed, &TxConfig::default()),
+            Err(TxError::InvalidVersionedHash {
+                index: 0,
+                reason: VersionedHashError::InvalidField(FieldError::WrongLength {
+                    expected: 32,
+                    found: 31
+                })
+            })
+        );
+    }
+
+    #[test]
+    fn contract_creation_rejected() {
+        // `TxEip4844` cannot express an empty `to`, so the fields are
+        // re-encoded with the destination replaced.
+        #[derive(RlpEncodable)]
+        struct CreatingEip4844 {
+            chain_id: u64,
+            nonce: u64,
+            max_priority_fee_per_gas: u128,
+            max_fee_per_gas: u128,
+            gas_limit: u64,
+            to: Bytes,
+            value: U256,
+            input: Bytes,
+            access_list: AccessList,
+            max_fee_per_blob_gas: u128,
+            blob_versioned_hashes: Vec<B256>,
+            y_parity: bool,
+            r: U256,
+            s: U256,
+        }
+
+        let tx = alloy_fixture(vec![B256::repeat_byte(0x01)]);
+        let creating = CreatingEip4844 {
+            chain_id: tx.chain_id,
+            nonce: tx.nonce,
+            max_priority_fee_per_gas: tx.max_priority_fee_per_gas,
+            max_fee_per_gas: tx.max_fee_per_gas,
+            gas_limit: tx.gas_limit,
+            to: Bytes::new(),
+            value: tx.value,
+            input: tx.input,
+            access_list: tx.access_list,
+            max_fee_per_blob_gas: tx.max_fee_per_blob_gas,
+            blob_versioned_hashes: tx.blob_versioned_hashes,
+            y_parity: true,
+            r: U256::from(0xaau64),
+            s: U256::from(0x55u64),
+        };
+        let mut raw = vec![0x03];
+        creating.encode(&mut raw);
+
+        let error = Eip4844Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(error, TxError::CreateNotAllowed);
+    }
+}
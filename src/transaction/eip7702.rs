@@ -0,0 +1,655 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::encode::{leaf, scalar};
+use crate::field::FieldError;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::{
+    decode_payload, decode_typed, encode_typed, encode_typed_signature, fields, invalid,
+    parse_kind, typed_signature, AuthError, AuthorizationField, Signature, TxConfig, TxError,
+    TxField, TxKind,
+};
+use crate::ParsedData;
+
+/// EIP-7702 set code transaction (type `0x04`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eip7702Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: [u8; 32],
+    pub max_fee_per_gas: [u8; 32],
+    pub gas_limit: u64,
+    pub to: TxKind,
+    pub value: [u8; 32],
+    pub data: Vec<u8>,
+    pub access_list: AccessList,
+    pub authorization_list: Vec<Authorization>,
+    pub signature: Signature,
+}
+
+/// Signed authorization to set the code of the signer account.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Authorization {
+    pub chain_id: [u8; 32],
+    pub address: [u8; 20],
+    pub nonce: u64,
+    pub y_parity: u8,
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+}
+
+impl Eip7702Transaction {
+    pub const TX_TYPE: u8 = 0x04;
+    const FIELD_COUNT: usize = 13;
+
+    /// Parse full typed transaction `0x04 || rlp([...])`.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse full typed transaction `0x04 || rlp([...])` with given options.
+    pub fn parse_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        config.limits.check(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_typed(data, ext_memory, Self::TX_TYPE)?, config)
+    }
+
+    /// Parse the inner RLP list only, without the type byte.
+    pub fn parse_payload<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_payload_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse the inner RLP list only, without the type byte, with given
+    /// options.
+    pub fn parse_payload_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        config
+            .limits
+            .check_payload(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_payload(data, ext_memory)?, config)
+    }
+
+    /// Bytes hashed for signing: `0x04 || rlp([...])` without the signature
+    /// fields, authorization list included.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        encode_typed(Self::TX_TYPE, self.unsigned_fields())
+    }
+
+    /// Canonical encoding of the signed transaction, `0x04 || rlp([...])`.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut fields = self.unsigned_fields();
+        fields.extend(encode_typed_signature(&self.signature));
+        encode_typed(Self::TX_TYPE, fields)
+    }
+
+    pub(crate) fn from_parsed<E: ExternalMemory>(
+        parsed_data: &ParsedData,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>> {
+        let fields = fields(parsed_data, Self::FIELD_COUNT)?;
+        Ok(Self {
+            chain_id: config
+                .scalar_u64(&fields[0])
+                .map_err(invalid(TxField::ChainId))?,
+            nonce: config
+                .scalar_u64(&fields[1])
+                .map_err(invalid(TxField::Nonce))?,
+            max_priority_fee_per_gas: config
+                .scalar_u256(&fields[2])
+                .map_err(invalid(TxField::MaxPriorityFeePerGas))?,
+            max_fee_per_gas: config
+                .scalar_u256(&fields[3])
+                .map_err(invalid(TxField::MaxFeePerGas))?,
+            gas_limit: config
+                .scalar_u64(&fields[4])
+                .map_err(invalid(TxField::GasLimit))?,
+            to: parse_kind(Self::TX_TYPE, &fields[5])?,
+            value: config
+                .scalar_u256(&fields[6])
+                .map_err(invalid(TxField::Value))?,
+            data: fields[7].to_bytes_vec().map_err(invalid(TxField::Data))?,
+            access_list: AccessList::parse_from(&fields[8]).map_err(TxError::InvalidAccessList)?,
+            authorization_list: parse_authorization_list(&fields[9], config)?,
+            signature: typed_signature(config, &fields[10..])?,
+        })
+    }
+
+    /// [`Authorization::validate`] for each authorization, expecting the
+    /// transaction chain id. Results are in authorization list order.
+    pub fn validate_authorizations(&self) -> Vec<Result<(), AuthError>> {
+        self.authorization_list
+            .iter()
+            .map(|authorization| authorization.validate(Some(self.chain_id)))
+            .collect()
+    }
+
+    /// Fields covered by the signature.
+    fn unsigned_fields(&self) -> Vec<ParsedData> {
+        Vec::from([
+            scalar(&self.chain_id.to_be_bytes()),
+            scalar(&self.nonce.to_be_bytes()),
+            scalar(&self.max_priority_fee_per_gas),
+            scalar(&self.max_fee_per_gas),
+            scalar(&self.gas_limit.to_be_bytes()),
+            self.to.to_parsed(),
+            scalar(&self.value),
+            leaf(self.data.clone()),
+            self.access_list.to_parsed(),
+            ParsedData::List(
+                self.authorization_list
+                    .iter()
+                    .map(Authorization::to_parsed)
+                    .collect(),
+            ),
+        ])
+    }
+}
+
+fn parse_authorization_list<E: ExternalMemory>(
+    parsed_data: &ParsedData,
+    config: &TxConfig,
+) -> Result<Vec<Authorization>, TxError<E>> {
+    let entries = parsed_data
+        .as_list()
+        .map_err(invalid(TxField::AuthorizationList))?;
+    if entries.is_empty() {
+        return Err(TxError::EmptyAuthorizationList);
+    }
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| Authorization::from_parsed(index, entry, config))
+        .collect()
+}
+
+impl Authorization {
+    /// Check the rules applied to authorizations before processing:
+    /// `chain_id` is `0` or `expected_chain_id` (any chain id passes if it is
+    /// `None`), nonce could be incremented, signature passes
+    /// [`Signature::validate`].
+    pub fn validate(&self, expected_chain_id: Option<u64>) -> Result<(), AuthError> {
+        if let Some(expected) = expected_chain_id {
+            let mut expected_bytes = [0; 32];
+            expected_bytes[24..].copy_from_slice(&expected.to_be_bytes());
+            if self.chain_id != [0; 32] && self.chain_id != expected_bytes {
+                return Err(AuthError::ChainIdMismatch {
+                    expected,
+                    found: self.chain_id,
+                });
+            }
+        }
+        if self.nonce == u64::MAX {
+            return Err(AuthError::NonceOverflow);
+        }
+        Signature::from_typed(self.y_parity as u64, &self.r, &self.s)
+            .and_then(|signature| signature.validate())
+            .map_err(AuthError::Signature)
+    }
+
+    fn from_parsed<E: ExternalMemory>(
+        index: usize,
+        parsed_data: &ParsedData,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>> {
+        let fields = match parsed_data {
+            ParsedData::List(fields) if fields.len() == 6 => fields,
+            _ => return Err(TxError::MalformedAuthorization { index }),
+        };
+        let invalid = |field: AuthorizationField| {
+            move |reason: FieldError| TxError::InvalidAuthorization {
+                index,
+                field,
+                reason,
+            }
+        };
+        let y_parity = config
+            .scalar_u64(&fields[3])
+            .map_err(invalid(AuthorizationField::YParity))?;
+        if y_parity > 1 {
+            return Err(TxError::InvalidAuthorizationYParity {
+                index,
+                value: y_parity,
+            });
+        }
+        Ok(Self {
+            chain_id: config
+                .scalar_u256(&fields[0])
+                .map_err(invalid(AuthorizationField::ChainId))?,
+            address: fields[1]
+                .as_fixed_bytes::<20>()
+                .map_err(invalid(AuthorizationField::Address))?,
+            nonce: config
+                .scalar_u64(&fields[2])
+                .map_err(invalid(AuthorizationField::Nonce))?,
+            y_parity: y_parity as u8,
+            r: config
+                .scalar_u256(&fields[4])
+                .map_err(invalid(AuthorizationField::R))?,
+            s: config
+                .scalar_u256(&fields[5])
+                .map_err(invalid(AuthorizationField::S))?,
+        })
+    }
+
+    fn to_parsed(&self) -> ParsedData {
+        ParsedData::List(Vec::from([
+            scalar(&self.chain_id),
+            ParsedData::String(self.address.to_vec()),
+            scalar(&self.nonce.to_be_bytes()),
+            scalar(&[self.y_parity]),
+            scalar(&self.r),
+            scalar(&self.s),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{SignableTransaction, TxEip7702};
+    use alloy_eips::eip2930::AccessList;
+    use alloy_eips::eip7702::{Authorization as AlloyAuthorization, SignedAuthorization};
+    use alloy_primitives::{Address, Bytes, Signature, U256};
+    use alloy_rlp::{Encodable, RlpEncodable};
+    use std::vec;
+
+    use super::*;
+    use crate::transaction::{SignatureError, TransactionEnvelope, TxBuilder};
+
+    fn alloy_fixture(authorization_list: Vec<SignedAuthorization>) -> TxEip7702 {
+        TxEip7702 {
+            chain_id: 1,
+            nonce: 12,
+            gas_limit: 150_000,
+            max_fee_per_gas: 20_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            to: Address::repeat_byte(0x70),
+            value: U256::from(5u64),
+            access_list: AccessList::default(),
+            authorization_list,
+            input: Bytes::from(vec![0x01, 0x02, 0x03]),
+        }
+    }
+
+    fn encode(tx: TxEip7702) -> Vec<u8> {
+        let signature = Signature::new(U256::from(0x0111u64), U256::from(0x0222u64), true);
+        let mut buffer = Vec::new();
+        tx.into_signed(signature).eip2718_encode(&mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn two_authorizations() {
+        let authorizations = vec![
+            SignedAuthorization::new_unchecked(
+                AlloyAuthorization {
+                    chain_id: U256::from(1u64),
+                    address: Address::repeat_byte(0xa1),
+                    nonce: 0,
+                },
+                0,
+                U256::from_be_bytes([0x11; 32]),
+                U256::from_be_bytes([0x22; 32]),
+            ),
+            SignedAuthorization::new_unchecked(
+                AlloyAuthorization {
+                    chain_id: U256::ZERO,
+                    address: Address::repeat_byte(0xa2),
+                    nonce: 77,
+                },
+                1,
+                U256::from(3u64),
+                U256::from(4u64),
+            ),
+        ];
+        let tx = alloy_fixture(authorizations.clone());
+        let raw = encode(tx.clone());
+        let parsed = Eip7702Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+
+        assert_eq!(parsed.chain_id, tx.chain_id);
+        assert_eq!(parsed.nonce, tx.nonce);
+        assert_eq!(parsed.gas_limit, tx.gas_limit);
+        assert_eq!(
+            parsed.max_fee_per_gas,
+            U256::from(tx.max_fee_per_gas).to_be_bytes::<32>()
+        );
+        assert_eq!(
+            parsed.max_priority_fee_per_gas,
+            U256::from(tx.max_priority_fee_per_gas).to_be_bytes::<32>()
+        );
+        assert_eq!(parsed.to, TxKind::Call(tx.to.into_array()));
+        assert_eq!(parsed.value, tx.value.to_be_bytes::<32>());
+        assert_eq!(parsed.data, tx.input.to_vec());
+        assert_eq!(parsed.signature.y_parity, 1);
+        assert_eq!(
+            parsed.signature.r,
+            U256::from(0x0111u64).to_be_bytes::<32>()
+        );
+        assert_eq!(
+            parsed.signature.s,
+            U256::from(0x0222u64).to_be_bytes::<32>()
+        );
+
+        assert_eq!(parsed.authorization_list.len(), 2);
+        for (parsed_auth, auth) in parsed.authorization_list.iter().zip(authorizations.iter()) {
+            assert_eq!(
+                parsed_auth,
+                &Authorization {
+                    chain_id: auth.chain_id.to_be_bytes::<32>(),
+                    address: auth.address.into_array(),
+                    nonce: auth.nonce,
+                    y_parity: auth.y_parity(),
+                    r: auth.r().to_be_bytes::<32>(),
+                    s: auth.s().to_be_bytes::<32>(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn empty_authorization_list() {
+        let raw = encode(alloy_fixture(Vec::new()));
+        let error = Eip7702Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(error, TxError::EmptyAuthorizationList);
+    }
+
+    #[test]
+    fn long_authorization_address() {
+        #[derive(RlpEncodable)]
+        struct RawAuthorization {
+            chain_id: u64,
+            address: Bytes,
+            nonce: u64,
+            y_parity: u8,
+            r: U256,
+            s: U256,
+        }
+
+        #[derive(RlpEncodable)]
+        struct RawEip7702 {
+            chain_id: u64,
+            nonce: u64,
+            max_priority_fee_per_gas: u64,
+            max_fee_per_gas: u64,
+            gas_limit: u64,
+            to: Address,
+            value: u64,
+            data: Bytes,
+            access_list: AccessList,
+            authorization_list: Vec<RawAuthorization>,
+            y_parity: u8,
+            r: U256,
+            s: U256,
+        }
+
+        let authorization = |address_len| RawAuthorization {
+            chain_id: 1,
+            address: Bytes::from(vec![0xa1; address_len]),
+            nonce: 0,
+            y_parity: 0,
+            r: U256::from(1u64),
+            s: U256::from(1u64),
+        };
+        let tx = RawEip7702 {
+            chain_id: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: 1,
+            max_fee_per_gas: 1,
+            gas_limit: 100_000,
+            to: Address::repeat_byte(0x70),
+            value: 0,
+            data: Bytes::new(),
+            access_list: AccessList::default(),
+            authorization_list: vec![authorization(20), authorization(21)],
+            y_parity: 0,
+            r: U256::from(1u64),
+            s: U256::from(1u64),
+        };
+        let mut raw = vec![0x04];
+        tx.encode(&mut raw);
+
+        let error = Eip7702Transaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap_err();
+        assert_eq!(
+            error,
+            TxError::InvalidAuthorization {
+                index: 1,
+                field: AuthorizationField::Address,
+                reason: FieldError::WrongLength {
+                    expected: 20,
+                    found: 21
+                }
+            }
+        );
+    }
+
+    fn authorization_list(entries: Vec<ParsedData>) -> ParsedData {
+        ParsedData::List(entries)
+    }
+
+    fn authorization_fields(r_len: usize) -> Vec<ParsedData> {
+        vec![
+            ParsedData::Byte(1),
+            ParsedData::String(vec![0xa1; 20]),
+            ParsedData::String(Vec::new()),
+            ParsedData::Byte(1),
+            ParsedData::String(vec![0x11; r_len]),
+            ParsedData::String(vec![0x22; 32]),
+        ]
+    }
+
+    #[test]
+    fn authorization_tuple_shape() {
+        let mut short = authorization_fields(32);
+        short.pop();
+        let parsed_data = authorization_list(vec![
+            ParsedData::List(authorization_fields(32)),
+            ParsedData::List(short),
+        ]);
+        assert_eq!(
+            parse_authorization_list::<()>(&parsed_data, &TxConfig::default()),
+            Err(TxError::MalformedAuthorization { index: 1 })
+        );
+
+        let parsed_data = authorization_list(vec![ParsedData::String(vec![0; 6])]);
+        assert_eq!(
+            parse_authorization_list::<()>(&parsed_data, &TxConfig::default()),
+            Err(TxError::MalformedAuthorization { index: 0 })
+        );
+    }
+
+    #[test]
+    fn authorization_field_widths() {
+        let parsed_data = authorization_list(vec![ParsedData::List(authorization_fields(33))]);
+        assert_eq!(
+            parse_authorization_list::<()>(&parsed_data, &TxConfig::default()),
+            Err(TxError::InvalidAuthorization {
+                index: 0,
+                field: AuthorizationField::R,
+                reason: FieldError::ScalarTooLong {
+                    max_length: 32,
+                    found: 33
+                }
+            })
+        );
+
+        let parsed_data = authorization_list(vec![ParsedData::List(authorization_fields(32))]);
+        let parsed = parse_authorization_list::<()>(&parsed_data, &TxConfig::default()).unwrap();
+        assert_eq!(parsed[0].nonce, 0);
+        assert_eq!(parsed[0].chain_id[31], 1);
+    }
+
+    #[test]
+    fn authorization_y_parity() {
+        let parse = |y_parity: ParsedData, config: &TxConfig| {
+            let mut fields = authorization_fields(32);
+            fields[3] = y_parity;
+            let parsed_data = authorization_list(vec![
+                ParsedData::List(authorization_fields(32)),
+                ParsedData::List(fields),
+            ]);
+            parse_authorization_list::<()>(&parsed_data, config).map(|list| list[1].y_parity)
+        };
+        let strict = TxConfig::default();
+        assert_eq!(parse(ParsedData::String(Vec::new()), &strict), Ok(0));
+        assert_eq!(parse(ParsedData::Byte(1), &strict), Ok(1));
+        for value in [2, 27] {
+            assert_eq!(
+                parse(ParsedData::Byte(value), &strict),
+                Err(TxError::InvalidAuthorizationYParity {
+                    index: 1,
+                    value: value as u64
+                })
+            );
+        }
+        assert_eq!(
+            parse(ParsedData::String(vec![0x00, 0x01]), &strict),
+            Err(TxError::InvalidAuthorization {
+                index: 1,
+                field: AuthorizationField::YParity,
+                reason: FieldError::LeadingZero
+            })
+        );
+        let lenient = TxConfig {
+            strict_scalars: false,
+            ..Default::default()
+        };
+        assert_eq!(parse(ParsedData::String(vec![0x00, 0x01]), &lenient), Ok(1));
+    }
+
+    fn valid_authorization() -> Authorization {
+        Authorization {
+            chain_id: [0; 32],
+            address: [0xa1; 20],
+            nonce: 5,
+            y_parity: 1,
+            r: [0x11; 32],
+            s: [0x22; 32],
+        }
+    }
+
+    #[test]
+    fn validate_chain_id() {
+        let mut authorization = valid_authorization();
+        // Zero chain id is valid on any chain.
+        assert_eq!(authorization.validate(Some(1)), Ok(()));
+        assert_eq!(authorization.validate(None), Ok(()));
+
+        authorization.chain_id[31] = 1;
+        assert_eq!(authorization.validate(Some(1)), Ok(()));
+        assert_eq!(authorization.validate(None), Ok(()));
+        assert_eq!(
+            authorization.validate(Some(5)),
+            Err(AuthError::ChainIdMismatch {
+                expected: 5,
+                found: authorization.chain_id
+            })
+        );
+
+        // Chain id beyond `u64` never matches.
+        authorization.chain_id[0] = 1;
+        assert!(authorization.validate(Some(1)).is_err());
+    }
+
+    #[test]
+    fn validate_nonce() {
+        let mut authorization = valid_authorization();
+        authorization.nonce = u64::MAX - 1;
+        assert_eq!(authorization.validate(None), Ok(()));
+        authorization.nonce = u64::MAX;
+        assert_eq!(authorization.validate(None), Err(AuthError::NonceOverflow));
+    }
+
+    #[test]
+    fn validate_signature() {
+        let check = |change: fn(&mut Authorization)| {
+            let mut authorization = valid_authorization();
+            change(&mut authorization);
+            authorization.validate(None)
+        };
+        assert_eq!(
+            check(|a| a.r = [0; 32]),
+            Err(AuthError::Signature(SignatureError::ZeroR))
+        );
+        assert_eq!(
+            check(|a| a.r = [0xff; 32]),
+            Err(AuthError::Signature(SignatureError::ROutOfRange))
+        );
+        // Upper half of the group order.
+        assert_eq!(
+            check(|a| a.s = [0x80; 32]),
+            Err(AuthError::Signature(SignatureError::HighS))
+        );
+        assert_eq!(
+            check(|a| a.y_parity = 2),
+            Err(AuthError::Signature(SignatureError::InvalidYParity(2)))
+        );
+    }
+
+    #[test]
+    fn validate_authorizations() {
+        let tx = TxBuilder::eip7702()
+            .chain_id(7)
+            .nonce(0)
+            .max_priority_fee_per_gas([0; 32])
+            .max_fee_per_gas([0; 32])
+            .gas_limit(100_000)
+            .to(TxKind::Call([0x11; 20]))
+            .authorization_list(vec![
+                valid_authorization(),
+                Authorization {
+                    chain_id: [0x01; 32],
+                    ..valid_authorization()
+                },
+                Authorization {
+                    nonce: u64::MAX,
+                    ..valid_authorization()
+                },
+            ])
+            .build_signed(crate::transaction::Signature {
+                r: [0x01; 32],
+                s: [0x01; 32],
+                y_parity: 0,
+            })
+            .unwrap();
+        let TransactionEnvelope::Eip7702(tx) = tx else {
+            panic!("expected set code transaction");
+        };
+        assert_eq!(
+            tx.validate_authorizations(),
+            vec![
+                Ok(()),
+                Err(AuthError::ChainIdMismatch {
+                    expected: 7,
+                    found: [0x01; 32]
+                }),
+                Err(AuthError::NonceOverflow),
+            ]
+        );
+    }
+}
@@ -0,0 +1,599 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::encode::scalar;
+use crate::transaction::{
+    decode_payload, decode_typed, encode_typed_signature, invalid, Eip1559Transaction,
+    Eip2930Transaction, Eip4844Transaction, Eip7702Transaction, LegacyTransaction, Signature,
+    TxConfig, TxError, TxField, TxKind,
+};
+use crate::{Error, ParsedData, BORDER_A, BORDER_C};
+
+/// Any supported transaction, as defined by EIP-2718.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TransactionEnvelope {
+    Legacy(LegacyTransaction),
+    Eip2930(Eip2930Transaction),
+    Eip1559(Eip1559Transaction),
+    Eip4844(Eip4844Transaction),
+    Eip7702(Eip7702Transaction),
+}
+
+impl TransactionEnvelope {
+    /// Parse transaction of any supported type.
+    ///
+    /// Input starting with a list prefix is a legacy transaction, otherwise
+    /// the first byte is the transaction type.
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    /// Parse transaction of any supported type with given options.
+    pub fn parse_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let first_byte = data
+            .read_byte(ext_memory, 0)
+            .map_err(|e| TxError::Decoding(Error::Buffer(e)))?;
+        match first_byte {
+            a if a >= BORDER_C => Ok(Self::Legacy(LegacyTransaction::parse_with_config(
+                data, ext_memory, config,
+            )?)),
+            Eip2930Transaction::TX_TYPE => Ok(Self::Eip2930(
+                Eip2930Transaction::parse_with_config(data, ext_memory, config)?,
+            )),
+            Eip1559Transaction::TX_TYPE => Ok(Self::Eip1559(
+                Eip1559Transaction::parse_with_config(data, ext_memory, config)?,
+            )),
+            Eip4844Transaction::TX_TYPE => Ok(Self::Eip4844(
+                Eip4844Transaction::parse_with_config(data, ext_memory, config)?,
+            )),
+            Eip7702Transaction::TX_TYPE => Ok(Self::Eip7702(
+                Eip7702Transaction::parse_with_config(data, ext_memory, config)?,
+            )),
+            a if a >= BORDER_A => Err(TxError::NotATransaction { first_byte: a }),
+            type_byte => Err(TxError::UnknownTransactionType { type_byte }),
+        }
+    }
+
+    /// EIP-2718 transaction type, `0` for legacy transactions.
+    pub fn tx_type(&self) -> u8 {
+        match self {
+            Self::Legacy(_) => 0,
+            Self::Eip2930(_) => Eip2930Transaction::TX_TYPE,
+            Self::Eip1559(_) => Eip1559Transaction::TX_TYPE,
+            Self::Eip4844(_) => Eip4844Transaction::TX_TYPE,
+            Self::Eip7702(_) => Eip7702Transaction::TX_TYPE,
+        }
+    }
+
+    pub fn nonce(&self) -> u64 {
+        match self {
+            Self::Legacy(tx) => tx.nonce,
+            Self::Eip2930(tx) => tx.nonce,
+            Self::Eip1559(tx) => tx.nonce,
+            Self::Eip4844(tx) => tx.nonce,
+            Self::Eip7702(tx) => tx.nonce,
+        }
+    }
+
+    /// Destination, [`TxKind::Create`] for contract creation.
+    pub fn to(&self) -> TxKind {
+        match self {
+            Self::Legacy(tx) => tx.to,
+            Self::Eip2930(tx) => tx.to,
+            Self::Eip1559(tx) => tx.to,
+            Self::Eip4844(tx) => tx.to,
+            Self::Eip7702(tx) => tx.to,
+        }
+    }
+
+    pub fn value(&self) -> [u8; 32] {
+        match self {
+            Self::Legacy(tx) => tx.value,
+            Self::Eip2930(tx) => tx.value,
+            Self::Eip1559(tx) => tx.value,
+            Self::Eip4844(tx) => tx.value,
+            Self::Eip7702(tx) => tx.value,
+        }
+    }
+
+    pub fn data(&self) -> &[u8] {
+        match self {
+            Self::Legacy(tx) => &tx.data,
+            Self::Eip2930(tx) => &tx.data,
+            Self::Eip1559(tx) => &tx.data,
+            Self::Eip4844(tx) => &tx.data,
+            Self::Eip7702(tx) => &tx.data,
+        }
+    }
+
+    /// Chain id; `None` only for pre-EIP-155 legacy transactions.
+    pub fn chain_id(&self) -> Option<u64> {
+        match self {
+            Self::Legacy(tx) => tx.chain_id,
+            Self::Eip2930(tx) => Some(tx.chain_id),
+            Self::Eip1559(tx) => Some(tx.chain_id),
+            Self::Eip4844(tx) => Some(tx.chain_id),
+            Self::Eip7702(tx) => Some(tx.chain_id),
+        }
+    }
+
+    pub fn signature(&self) -> &Signature {
+        match self {
+            Self::Legacy(tx) => &tx.signature,
+            Self::Eip2930(tx) => &tx.signature,
+            Self::Eip1559(tx) => &tx.signature,
+            Self::Eip4844(tx) => &tx.signature,
+            Self::Eip7702(tx) => &tx.signature,
+        }
+    }
+
+    /// Bytes hashed for signing, see `signing_payload` of each type.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        match self {
+            Self::Legacy(tx) => tx.signing_payload(tx.chain_id),
+            Self::Eip2930(tx) => tx.signing_payload(),
+            Self::Eip1559(tx) => tx.signing_payload(),
+            Self::Eip4844(tx) => tx.signing_payload(),
+            Self::Eip7702(tx) => tx.signing_payload(),
+        }
+    }
+
+    /// Canonical encoding of the signed transaction.
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            Self::Legacy(tx) => tx.encode(),
+            Self::Eip2930(tx) => tx.encode(),
+            Self::Eip1559(tx) => tx.encode(),
+            Self::Eip4844(tx) => tx.encode(),
+            Self::Eip7702(tx) => tx.encode(),
+        }
+    }
+
+    pub fn gas_limit(&self) -> u64 {
+        match self {
+            Self::Legacy(tx) => tx.gas_limit,
+            Self::Eip2930(tx) => tx.gas_limit,
+            Self::Eip1559(tx) => tx.gas_limit,
+            Self::Eip4844(tx) => tx.gas_limit,
+            Self::Eip7702(tx) => tx.gas_limit,
+        }
+    }
+
+    /// Canonical encoding without the signature, same as
+    /// [`signing_payload`](Self::signing_payload). Legacy EIP-155
+    /// transactions keep the `[chain_id, 0, 0]` suffix.
+    pub fn into_unsigned_bytes(&self) -> Vec<u8> {
+        self.signing_payload()
+    }
+
+    /// Inverse of [`into_unsigned_bytes`](Self::into_unsigned_bytes): parse
+    /// unsigned transaction and attach the signature.
+    ///
+    /// `chain_id` must match the chain id in the unsigned payload, `None`
+    /// only for pre-EIP-155 legacy transactions.
+    pub fn attach_signature<B, E>(
+        unsigned: &B,
+        ext_memory: &mut E,
+        signature: Signature,
+        chain_id: Option<u64>,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let config = TxConfig::default();
+        let first_byte = unsigned
+            .read_byte(ext_memory, 0)
+            .map_err(|e| TxError::Decoding(Error::Buffer(e)))?;
+        let envelope = match first_byte {
+            a if a >= BORDER_C => {
+                let mut fields = unsigned_fields(decode_payload(unsigned, ext_memory)?)?;
+                let found = match fields.len() {
+                    6 => None,
+                    9 => Some(eip155_chain_id(&fields.split_off(6), &config)?),
+                    found => return Err(TxError::WrongFieldCount { expected: 9, found }),
+                };
+                check_chain_id(chain_id, found)?;
+                let v = match found {
+                    Some(found) => 35 + 2 * found as u128 + signature.y_parity as u128,
+                    None => 27 + signature.y_parity as u128,
+                };
+                fields.extend([
+                    scalar(&v.to_be_bytes()),
+                    scalar(&signature.r),
+                    scalar(&signature.s),
+                ]);
+                return Ok(Self::Legacy(LegacyTransaction::from_parsed(
+                    &ParsedData::List(fields),
+                    &config,
+                )?));
+            }
+            Eip2930Transaction::TX_TYPE
+            | Eip1559Transaction::TX_TYPE
+            | Eip4844Transaction::TX_TYPE
+            | Eip7702Transaction::TX_TYPE => {
+                let mut fields = unsigned_fields(decode_typed(unsigned, ext_memory, first_byte)?)?;
+                fields.extend(encode_typed_signature(&signature));
+                Self::from_typed_parsed(first_byte, &ParsedData::List(fields), &config)?
+            }
+            a if a >= BORDER_A => return Err(TxError::NotATransaction { first_byte: a }),
+            type_byte => return Err(TxError::UnknownTransactionType { type_byte }),
+        };
+        check_chain_id(chain_id, envelope.chain_id())?;
+        Ok(envelope)
+    }
+
+    /// Typed transaction from its decoded RLP payload.
+    pub(crate) fn from_typed_parsed<E: ExternalMemory>(
+        tx_type: u8,
+        parsed_data: &ParsedData,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>> {
+        match tx_type {
+            Eip2930Transaction::TX_TYPE => Ok(Self::Eip2930(Eip2930Transaction::from_parsed(
+                parsed_data,
+                config,
+            )?)),
+            Eip1559Transaction::TX_TYPE => Ok(Self::Eip1559(Eip1559Transaction::from_parsed(
+                parsed_data,
+                config,
+            )?)),
+            Eip4844Transaction::TX_TYPE => Ok(Self::Eip4844(Eip4844Transaction::from_parsed(
+                parsed_data,
+                config,
+            )?)),
+            Eip7702Transaction::TX_TYPE => Ok(Self::Eip7702(Eip7702Transaction::from_parsed(
+                parsed_data,
+                config,
+            )?)),
+            type_byte => Err(TxError::UnknownTransactionType { type_byte }),
+        }
+    }
+}
+
+fn unsigned_fields<E: ExternalMemory>(
+    parsed_data: ParsedData,
+) -> Result<Vec<ParsedData>, TxError<E>> {
+    match parsed_data {
+        ParsedData::List(fields) => Ok(fields),
+        _ => Err(TxError::NotAList),
+    }
+}
+
+/// Chain id from the `[chain_id, 0, 0]` suffix of unsigned EIP-155
+/// transaction.
+fn eip155_chain_id<E: ExternalMemory>(
+    suffix: &[ParsedData],
+    config: &TxConfig,
+) -> Result<u64, TxError<E>> {
+    if !suffix[1].is_empty_string() || !suffix[2].is_empty_string() {
+        return Err(TxError::InvalidEip155Suffix);
+    }
+    config
+        .scalar_u64(&suffix[0])
+        .map_err(invalid(TxField::ChainId))
+}
+
+fn check_chain_id<E: ExternalMemory>(
+    expected: Option<u64>,
+    found: Option<u64>,
+) -> Result<(), TxError<E>> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(TxError::ChainIdMismatch { expected, found })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::transaction::RlpEcdsaEncodableTx;
+    use alloy_consensus::{
+        SignableTransaction, TxEip1559, TxEip2930, TxEip4844, TxEip7702, TxLegacy,
+    };
+    use alloy_eips::eip2930::{AccessList, AccessListItem};
+    use alloy_eips::eip7702::{Authorization, SignedAuthorization};
+    use alloy_primitives::{keccak256, Address, Bytes, Signature, TxKind, B256, U256};
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+    use std::vec;
+
+    use super::*;
+
+    fn signature() -> Signature {
+        Signature::new(U256::from(0xabcdu64), U256::from(0x1234u64), true)
+    }
+
+    fn encode<T>(tx: T) -> Vec<u8>
+    where
+        T: SignableTransaction<Signature> + RlpEcdsaEncodableTx,
+    {
+        let mut buffer = Vec::new();
+        tx.into_signed(signature()).eip2718_encode(&mut buffer);
+        buffer
+    }
+
+    fn parse(raw: &[u8]) -> TransactionEnvelope {
+        TransactionEnvelope::parse::<&[u8], ()>(&raw, &mut ()).unwrap()
+    }
+
+    fn value(amount: u64) -> [u8; 32] {
+        U256::from(amount).to_be_bytes::<32>()
+    }
+
+    fn legacy_tx() -> TxLegacy {
+        TxLegacy {
+            chain_id: Some(5),
+            nonce: 1,
+            gas_price: 10,
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::repeat_byte(0x01)),
+            value: U256::from(100u64),
+            input: Bytes::new(),
+        }
+    }
+
+    fn eip2930_tx() -> TxEip2930 {
+        TxEip2930 {
+            chain_id: 1,
+            nonce: 2,
+            gas_price: 10,
+            gas_limit: 30_000,
+            to: TxKind::Create,
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            input: Bytes::from(vec![0x60, 0x00]),
+        }
+    }
+
+    fn eip1559_tx() -> TxEip1559 {
+        TxEip1559 {
+            chain_id: 10,
+            nonce: 3,
+            gas_limit: 40_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 2,
+            to: TxKind::Call(Address::repeat_byte(0x03)),
+            value: U256::from(7u64),
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::repeat_byte(0x33),
+                storage_keys: vec![B256::ZERO, B256::repeat_byte(0x01)],
+            }]),
+            input: Bytes::new(),
+        }
+    }
+
+    fn eip4844_tx() -> TxEip4844 {
+        TxEip4844 {
+            chain_id: 1,
+            nonce: 4,
+            gas_limit: 50_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 2,
+            to: Address::repeat_byte(0x04),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            blob_versioned_hashes: vec![B256::repeat_byte(0x01)],
+            max_fee_per_blob_gas: 1,
+            input: Bytes::new(),
+        }
+    }
+
+    fn eip7702_tx() -> TxEip7702 {
+        let authorization = SignedAuthorization::new_unchecked(
+            Authorization {
+                chain_id: U256::from(1u64),
+                address: Address::repeat_byte(0xaa),
+                nonce: 0,
+            },
+            0,
+            U256::from(1u64),
+            U256::from(1u64),
+        );
+        TxEip7702 {
+            chain_id: 1,
+            nonce: 5,
+            gas_limit: 60_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 2,
+            to: Address::repeat_byte(0x05),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            authorization_list: vec![authorization],
+            input: Bytes::new(),
+        }
+    }
+
+    #[test]
+    fn legacy() {
+        let envelope = parse(&encode(legacy_tx()));
+        assert!(matches!(envelope, TransactionEnvelope::Legacy(_)));
+        assert_eq!(envelope.tx_type(), 0);
+        assert_eq!(envelope.nonce(), 1);
+        assert_eq!(envelope.to().to(), Some([0x01; 20]));
+        assert_eq!(envelope.value(), value(100));
+        assert_eq!(envelope.data(), &[] as &[u8]);
+        assert_eq!(envelope.chain_id(), Some(5));
+        assert_eq!(envelope.gas_limit(), 21_000);
+    }
+
+    #[test]
+    fn eip2930() {
+        let envelope = parse(&encode(eip2930_tx()));
+        assert!(matches!(envelope, TransactionEnvelope::Eip2930(_)));
+        assert_eq!(envelope.nonce(), 2);
+        assert!(envelope.to().is_create());
+        assert_eq!(envelope.data(), &[0x60, 0x00]);
+        assert_eq!(envelope.chain_id(), Some(1));
+        assert_eq!(envelope.gas_limit(), 30_000);
+    }
+
+    #[test]
+    fn eip1559() {
+        let envelope = parse(&encode(eip1559_tx()));
+        assert!(matches!(envelope, TransactionEnvelope::Eip1559(_)));
+        assert_eq!(envelope.tx_type(), 2);
+        assert_eq!(envelope.nonce(), 3);
+        assert_eq!(envelope.to().to(), Some([0x03; 20]));
+        assert_eq!(envelope.value(), value(7));
+        assert_eq!(envelope.chain_id(), Some(10));
+    }
+
+    #[test]
+    fn eip4844() {
+        let envelope = parse(&encode(eip4844_tx()));
+        assert!(matches!(envelope, TransactionEnvelope::Eip4844(_)));
+        assert_eq!(envelope.nonce(), 4);
+        assert_eq!(envelope.to().to(), Some([0x04; 20]));
+        assert_eq!(envelope.gas_limit(), 50_000);
+    }
+
+    #[test]
+    fn eip7702() {
+        let envelope = parse(&encode(eip7702_tx()));
+        assert!(matches!(envelope, TransactionEnvelope::Eip7702(_)));
+        assert_eq!(envelope.tx_type(), 4);
+        assert_eq!(envelope.nonce(), 5);
+        assert_eq!(envelope.to().to(), Some([0x05; 20]));
+        assert_eq!(envelope.chain_id(), Some(1));
+    }
+
+    /// Signing payload must match alloy and recover the signer.
+    fn check_signing_payload<T>(tx: T)
+    where
+        T: SignableTransaction<Signature> + RlpEcdsaEncodableTx + Clone,
+    {
+        let signer = PrivateKeySigner::from_bytes(&B256::repeat_byte(0x46)).unwrap();
+        let signature = signer.sign_hash_sync(&tx.signature_hash()).unwrap();
+        let mut raw = Vec::new();
+        tx.clone().into_signed(signature).eip2718_encode(&mut raw);
+
+        let payload = parse(&raw).signing_payload();
+        assert_eq!(payload, tx.encoded_for_signing());
+        let hash = keccak256(&payload);
+        assert_eq!(
+            signature.recover_address_from_prehash(&hash).unwrap(),
+            signer.address()
+        );
+    }
+
+    #[test]
+    fn signing_payloads() {
+        check_signing_payload(legacy_tx());
+        check_signing_payload(TxLegacy {
+            chain_id: None,
+            ..legacy_tx()
+        });
+        check_signing_payload(eip2930_tx());
+        check_signing_payload(eip1559_tx());
+        check_signing_payload(eip4844_tx());
+        check_signing_payload(eip7702_tx());
+    }
+
+    fn check_round_trip(raw: &[u8]) {
+        let envelope = parse(raw);
+        let unsigned = envelope.into_unsigned_bytes();
+        let signed = TransactionEnvelope::attach_signature::<&[u8], ()>(
+            &unsigned.as_ref(),
+            &mut (),
+            *envelope.signature(),
+            envelope.chain_id(),
+        )
+        .unwrap();
+        assert_eq!(signed, envelope);
+        assert_eq!(signed.encode(), raw);
+    }
+
+    #[test]
+    fn unsigned_round_trip() {
+        check_round_trip(&encode(legacy_tx()));
+        check_round_trip(&encode(TxLegacy {
+            chain_id: None,
+            ..legacy_tx()
+        }));
+        check_round_trip(&encode(eip2930_tx()));
+        check_round_trip(&encode(eip1559_tx()));
+        check_round_trip(&encode(eip4844_tx()));
+        check_round_trip(&encode(eip7702_tx()));
+    }
+
+    #[test]
+    fn attach_signature_errors() {
+        let envelope = parse(&encode(legacy_tx()));
+        let unsigned = envelope.into_unsigned_bytes();
+        let attach = |unsigned: &[u8], chain_id| {
+            TransactionEnvelope::attach_signature::<&[u8], ()>(
+                &unsigned,
+                &mut (),
+                *envelope.signature(),
+                chain_id,
+            )
+        };
+        assert_eq!(
+            attach(&unsigned, None),
+            Err(TxError::ChainIdMismatch {
+                expected: None,
+                found: Some(5)
+            })
+        );
+
+        // Last item of `[chain_id, 0, 0]` replaced with `1`.
+        let mut bad_suffix = unsigned.clone();
+        *bad_suffix.last_mut().unwrap() = 0x01;
+        assert_eq!(
+            attach(&bad_suffix, Some(5)),
+            Err(TxError::InvalidEip155Suffix)
+        );
+
+        let unsigned = parse(&encode(eip1559_tx())).into_unsigned_bytes();
+        assert_eq!(
+            attach(&unsigned, Some(1)),
+            Err(TxError::ChainIdMismatch {
+                expected: Some(1),
+                found: Some(10)
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_types() {
+        for type_byte in [0x00, 0x05, 0x7f] {
+            let raw = [type_byte, 0xc0];
+            assert_eq!(
+                TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+                Err(TxError::UnknownTransactionType { type_byte })
+            );
+        }
+    }
+
+    #[test]
+    fn string_is_not_a_transaction() {
+        let raw = [0x80];
+        assert_eq!(
+            TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(TxError::NotATransaction { first_byte: 0x80 })
+        );
+        let raw = [0xb8, 0x38];
+        assert_eq!(
+            TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(TxError::NotATransaction { first_byte: 0xb8 })
+        );
+    }
+}
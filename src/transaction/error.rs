@@ -0,0 +1,245 @@
+use external_memory_tools::ExternalMemory;
+
+use crate::field::FieldError;
+use crate::span::ItemSpan;
+use crate::transaction::access_list::AccessListError;
+use crate::transaction::signature::SignatureError;
+use crate::transaction::versioned_hash::VersionedHashError;
+use crate::Error;
+
+/// Top-level transaction fields, used to locate errors.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TxField {
+    AccessList,
+    AuthorizationList,
+    BlobVersionedHashes,
+    ChainId,
+    Data,
+    GasLimit,
+    GasPrice,
+    MaxFeePerBlobGas,
+    MaxFeePerGas,
+    MaxPriorityFeePerGas,
+    Nonce,
+    R,
+    S,
+    To,
+    V,
+    Value,
+    YParity,
+}
+
+/// Fields of EIP-7702 authorization list entries.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuthorizationField {
+    Address,
+    ChainId,
+    Nonce,
+    R,
+    S,
+    YParity,
+}
+
+/// Blob sidecar lists of the network form of blob transactions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SidecarField {
+    Blobs,
+    Commitments,
+    Proofs,
+}
+
+/// Transaction size limits, see [`TxLimits`](crate::transaction::TxLimits).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LimitField {
+    AccessListEntries,
+    Authorizations,
+    Data,
+    StorageKeys,
+    /// Whole encoded transaction.
+    Total,
+}
+
+/// Errors in decoding a list of transactions with
+/// [`decode_transactions`](crate::transaction::decode_transactions).
+#[derive(Debug, Eq, PartialEq)]
+pub enum BatchError<E: ExternalMemory> {
+    /// Transactions list itself could not be decoded.
+    Decoding(Error<E>),
+    /// Transaction at `index` of the list, located at `span`, is invalid.
+    Transaction {
+        index: usize,
+        source: TxError<E>,
+        span: ItemSpan,
+    },
+}
+
+impl<E: ExternalMemory> From<Error<E>> for BatchError<E> {
+    fn from(error: Error<E>) -> Self {
+        BatchError::Decoding(error)
+    }
+}
+
+/// Errors in building transactions with
+/// [`TxBuilder`](crate::transaction::TxBuilder).
+#[derive(Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// Legacy transaction chain id above
+    /// [`MAX_EIP155_CHAIN_ID`](crate::transaction::MAX_EIP155_CHAIN_ID).
+    ChainIdTooHigh {
+        found: u64,
+    },
+    /// Empty destination in a transaction type that cannot create contracts.
+    CreateNotAllowed,
+    /// Set code transaction without authorizations.
+    EmptyAuthorizationList,
+    /// Authorization recovery id other than 0 or 1.
+    InvalidAuthorizationYParity {
+        index: usize,
+        value: u8,
+    },
+    InvalidSignature(SignatureError),
+    InvalidVersionedHash {
+        index: usize,
+        reason: VersionedHashError,
+    },
+    MissingField(TxField),
+    /// Blob transaction without blobs.
+    NoBlobVersionedHashes,
+    /// Field set, but not used by the transaction type.
+    UnusedField(TxField),
+}
+
+/// Errors in [`with_bumped_fees`](crate::transaction::TransactionEnvelope::with_bumped_fees).
+#[derive(Debug, Eq, PartialEq)]
+pub enum FeeBumpError {
+    /// Fees would decrease.
+    MultiplierBelowOne,
+    ZeroDenominator,
+}
+
+/// Rule violations of an [`Authorization`](crate::transaction::Authorization)
+/// as checked by EIP-7702 before applying it.
+#[derive(Debug, Eq, PartialEq)]
+pub enum AuthError {
+    /// Chain id is neither `0` nor the expected one.
+    ChainIdMismatch {
+        expected: u64,
+        found: [u8; 32],
+    },
+    /// Nonce `2^64 - 1`, which could not be incremented.
+    NonceOverflow,
+    Signature(SignatureError),
+}
+
+/// Protocol-level range violations in a parsed transaction.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// `gas_limit * gas_price + value` does not fit into 256 bits.
+    CostOverflow,
+    /// Gas limit above [`MAX_GAS_LIMIT`](crate::transaction::MAX_GAS_LIMIT).
+    GasLimitTooHigh {
+        found: u64,
+    },
+    Signature(SignatureError),
+}
+
+/// Errors in parsing transactions.
+#[derive(Debug, Eq, PartialEq)]
+pub enum TxError<E: ExternalMemory> {
+    /// Chain id given with the signature differs from the one in the
+    /// unsigned transaction.
+    ChainIdMismatch {
+        expected: Option<u64>,
+        found: Option<u64>,
+    },
+    /// Empty destination in a transaction type that cannot create contracts.
+    CreateNotAllowed,
+    Decoding(Error<E>),
+    /// Set code transaction without authorizations.
+    EmptyAuthorizationList,
+    InvalidAccessList(AccessListError),
+    InvalidAuthorization {
+        index: usize,
+        field: AuthorizationField,
+        reason: FieldError,
+    },
+    /// Authorization recovery id other than 0 or 1.
+    InvalidAuthorizationYParity {
+        index: usize,
+        value: u64,
+    },
+    /// Unsigned legacy transaction of 9 items does not end with
+    /// `[chain_id, 0, 0]`.
+    InvalidEip155Suffix,
+    InvalidField {
+        field: TxField,
+        reason: FieldError,
+    },
+    InvalidSignature(SignatureError),
+    InvalidSidecar {
+        field: SidecarField,
+        index: Option<usize>,
+        reason: FieldError,
+    },
+    InvalidVersionedHash {
+        index: usize,
+        reason: VersionedHashError,
+    },
+    /// Typed transaction recovery id other than 0 or 1.
+    InvalidYParity {
+        value: u64,
+    },
+    /// Transaction or its field is over the limit set in
+    /// [`TxLimits`](crate::transaction::TxLimits).
+    LimitExceeded {
+        field: LimitField,
+        limit: usize,
+        actual: usize,
+    },
+    /// Authorization list entry is not a list of 6 items.
+    MalformedAuthorization {
+        index: usize,
+    },
+    /// Blob transaction without blobs.
+    NoBlobVersionedHashes,
+    NotAList,
+    /// Input starts with a string prefix; transactions are either lists or
+    /// typed envelopes.
+    NotATransaction {
+        first_byte: u8,
+    },
+    /// Blob transaction without sidecar in strict
+    /// [`TxContext::Pooled`](crate::transaction::TxContext::Pooled) context.
+    SidecarRequired,
+    SidecarLengthMismatch {
+        versioned_hashes: usize,
+        blobs: usize,
+        commitments: usize,
+        proofs: usize,
+    },
+    /// First byte is in EIP-2718 type range `0x00..=0x7f`, but is not a
+    /// transaction type known to this crate. `0x00` is never a valid type.
+    UnknownTransactionType {
+        type_byte: u8,
+    },
+    UnexpectedType {
+        expected: u8,
+        found: u8,
+    },
+    /// Blob transaction with sidecar outside of
+    /// [`TxContext::Pooled`](crate::transaction::TxContext::Pooled) context.
+    WrapperNotAllowedHere,
+    /// Top-level list has a different number of fields than the transaction
+    /// type requires. Typically a body of one transaction type fed to the
+    /// parser of another.
+    WrongFieldCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<E: ExternalMemory> From<Error<E>> for TxError<E> {
+    fn from(error: Error<E>) -> Self {
+        TxError::Decoding(error)
+    }
+}
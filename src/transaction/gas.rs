@@ -0,0 +1,202 @@
+use crate::fork::Fork;
+use crate::transaction::TransactionEnvelope;
+
+/// Gas charged for every transaction.
+pub const TX_BASE_GAS: u64 = 21_000;
+
+/// Additional gas for contract creation, since Homestead.
+pub const TX_CREATE_GAS: u64 = 32_000;
+
+/// Gas per zero byte of calldata.
+pub const TX_DATA_ZERO_GAS: u64 = 4;
+
+/// Gas per non-zero byte of calldata, since Istanbul (EIP-2028).
+pub const TX_DATA_NON_ZERO_GAS: u64 = 16;
+
+/// Gas per non-zero byte of calldata before Istanbul.
+pub const TX_DATA_NON_ZERO_GAS_FRONTIER: u64 = 68;
+
+/// Gas per 32-byte word of init code, since Shanghai (EIP-3860).
+pub const INIT_CODE_WORD_GAS: u64 = 2;
+
+/// Gas per EIP-7702 authorization.
+pub const PER_EMPTY_ACCOUNT_COST: u64 = 25_000;
+
+impl TransactionEnvelope {
+    /// Gas charged before execution under the rules of `fork`.
+    ///
+    /// Includes the base cost, calldata, contract creation with init code
+    /// words, access list and authorization costs. The EIP-7623 calldata
+    /// floor is a separate lower bound for the gas limit and is not included.
+    pub fn intrinsic_gas(&self, fork: Fork) -> u64 {
+        let data = self.data();
+        let zero_bytes = data.iter().filter(|a| **a == 0).count() as u64;
+        let non_zero_bytes = data.len() as u64 - zero_bytes;
+        let non_zero_byte_gas = if fork >= Fork::Istanbul {
+            TX_DATA_NON_ZERO_GAS
+        } else {
+            TX_DATA_NON_ZERO_GAS_FRONTIER
+        };
+        let mut gas =
+            TX_BASE_GAS + zero_bytes * TX_DATA_ZERO_GAS + non_zero_bytes * non_zero_byte_gas;
+
+        if self.to().is_create() {
+            if fork >= Fork::Homestead {
+                gas += TX_CREATE_GAS;
+            }
+            if fork >= Fork::Shanghai {
+                gas += INIT_CODE_WORD_GAS * data.len().div_ceil(32) as u64;
+            }
+        }
+
+        gas += match self {
+            Self::Legacy(_) => 0,
+            Self::Eip2930(tx) => tx.access_list.gas_cost(),
+            Self::Eip1559(tx) => tx.access_list.gas_cost(),
+            Self::Eip4844(tx) => tx.access_list.gas_cost(),
+            Self::Eip7702(tx) => {
+                tx.access_list.gas_cost()
+                    + PER_EMPTY_ACCOUNT_COST * tx.authorization_list.len() as u64
+            }
+        };
+        gas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use crate::transaction::fixtures::{
+        DEVNET_EIP7702, MAINNET_EIP1559, MAINNET_EIP4844, MAINNET_LEGACY,
+    };
+    use crate::transaction::{
+        AccessList, AccessListItem, Authorization, Eip2930Transaction, Eip7702Transaction,
+        LegacyTransaction, Signature, TxKind,
+    };
+
+    fn signature() -> Signature {
+        Signature {
+            r: [0x01; 32],
+            s: [0x01; 32],
+            y_parity: 0,
+        }
+    }
+
+    fn legacy(to: TxKind, data: Vec<u8>) -> TransactionEnvelope {
+        TransactionEnvelope::Legacy(LegacyTransaction {
+            nonce: 0,
+            gas_price: [0; 32],
+            gas_limit: 1_000_000,
+            to,
+            value: [0; 32],
+            data,
+            signature: signature(),
+            chain_id: Some(1),
+        })
+    }
+
+    #[test]
+    fn transfer() {
+        let transfer = legacy(TxKind::Call([0x01; 20]), Vec::new());
+        assert_eq!(transfer.intrinsic_gas(Fork::Frontier), 21_000);
+        assert_eq!(transfer.intrinsic_gas(Fork::Prague), 21_000);
+
+        // Two zero and two non-zero bytes.
+        let call = legacy(TxKind::Call([0x01; 20]), vec![0x00, 0xa9, 0x00, 0x05]);
+        assert_eq!(call.intrinsic_gas(Fork::Byzantium), 21_000 + 8 + 136);
+        assert_eq!(call.intrinsic_gas(Fork::Istanbul), 21_000 + 8 + 32);
+    }
+
+    #[test]
+    fn creation() {
+        // 33 bytes of init code, one of them zero: two words.
+        let mut init_code = vec![0x60; 32];
+        init_code.push(0x00);
+        let creation = legacy(TxKind::Create, init_code);
+        assert_eq!(creation.intrinsic_gas(Fork::Frontier), 21_000 + 4 + 32 * 68);
+        assert_eq!(
+            creation.intrinsic_gas(Fork::Homestead),
+            53_000 + 4 + 32 * 68
+        );
+        assert_eq!(creation.intrinsic_gas(Fork::London), 53_000 + 4 + 32 * 16);
+        assert_eq!(
+            creation.intrinsic_gas(Fork::Shanghai),
+            53_000 + 4 + 32 * 16 + 4
+        );
+    }
+
+    #[test]
+    fn access_list_and_authorizations() {
+        let access_list = AccessList(vec![
+            AccessListItem {
+                address: [0x01; 20],
+                storage_keys: vec![[0; 32], [0x01; 32]],
+            },
+            AccessListItem {
+                address: [0x02; 20],
+                storage_keys: Vec::new(),
+            },
+        ]);
+        let eip2930 = TransactionEnvelope::Eip2930(Eip2930Transaction {
+            chain_id: 1,
+            nonce: 0,
+            gas_price: [0; 32],
+            gas_limit: 100_000,
+            to: TxKind::Call([0x01; 20]),
+            value: [0; 32],
+            data: Vec::new(),
+            access_list: access_list.clone(),
+            signature: signature(),
+        });
+        assert_eq!(
+            eip2930.intrinsic_gas(Fork::Berlin),
+            21_000 + 2 * 2_400 + 2 * 1_900
+        );
+
+        let authorization = Authorization {
+            chain_id: [0; 32],
+            address: [0xaa; 20],
+            nonce: 0,
+            y_parity: 0,
+            r: [0x01; 32],
+            s: [0x01; 32],
+        };
+        let eip7702 = TransactionEnvelope::Eip7702(Eip7702Transaction {
+            chain_id: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: [0; 32],
+            max_fee_per_gas: [0; 32],
+            gas_limit: 100_000,
+            to: TxKind::Call([0x01; 20]),
+            value: [0; 32],
+            data: Vec::new(),
+            access_list,
+            authorization_list: vec![authorization.clone(), authorization],
+            signature: signature(),
+        });
+        assert_eq!(
+            eip7702.intrinsic_gas(Fork::Prague),
+            21_000 + 2 * 2_400 + 2 * 1_900 + 2 * 25_000
+        );
+    }
+
+    /// Clients reject transactions with a gas limit below the intrinsic
+    /// gas, so every included transaction bounds it from above.
+    #[test]
+    fn included_transactions() {
+        for (raw, fork) in [
+            (MAINNET_LEGACY, Fork::Paris),
+            (MAINNET_EIP1559, Fork::London),
+            (MAINNET_EIP4844, Fork::Cancun),
+            (DEVNET_EIP7702, Fork::Prague),
+        ] {
+            let raw = hex::decode(raw).unwrap();
+            let tx = TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+            let intrinsic_gas = tx.intrinsic_gas(fork);
+            assert!(intrinsic_gas > TX_BASE_GAS);
+            assert!(intrinsic_gas <= tx.gas_limit());
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::keccak::{keccak256, keccak256_concat};
+use crate::transaction::pooled::is_blob_wrapper;
+use crate::transaction::{Eip4844Transaction, PooledBlobTransaction, TransactionEnvelope, TxError};
+use crate::{decode_checked_at_position, limit_to, Error, BORDER_C};
+
+impl TransactionEnvelope {
+    /// Transaction hash: keccak of the canonical signed encoding.
+    pub fn hash(&self) -> [u8; 32] {
+        keccak256(&self.encode())
+    }
+}
+
+/// Hash of a raw transaction of any supported type, including the network
+/// form of blob transactions, which is hashed without the sidecar.
+///
+/// Canonically encoded input is hashed as is; otherwise the transaction is
+/// re-encoded first.
+pub fn transaction_hash_from_raw<B, E>(data: &B, ext_memory: &mut E) -> Result<[u8; 32], TxError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let first_byte = data
+        .read_byte(ext_memory, 0)
+        .map_err(|e| TxError::Decoding(Error::Buffer(e)))?;
+    if is_blob_wrapper(data, ext_memory, 0) {
+        return pooled_hash(data, ext_memory);
+    }
+    let envelope = TransactionEnvelope::parse(data, ext_memory)?;
+    let rlp_start = if first_byte >= BORDER_C { 0 } else { 1 };
+    if is_canonical(data, ext_memory, rlp_start)? {
+        let raw = data
+            .read_slice(ext_memory, 0, data.total_len())
+            .map_err(|e| TxError::Decoding(Error::Buffer(e)))?;
+        Ok(keccak256(raw.as_ref()))
+    } else {
+        Ok(envelope.hash())
+    }
+}
+
+fn pooled_hash<B, E>(data: &B, ext_memory: &mut E) -> Result<[u8; 32], TxError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let pooled = PooledBlobTransaction::parse(data, ext_memory)?;
+    let body_data = limit_to(data, pooled.body.end)?;
+    if is_canonical(&body_data, ext_memory, pooled.body.start)? {
+        let body = pooled.body.read_raw(data, ext_memory)?;
+        Ok(keccak256_concat(&[
+            &[Eip4844Transaction::TX_TYPE],
+            body.as_ref(),
+        ]))
+    } else {
+        Ok(keccak256(&pooled.transaction.encode()))
+    }
+}
+
+fn is_canonical<B, E>(data: &B, ext_memory: &mut E, start: usize) -> Result<bool, TxError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = start;
+    let (_, canonical) = decode_checked_at_position(data, ext_memory, &mut position)?;
+    Ok(canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::transaction::RlpEcdsaEncodableTx;
+    use alloy_consensus::{SignableTransaction, TxEip2930, TxEip4844, TxEip7702};
+    use alloy_eips::eip2930::AccessList;
+    use alloy_eips::eip4844::{Blob, BlobTransactionSidecar, Bytes48};
+    use alloy_eips::eip7702::{Authorization, SignedAuthorization};
+    use alloy_primitives::{Address, Bytes, Signature, TxKind, B256, U256};
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use crate::transaction::fixtures;
+
+    fn signature() -> Signature {
+        Signature::new(U256::from(0xabcdu64), U256::from(0x1234u64), true)
+    }
+
+    /// Check hashes of the raw and the parsed transaction against alloy.
+    fn check<T>(tx: T)
+    where
+        T: SignableTransaction<Signature> + RlpEcdsaEncodableTx,
+    {
+        let signed = tx.into_signed(signature());
+        let mut raw = Vec::new();
+        signed.eip2718_encode(&mut raw);
+
+        let from_raw = transaction_hash_from_raw::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(from_raw, signed.hash().0);
+        let envelope = TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(envelope.hash(), signed.hash().0);
+        assert_eq!(envelope.encode(), raw);
+    }
+
+    fn eip4844_tx() -> TxEip4844 {
+        TxEip4844 {
+            chain_id: 1,
+            nonce: 4,
+            gas_limit: 50_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 2,
+            to: Address::repeat_byte(0x04),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            blob_versioned_hashes: vec![B256::repeat_byte(0x01)],
+            max_fee_per_blob_gas: 1,
+            input: Bytes::new(),
+        }
+    }
+
+    /// Mainnet transactions with their block explorer hashes, and a devnet
+    /// EIP-7702 transaction with the hash reported by its node.
+    const PUBLIC_NETWORKS: [(u8, &str, &str); 4] = [
+        (
+            0,
+            fixtures::MAINNET_LEGACY,
+            "280cde7cdefe4b188750e76c888f13bd05ce9a4d7767730feefe8a0e50ca6fc4",
+        ),
+        (
+            2,
+            fixtures::MAINNET_EIP1559,
+            "ce4dc6d7a7549a98ee3b071b67e970879ff51b5b95d1c340bacd80fa1e1aab31",
+        ),
+        (
+            3,
+            fixtures::MAINNET_EIP4844,
+            "93fc9daaa0726c3292a2e939df60f7e773c6a6a726a61ce43f4a217c64d85e87",
+        ),
+        (
+            4,
+            fixtures::DEVNET_EIP7702,
+            "adc3f24d05f05f1065debccb1c4b033eaa35917b69b343d88d9062cdf8ecad83",
+        ),
+    ];
+
+    #[test]
+    fn public_networks() {
+        for (tx_type, raw, tx_hash) in PUBLIC_NETWORKS {
+            let raw = hex::decode(raw).unwrap();
+            let tx_hash: [u8; 32] = hex::decode(tx_hash).unwrap().try_into().unwrap();
+            assert_eq!(
+                transaction_hash_from_raw::<&[u8], ()>(&raw.as_ref(), &mut ()),
+                Ok(tx_hash)
+            );
+            let envelope = TransactionEnvelope::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+            assert_eq!(envelope.tx_type(), tx_type);
+            assert_eq!(envelope.hash(), tx_hash);
+        }
+    }
+
+    #[test]
+    fn other_types() {
+        check(TxEip2930 {
+            chain_id: 1,
+            nonce: 2,
+            gas_price: 10,
+            gas_limit: 30_000,
+            to: TxKind::Create,
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            input: Bytes::from(vec![0x60, 0x00]),
+        });
+        check(TxEip7702 {
+            chain_id: 1,
+            nonce: 5,
+            gas_limit: 60_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 2,
+            to: Address::repeat_byte(0x05),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            authorization_list: vec![SignedAuthorization::new_unchecked(
+                Authorization {
+                    chain_id: U256::from(1u64),
+                    address: Address::repeat_byte(0xaa),
+                    nonce: 0,
+                },
+                0,
+                U256::from(1u64),
+                U256::from(1u64),
+            )],
+            input: Bytes::new(),
+        });
+    }
+
+    #[test]
+    fn pooled_blob_transaction() {
+        let sidecar = BlobTransactionSidecar {
+            blobs: vec![Blob::ZERO],
+            commitments: vec![Bytes48::repeat_byte(0xc0)],
+            proofs: vec![Bytes48::repeat_byte(0xd0)],
+        };
+        let signed = eip4844_tx().with_sidecar(sidecar).into_signed(signature());
+        let mut raw = Vec::new();
+        signed.eip2718_encode(&mut raw);
+
+        let hash = transaction_hash_from_raw::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(hash, signed.hash().0);
+    }
+
+    #[test]
+    fn non_canonical_input() {
+        let signed = eip4844_tx().into_signed(signature());
+        let mut raw = Vec::new();
+        signed.eip2718_encode(&mut raw);
+
+        // Same transaction with a leading zero in the outer list length.
+        assert_eq!(raw[1], 0xf8);
+        let mut non_canonical = vec![raw[0], 0xf9, 0x00];
+        non_canonical.extend_from_slice(&raw[2..]);
+
+        let hash =
+            transaction_hash_from_raw::<&[u8], ()>(&non_canonical.as_ref(), &mut ()).unwrap();
+        assert_eq!(hash, signed.hash().0);
+    }
+}
@@ -0,0 +1,138 @@
+use core::fmt;
+
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::field::FieldError;
+use crate::ParsedData;
+
+/// Transaction destination.
+///
+/// Contract creation is encoded as the empty string, which differs from a
+/// call to the zero address.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TxKind {
+    Create,
+    Call([u8; 20]),
+}
+
+impl TxKind {
+    /// Empty string is [`TxKind::Create`], 20 bytes are [`TxKind::Call`].
+    pub fn parse_from(parsed_data: &ParsedData) -> Result<Self, FieldError> {
+        if parsed_data.is_empty_string() {
+            Ok(Self::Create)
+        } else {
+            parsed_data.as_fixed_bytes::<20>().map(Self::Call)
+        }
+    }
+
+    /// Called address, `None` for contract creation.
+    pub fn to(&self) -> Option<[u8; 20]> {
+        match self {
+            Self::Create => None,
+            Self::Call(a) => Some(*a),
+        }
+    }
+
+    pub fn is_create(&self) -> bool {
+        matches!(self, Self::Create)
+    }
+
+    pub(crate) fn to_parsed(self) -> ParsedData {
+        match self {
+            Self::Create => ParsedData::String(Vec::new()),
+            Self::Call(a) => ParsedData::String(a.to_vec()),
+        }
+    }
+}
+
+/// `CREATE`, or `0x`-prefixed address. Addresses are EIP-55 checksummed with
+/// the `keccak` feature, lowercase otherwise.
+impl fmt::Display for TxKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Create => f.write_str("CREATE"),
+            Self::Call(a) => write_address(f, a),
+        }
+    }
+}
+
+#[cfg(feature = "keccak")]
+fn write_address(f: &mut fmt::Formatter<'_>, address: &[u8; 20]) -> fmt::Result {
+    let mut lowercase = [0; 40];
+    for (i, a) in address.iter().enumerate() {
+        lowercase[2 * i] = HEX_DIGITS[(a >> 4) as usize];
+        lowercase[2 * i + 1] = HEX_DIGITS[(a & 0x0f) as usize];
+    }
+    let hash = crate::keccak::keccak256(&lowercase);
+    f.write_str("0x")?;
+    for (i, digit) in lowercase.iter().enumerate() {
+        let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+        let digit = if nibble >= 8 {
+            digit.to_ascii_uppercase()
+        } else {
+            *digit
+        };
+        fmt::Write::write_char(f, digit as char)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "keccak"))]
+fn write_address(f: &mut fmt::Formatter<'_>, address: &[u8; 20]) -> fmt::Result {
+    f.write_str("0x")?;
+    for a in address {
+        fmt::Write::write_char(f, HEX_DIGITS[(a >> 4) as usize] as char)?;
+        fmt::Write::write_char(f, HEX_DIGITS[(a & 0x0f) as usize] as char)?;
+    }
+    Ok(())
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+#[cfg(test)]
+mod tests {
+    use std::{format, vec};
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            TxKind::parse_from(&ParsedData::String(Vec::new())),
+            Ok(TxKind::Create)
+        );
+        assert_eq!(
+            TxKind::parse_from(&ParsedData::String(vec![0; 20])),
+            Ok(TxKind::Call([0; 20]))
+        );
+        assert_eq!(
+            TxKind::parse_from(&ParsedData::Byte(0)),
+            Err(FieldError::WrongLength {
+                expected: 20,
+                found: 1
+            })
+        );
+        assert_eq!(
+            TxKind::parse_from(&ParsedData::List(Vec::new())),
+            Err(FieldError::ExpectedString)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", TxKind::Create), "CREATE");
+        let address: [u8; 20] = hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        #[cfg(feature = "keccak")]
+        let expected = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        #[cfg(not(feature = "keccak"))]
+        let expected = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+        assert_eq!(format!("{}", TxKind::Call(address)), expected);
+    }
+}
@@ -0,0 +1,331 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::encode::{leaf, scalar};
+use crate::transaction::{
+    decode_payload, fields, invalid, parse_kind, upfront_cost, Signature, TxConfig, TxError,
+    TxField, TxKind, ValidationError, MAX_GAS_LIMIT,
+};
+use crate::ParsedData;
+
+/// Untyped transaction, a bare RLP list of 9 fields.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyTransaction {
+    pub nonce: u64,
+    pub gas_price: [u8; 32],
+    pub gas_limit: u64,
+    pub to: TxKind,
+    pub value: [u8; 32],
+    pub data: Vec<u8>,
+    pub signature: Signature,
+    /// Chain id encoded in `v` per EIP-155, `None` for pre-EIP-155 `v` of 27
+    /// or 28.
+    pub chain_id: Option<u64>,
+}
+
+impl LegacyTransaction {
+    /// Type reported for legacy transactions, never present in encoding.
+    pub const TX_TYPE: u8 = 0x00;
+    const FIELD_COUNT: usize = 9;
+
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_with_config(data, ext_memory, &TxConfig::default())
+    }
+
+    pub fn parse_with_config<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        config.limits.check(data, ext_memory, Self::TX_TYPE)?;
+        Self::from_parsed(&decode_payload(data, ext_memory)?, config)
+    }
+
+    pub(crate) fn from_parsed<E: ExternalMemory>(
+        parsed_data: &ParsedData,
+        config: &TxConfig,
+    ) -> Result<Self, TxError<E>> {
+        let fields = fields(parsed_data, Self::FIELD_COUNT)?;
+        let v = config.scalar_u64(&fields[6]).map_err(invalid(TxField::V))?;
+        let r = config
+            .scalar_u256(&fields[7])
+            .map_err(invalid(TxField::R))?;
+        let s = config
+            .scalar_u256(&fields[8])
+            .map_err(invalid(TxField::S))?;
+        let (signature, chain_id) =
+            Signature::from_v(v, &r, &s).map_err(TxError::InvalidSignature)?;
+        Ok(Self {
+            nonce: config
+                .scalar_u64(&fields[0])
+                .map_err(invalid(TxField::Nonce))?,
+            gas_price: config
+                .scalar_u256(&fields[1])
+                .map_err(invalid(TxField::GasPrice))?,
+            gas_limit: config
+                .scalar_u64(&fields[2])
+                .map_err(invalid(TxField::GasLimit))?,
+            to: parse_kind(Self::TX_TYPE, &fields[3])?,
+            value: config
+                .scalar_u256(&fields[4])
+                .map_err(invalid(TxField::Value))?,
+            data: fields[5].to_bytes_vec().map_err(invalid(TxField::Data))?,
+            signature,
+            chain_id,
+        })
+    }
+
+    /// Bytes hashed for signing: `rlp([nonce, gas_price, gas_limit, to, value,
+    /// data])`, extended with `[chain_id, 0, 0]` per EIP-155 if `chain_id` is
+    /// set.
+    pub fn signing_payload(&self, chain_id: Option<u64>) -> Vec<u8> {
+        let mut fields = self.unsigned_fields();
+        if let Some(chain_id) = chain_id {
+            fields.extend([scalar(&chain_id.to_be_bytes()), scalar(&[]), scalar(&[])]);
+        }
+        ParsedData::List(fields).to_vec()
+    }
+
+    /// Canonical encoding of the signed transaction.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut fields = self.unsigned_fields();
+        fields.extend([
+            scalar(&self.v().to_be_bytes()),
+            scalar(&self.signature.r),
+            scalar(&self.signature.s),
+        ]);
+        ParsedData::List(fields).to_vec()
+    }
+
+    /// Run all range checks below.
+    pub fn validate_ranges(&self) -> Result<(), ValidationError> {
+        self.check_gas_limit()?;
+        self.check_cost()?;
+        self.check_signature()
+    }
+
+    /// Gas limit is at most [`MAX_GAS_LIMIT`].
+    pub fn check_gas_limit(&self) -> Result<(), ValidationError> {
+        if self.gas_limit > MAX_GAS_LIMIT {
+            Err(ValidationError::GasLimitTooHigh {
+                found: self.gas_limit,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Maximal cost `gas_limit * gas_price + value` fits into 256 bits.
+    pub fn check_cost(&self) -> Result<(), ValidationError> {
+        upfront_cost(self.gas_limit, &self.gas_price, &self.value)
+            .map(|_| ())
+            .ok_or(ValidationError::CostOverflow)
+    }
+
+    /// Structural signature checks, including EIP-2 low `s`, see
+    /// [`Signature::validate`].
+    pub fn check_signature(&self) -> Result<(), ValidationError> {
+        self.signature
+            .validate()
+            .map_err(ValidationError::Signature)
+    }
+
+    /// Original `v` value, restored from the signature and chain id. Chain
+    /// id must not exceed
+    /// [`MAX_EIP155_CHAIN_ID`](crate::transaction::MAX_EIP155_CHAIN_ID), as
+    /// ensured by parsing and by [`TxBuilder`](crate::transaction::TxBuilder).
+    pub fn v(&self) -> u64 {
+        match self.chain_id {
+            Some(chain_id) => 35 + 2 * chain_id + self.signature.y_parity as u64,
+            None => 27 + self.signature.y_parity as u64,
+        }
+    }
+
+    fn unsigned_fields(&self) -> Vec<ParsedData> {
+        Vec::from([
+            scalar(&self.nonce.to_be_bytes()),
+            scalar(&self.gas_price),
+            scalar(&self.gas_limit.to_be_bytes()),
+            self.to.to_parsed(),
+            scalar(&self.value),
+            leaf(self.data.clone()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{SignableTransaction, TxLegacy};
+    use alloy_primitives::{
+        hex, keccak256, Bytes, Signature as AlloySignature, TxKind as AlloyTxKind, U256,
+    };
+    use std::vec;
+
+    use super::*;
+    use crate::transaction::SignatureError;
+
+    /// Signed transaction example from EIP-155 specification.
+    const EIP155_EXAMPLE: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    #[test]
+    fn eip155_example() {
+        let raw = hex::decode(EIP155_EXAMPLE).unwrap();
+        let tx = LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+
+        let mut gas_price = [0; 32];
+        gas_price[24..].copy_from_slice(&20_000_000_000u64.to_be_bytes());
+        let mut value = [0; 32];
+        value[24..].copy_from_slice(&1_000_000_000_000_000_000u64.to_be_bytes());
+        let r = hex::decode("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276")
+            .unwrap();
+        let s = hex::decode("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83")
+            .unwrap();
+
+        assert_eq!(
+            tx,
+            LegacyTransaction {
+                nonce: 9,
+                gas_price,
+                gas_limit: 21000,
+                to: TxKind::Call([0x35; 20]),
+                value,
+                data: Vec::new(),
+                signature: Signature {
+                    r: r.try_into().unwrap(),
+                    s: s.try_into().unwrap(),
+                    y_parity: 0,
+                },
+                chain_id: Some(1),
+            }
+        );
+        assert_eq!(tx.v(), 37);
+    }
+
+    #[test]
+    fn eip155_signing_payload() {
+        let raw = hex::decode(EIP155_EXAMPLE).unwrap();
+        let tx = LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        let payload = tx.signing_payload(tx.chain_id);
+        assert_eq!(
+            hex::encode(&payload),
+            "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080"
+        );
+        assert_eq!(
+            keccak256(&payload),
+            hex!("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+        );
+    }
+
+    #[test]
+    fn pre_eip155_signing_payload() {
+        let tx = TxLegacy {
+            chain_id: None,
+            nonce: 0,
+            gas_price: 50_000_000_000,
+            gas_limit: 90_000,
+            to: AlloyTxKind::Create,
+            value: U256::ZERO,
+            input: Bytes::from(vec![0x60, 0x60, 0x60, 0x40, 0x52]),
+        };
+        let signature = AlloySignature::new(U256::from(1u64), U256::from(2u64), true);
+        let mut raw = Vec::new();
+        tx.clone().into_signed(signature).rlp_encode(&mut raw);
+
+        let parsed = LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(parsed.chain_id, None);
+        assert_eq!(parsed.signing_payload(None), tx.encoded_for_signing());
+        assert_eq!(keccak256(parsed.signing_payload(None)), tx.signature_hash());
+    }
+
+    #[test]
+    fn pre_eip155_v_27() {
+        let tx = TxLegacy {
+            chain_id: None,
+            nonce: 5,
+            gas_price: 50_000_000_000,
+            gas_limit: 21_000,
+            to: AlloyTxKind::Call([0x35; 20].into()),
+            value: U256::from(1_000_000_000_000_000u64),
+            input: Bytes::new(),
+        };
+        let signature = AlloySignature::new(U256::from(1u64), U256::from(2u64), false);
+        let mut raw = Vec::new();
+        tx.clone().into_signed(signature).rlp_encode(&mut raw);
+        // `v`, `r` and `s` are single bytes at the end.
+        assert_eq!(raw[raw.len() - 3..], [27, 1, 2]);
+
+        let parsed = LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(parsed.chain_id, None);
+        assert_eq!(parsed.signature.y_parity, 0);
+        assert_eq!(parsed.v(), 27);
+        assert_eq!(parsed.encode(), raw);
+        assert_eq!(
+            keccak256(parsed.signing_payload(parsed.chain_id)),
+            tx.signature_hash()
+        );
+    }
+
+    #[test]
+    fn validate_ranges() {
+        let raw = hex::decode(EIP155_EXAMPLE).unwrap();
+        let tx = LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(tx.validate_ranges(), Ok(()));
+
+        let tx = LegacyTransaction {
+            gas_limit: MAX_GAS_LIMIT + 1,
+            ..LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+        };
+        assert_eq!(
+            tx.check_gas_limit(),
+            Err(ValidationError::GasLimitTooHigh {
+                found: MAX_GAS_LIMIT + 1
+            })
+        );
+        assert_eq!(tx.check_cost(), Ok(()));
+
+        let mut gas_price = [0; 32];
+        gas_price[0] = 0x01;
+        let tx = LegacyTransaction {
+            gas_price,
+            ..LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+        };
+        assert_eq!(tx.check_cost(), Err(ValidationError::CostOverflow));
+
+        let tx = LegacyTransaction {
+            gas_price: [0; 32],
+            gas_limit: 1,
+            value: [0xff; 32],
+            ..LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap()
+        };
+        assert_eq!(tx.check_cost(), Ok(()));
+
+        let mut tx = LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        tx.signature.s = [0xff; 32];
+        assert_eq!(
+            tx.validate_ranges(),
+            Err(ValidationError::Signature(SignatureError::SOutOfRange))
+        );
+    }
+
+    #[test]
+    fn impossible_v() {
+        let raw = hex::decode(EIP155_EXAMPLE.replace("8025a0", "801ea0")).unwrap();
+        assert_eq!(
+            LegacyTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()),
+            Err(TxError::InvalidSignature(SignatureError::InvalidV(30)))
+        );
+    }
+}
@@ -0,0 +1,409 @@
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::span::{span_at_position, ItemKind, ItemSpan};
+use crate::transaction::{
+    Eip2930Transaction, Eip7702Transaction, LegacyTransaction, LimitField, TxError,
+};
+use crate::{limit_to, Error};
+
+/// Size limits for parsed transactions.
+///
+/// Limits are checked on item prefixes before the transaction is decoded,
+/// so oversized fields are never copied. Defaults impose no limits.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TxLimits {
+    /// Whole encoded transaction, type byte included.
+    pub max_total_bytes: usize,
+    pub max_data_bytes: usize,
+    pub max_access_list_entries: usize,
+    pub max_storage_keys_per_entry: usize,
+    pub max_authorizations: usize,
+}
+
+impl Default for TxLimits {
+    fn default() -> Self {
+        Self {
+            max_total_bytes: usize::MAX,
+            max_data_bytes: usize::MAX,
+            max_access_list_entries: usize::MAX,
+            max_storage_keys_per_entry: usize::MAX,
+            max_authorizations: usize::MAX,
+        }
+    }
+}
+
+impl TxLimits {
+    /// Check encoded transaction of type `tx_type` against the limits.
+    ///
+    /// Malformed structure is left for the decoder to report, only errors in
+    /// reading prefixes are returned here.
+    pub(crate) fn check<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        tx_type: u8,
+    ) -> Result<(), TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let start = if tx_type == LegacyTransaction::TX_TYPE {
+            0
+        } else {
+            1
+        };
+        self.check_at(data, ext_memory, tx_type, start, data.total_len())
+    }
+
+    /// Same as [`check`](Self::check), for the inner RLP list of a typed
+    /// transaction without the type byte.
+    pub(crate) fn check_payload<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        tx_type: u8,
+    ) -> Result<(), TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        self.check_at(data, ext_memory, tx_type, 0, data.total_len() + 1)
+    }
+
+    /// Check transaction with the RLP list at `start`, `total_len` bytes long
+    /// in its full encoding.
+    pub(crate) fn check_at<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        tx_type: u8,
+        start: usize,
+        total_len: usize,
+    ) -> Result<(), TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        exceeds(LimitField::Total, self.max_total_bytes, total_len)?;
+        let mut position = start;
+        let payload = span_at_position(data, ext_memory, &mut position)?;
+        if payload.kind != ItemKind::List {
+            return Ok(());
+        }
+        let data = limit_to(data, payload.end)?;
+        let data_index = match tx_type {
+            LegacyTransaction::TX_TYPE => 5,
+            Eip2930Transaction::TX_TYPE => 6,
+            _ => 7,
+        };
+        let mut position = payload.payload_start;
+        let mut index = 0;
+        while position < payload.end {
+            let field = span_at_position(&data, ext_memory, &mut position)?;
+            if index == data_index && field.kind != ItemKind::List {
+                exceeds(LimitField::Data, self.max_data_bytes, field.payload_len())?;
+            } else if index == data_index + 1 && tx_type != LegacyTransaction::TX_TYPE {
+                self.check_access_list(&data, ext_memory, &field)?;
+            } else if index == data_index + 2 && tx_type == Eip7702Transaction::TX_TYPE {
+                let authorizations = count_items(&data, ext_memory, &field)?;
+                exceeds(
+                    LimitField::Authorizations,
+                    self.max_authorizations,
+                    authorizations,
+                )?;
+            }
+            index += 1;
+        }
+        Ok(())
+    }
+
+    fn check_access_list<B, E>(
+        &self,
+        data: &B,
+        ext_memory: &mut E,
+        list: &ItemSpan,
+    ) -> Result<(), TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let entries = count_items(data, ext_memory, list)?;
+        exceeds(
+            LimitField::AccessListEntries,
+            self.max_access_list_entries,
+            entries,
+        )?;
+        if list.kind != ItemKind::List {
+            return Ok(());
+        }
+        let mut position = list.payload_start;
+        while position < list.end {
+            let entry = span_at_position(data, ext_memory, &mut position)?;
+            if entry.kind != ItemKind::List {
+                continue;
+            }
+            // Skip the address, storage keys follow.
+            let mut entry_position = entry.payload_start;
+            if entry_position < entry.end {
+                span_at_position(data, ext_memory, &mut entry_position)?;
+            }
+            if entry_position < entry.end {
+                let keys = span_at_position(data, ext_memory, &mut entry_position)?;
+                let count = count_items(data, ext_memory, &keys)?;
+                exceeds(
+                    LimitField::StorageKeys,
+                    self.max_storage_keys_per_entry,
+                    count,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Number of elements of a list item, `0` for strings. Only prefixes are
+/// read.
+fn count_items<B, E>(data: &B, ext_memory: &mut E, list: &ItemSpan) -> Result<usize, Error<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    if list.kind != ItemKind::List {
+        return Ok(0);
+    }
+    let mut position = list.payload_start;
+    let mut count = 0;
+    while position < list.end {
+        span_at_position(data, ext_memory, &mut position)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn exceeds<E: ExternalMemory>(
+    field: LimitField,
+    limit: usize,
+    actual: usize,
+) -> Result<(), TxError<E>> {
+    if actual > limit {
+        Err(TxError::LimitExceeded {
+            field,
+            limit,
+            actual,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use crate::transaction::{
+        AccessList, AccessListItem, Authorization, Eip1559Transaction, Signature,
+        TransactionEnvelope, TxBuilder, TxConfig, TxKind,
+    };
+
+    fn signature() -> Signature {
+        Signature {
+            r: [0x01; 32],
+            s: [0x01; 32],
+            y_parity: 0,
+        }
+    }
+
+    fn parse(raw: &[u8], limits: TxLimits) -> Result<TransactionEnvelope, TxError<()>> {
+        TransactionEnvelope::parse_with_config::<&[u8], ()>(
+            &raw,
+            &mut (),
+            &TxConfig {
+                limits,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn with_data(builder: TxBuilder, data_len: usize) -> Vec<u8> {
+        builder
+            .nonce(0)
+            .gas_limit(100_000)
+            .to(TxKind::Call([0x11; 20]))
+            .data(vec![0xaa; data_len])
+            .build_signed(signature())
+            .unwrap()
+            .encode()
+    }
+
+    #[test]
+    fn data_at_limit() {
+        let limits = TxLimits {
+            max_data_bytes: 100,
+            ..Default::default()
+        };
+        let builders = [
+            TxBuilder::legacy().gas_price([0; 32]),
+            TxBuilder::eip2930().chain_id(1).gas_price([0; 32]),
+            TxBuilder::eip1559()
+                .chain_id(1)
+                .max_priority_fee_per_gas([0; 32])
+                .max_fee_per_gas([0; 32]),
+        ];
+        for builder in builders {
+            assert!(parse(&with_data(builder.clone(), 100), limits).is_ok());
+            assert_eq!(
+                parse(&with_data(builder, 101), limits),
+                Err(TxError::LimitExceeded {
+                    field: LimitField::Data,
+                    limit: 100,
+                    actual: 101
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn payload_only() {
+        let limits = |max_total_bytes| TxLimits {
+            max_total_bytes,
+            max_data_bytes: 100,
+            ..Default::default()
+        };
+        let parse_payload = |raw: &[u8], limits| {
+            Eip1559Transaction::parse_payload_with_config::<&[u8], ()>(
+                &&raw[1..],
+                &mut (),
+                &TxConfig {
+                    limits,
+                    ..Default::default()
+                },
+            )
+        };
+        let builder = TxBuilder::eip1559()
+            .chain_id(1)
+            .max_priority_fee_per_gas([0; 32])
+            .max_fee_per_gas([0; 32]);
+
+        let raw = with_data(builder.clone(), 100);
+        assert!(parse_payload(&raw, limits(raw.len())).is_ok());
+        // Type byte is counted even if not passed in.
+        assert_eq!(
+            parse_payload(&raw, limits(raw.len() - 1)),
+            Err(TxError::LimitExceeded {
+                field: LimitField::Total,
+                limit: raw.len() - 1,
+                actual: raw.len()
+            })
+        );
+        let raw = with_data(builder, 101);
+        assert_eq!(
+            parse_payload(&raw, limits(usize::MAX)),
+            Err(TxError::LimitExceeded {
+                field: LimitField::Data,
+                limit: 100,
+                actual: 101
+            })
+        );
+    }
+
+    #[test]
+    fn total_bytes() {
+        let raw = with_data(TxBuilder::legacy().gas_price([0; 32]), 10);
+        let limits = |max_total_bytes| TxLimits {
+            max_total_bytes,
+            ..Default::default()
+        };
+        assert!(parse(&raw, limits(raw.len())).is_ok());
+        assert_eq!(
+            parse(&raw, limits(raw.len() - 1)),
+            Err(TxError::LimitExceeded {
+                field: LimitField::Total,
+                limit: raw.len() - 1,
+                actual: raw.len()
+            })
+        );
+    }
+
+    #[test]
+    fn lists() {
+        let authorization = Authorization {
+            chain_id: [0; 32],
+            address: [0xaa; 20],
+            nonce: 0,
+            y_parity: 0,
+            r: [0x01; 32],
+            s: [0x01; 32],
+        };
+        let raw = TxBuilder::eip7702()
+            .chain_id(1)
+            .nonce(0)
+            .max_priority_fee_per_gas([0; 32])
+            .max_fee_per_gas([0; 32])
+            .gas_limit(100_000)
+            .to(TxKind::Call([0x11; 20]))
+            .access_list(AccessList(vec![
+                AccessListItem {
+                    address: [0x01; 20],
+                    storage_keys: vec![[0; 32]; 3],
+                },
+                AccessListItem {
+                    address: [0x02; 20],
+                    storage_keys: Vec::new(),
+                },
+            ]))
+            .authorization_list(vec![authorization.clone(), authorization])
+            .build_signed(signature())
+            .unwrap()
+            .encode();
+        let exact = TxLimits {
+            max_access_list_entries: 2,
+            max_storage_keys_per_entry: 3,
+            max_authorizations: 2,
+            ..Default::default()
+        };
+        assert!(parse(&raw, exact).is_ok());
+        assert_eq!(
+            parse(
+                &raw,
+                TxLimits {
+                    max_access_list_entries: 1,
+                    ..exact
+                }
+            ),
+            Err(TxError::LimitExceeded {
+                field: LimitField::AccessListEntries,
+                limit: 1,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            parse(
+                &raw,
+                TxLimits {
+                    max_storage_keys_per_entry: 2,
+                    ..exact
+                }
+            ),
+            Err(TxError::LimitExceeded {
+                field: LimitField::StorageKeys,
+                limit: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            parse(
+                &raw,
+                TxLimits {
+                    max_authorizations: 1,
+                    ..exact
+                }
+            ),
+            Err(TxError::LimitExceeded {
+                field: LimitField::Authorizations,
+                limit: 1,
+                actual: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Typed Ethereum transactions.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::encode::scalar;
+use crate::field::FieldError;
+use crate::{decode_blob_portion_at_position, decode_whole_blob, Error, ParsedData};
+
+mod access_list;
+mod batch;
+mod builder;
+mod bump;
+mod eip1559;
+mod eip2930;
+mod eip4844;
+mod eip7702;
+mod envelope;
+mod error;
+#[cfg(test)]
+pub(crate) mod fixtures;
+mod gas;
+#[cfg(feature = "keccak")]
+mod hash;
+mod kind;
+mod legacy;
+mod limits;
+mod pooled;
+mod signature;
+mod summary;
+mod versioned_hash;
+
+pub use access_list::{
+    AccessList, AccessListError, AccessListItem, ACCESS_LIST_ADDRESS_COST,
+    ACCESS_LIST_STORAGE_KEY_COST,
+};
+pub(crate) use batch::decode_transactions_with_spans;
+pub use batch::{
+    decode_transactions, decode_transactions_lenient, decode_transactions_lenient_with_config,
+    decode_transactions_with_config,
+};
+pub use builder::TxBuilder;
+pub use bump::UnsignedTransaction;
+pub use eip1559::Eip1559Transaction;
+pub use eip2930::Eip2930Transaction;
+pub use eip4844::Eip4844Transaction;
+pub use eip7702::{Authorization, Eip7702Transaction};
+pub use envelope::TransactionEnvelope;
+pub use error::{
+    AuthError, AuthorizationField, BatchError, BuildError, FeeBumpError, LimitField, SidecarField,
+    TxError, TxField, ValidationError,
+};
+pub use gas::{
+    INIT_CODE_WORD_GAS, PER_EMPTY_ACCOUNT_COST, TX_BASE_GAS, TX_CREATE_GAS, TX_DATA_NON_ZERO_GAS,
+    TX_DATA_NON_ZERO_GAS_FRONTIER, TX_DATA_ZERO_GAS,
+};
+#[cfg(feature = "keccak")]
+pub use hash::transaction_hash_from_raw;
+pub use kind::TxKind;
+pub use legacy::LegacyTransaction;
+pub use limits::TxLimits;
+pub use pooled::{
+    BlobSidecar, PooledBlobTransaction, PooledTransaction, TxContext, BLOB_SIZE,
+    KZG_COMMITMENT_SIZE, KZG_PROOF_SIZE,
+};
+pub use signature::{split_eip155_v, Signature, SignatureError, MAX_EIP155_CHAIN_ID};
+pub use summary::SummaryOptions;
+pub use versioned_hash::{VersionedHash, VersionedHashError};
+
+/// Highest gas limit allowed by EIP-1985, `2^63 - 1`.
+pub const MAX_GAS_LIMIT: u64 = i64::MAX as u64;
+
+/// Transaction parsing options.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TxConfig {
+    /// Reject scalars with leading zeros, including `0x00` in place of the
+    /// empty string for zero. On by default; turn off to read historical
+    /// data produced by lax encoders.
+    pub strict_scalars: bool,
+    pub limits: TxLimits,
+}
+
+impl Default for TxConfig {
+    fn default() -> Self {
+        Self {
+            strict_scalars: true,
+            limits: TxLimits::default(),
+        }
+    }
+}
+
+impl TxConfig {
+    pub(crate) fn scalar_u64(&self, parsed_data: &ParsedData) -> Result<u64, FieldError> {
+        if self.strict_scalars {
+            parsed_data.as_strict_u64()
+        } else {
+            parsed_data.as_u64()
+        }
+    }
+
+    pub(crate) fn scalar_u256(&self, parsed_data: &ParsedData) -> Result<[u8; 32], FieldError> {
+        if self.strict_scalars {
+            parsed_data.as_strict_u256()
+        } else {
+            parsed_data.as_u256()
+        }
+    }
+}
+
+/// Decode `tx_type || rlp(payload)`, checking the type byte.
+pub(crate) fn decode_typed<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    tx_type: u8,
+) -> Result<ParsedData, TxError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let found = data
+        .read_byte(ext_memory, 0)
+        .map_err(|e| TxError::Decoding(Error::Buffer(e)))?;
+    if found != tx_type {
+        return Err(TxError::UnexpectedType {
+            expected: tx_type,
+            found,
+        });
+    }
+    let mut position = 1;
+    let parsed_data = decode_blob_portion_at_position(data, ext_memory, &mut position)?;
+    if position < data.total_len() {
+        Err(TxError::Decoding(Error::SomeDataUnused { from: position }))
+    } else {
+        Ok(parsed_data)
+    }
+}
+
+/// Decode a bare RLP payload.
+pub(crate) fn decode_payload<B, E>(data: &B, ext_memory: &mut E) -> Result<ParsedData, TxError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    Ok(decode_whole_blob(data, ext_memory)?)
+}
+
+/// Top-level list of transaction fields, checked for arity.
+pub(crate) fn fields<E: ExternalMemory>(
+    parsed_data: &ParsedData,
+    expected: usize,
+) -> Result<&[ParsedData], TxError<E>> {
+    let fields = parsed_data.as_list().map_err(|_| TxError::NotAList)?;
+    if fields.len() != expected {
+        return Err(TxError::WrongFieldCount {
+            expected,
+            found: fields.len(),
+        });
+    }
+    Ok(fields)
+}
+
+pub(crate) fn invalid<E: ExternalMemory>(field: TxField) -> impl FnOnce(FieldError) -> TxError<E> {
+    move |reason| TxError::InvalidField { field, reason }
+}
+
+/// Destination field of transaction of type `tx_type`. Blob and set code
+/// transactions cannot create contracts.
+pub(crate) fn parse_kind<E: ExternalMemory>(
+    tx_type: u8,
+    parsed_data: &ParsedData,
+) -> Result<TxKind, TxError<E>> {
+    let kind = TxKind::parse_from(parsed_data).map_err(invalid(TxField::To))?;
+    let allows_create = !matches!(
+        tx_type,
+        Eip4844Transaction::TX_TYPE | Eip7702Transaction::TX_TYPE
+    );
+    if kind.is_create() && !allows_create {
+        Err(TxError::CreateNotAllowed)
+    } else {
+        Ok(kind)
+    }
+}
+
+/// Trailing `[y_parity, r, s]` fields of a typed transaction.
+pub(crate) fn typed_signature<E: ExternalMemory>(
+    config: &TxConfig,
+    fields: &[ParsedData],
+) -> Result<Signature, TxError<E>> {
+    let y_parity = config
+        .scalar_u64(&fields[0])
+        .map_err(invalid(TxField::YParity))?;
+    if y_parity > 1 {
+        return Err(TxError::InvalidYParity { value: y_parity });
+    }
+    let r = config
+        .scalar_u256(&fields[1])
+        .map_err(invalid(TxField::R))?;
+    let s = config
+        .scalar_u256(&fields[2])
+        .map_err(invalid(TxField::S))?;
+    Signature::from_typed(y_parity, &r, &s).map_err(TxError::InvalidSignature)
+}
+
+/// Typed transaction encoding `tx_type || rlp(fields)`.
+pub(crate) fn encode_typed(tx_type: u8, fields: Vec<ParsedData>) -> Vec<u8> {
+    let list = ParsedData::List(fields);
+    let mut out = Vec::with_capacity(1 + list.encoded_len());
+    out.push(tx_type);
+    list.encode_into(&mut out);
+    out
+}
+
+/// Trailing `[y_parity, r, s]` items of a typed transaction.
+pub(crate) fn encode_typed_signature(signature: &Signature) -> [ParsedData; 3] {
+    [
+        scalar(&[signature.y_parity]),
+        scalar(&signature.r),
+        scalar(&signature.s),
+    ]
+}
+
+/// Upfront cost `gas_limit * gas_price + value`, `None` on 256-bit overflow.
+pub(crate) fn upfront_cost(
+    gas_limit: u64,
+    gas_price: &[u8; 32],
+    value: &[u8; 32],
+) -> Option<[u8; 32]> {
+    let mut limbs = [0u64; 4];
+    let mut carry = 0u128;
+    for (i, limb) in limbs.iter_mut().enumerate().rev() {
+        let price = u64::from_be_bytes(gas_price[8 * i..8 * i + 8].try_into().unwrap());
+        let value = u64::from_be_bytes(value[8 * i..8 * i + 8].try_into().unwrap());
+        let sum = price as u128 * gas_limit as u128 + value as u128 + carry;
+        *limb = sum as u64;
+        carry = sum >> 64;
+    }
+    if carry != 0 {
+        return None;
+    }
+    let mut cost = [0; 32];
+    for (i, limb) in limbs.iter().enumerate() {
+        cost[8 * i..8 * i + 8].copy_from_slice(&limb.to_be_bytes());
+    }
+    Some(cost)
+}
@@ -0,0 +1,696 @@
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use external_memory_tools::{AddressableBuffer, ExternalMemory};
+
+use crate::field::FieldError;
+use crate::span::{span_at_position, ItemKind, ItemSpan};
+use crate::transaction::batch::decode_item;
+use crate::transaction::{
+    Eip4844Transaction, SidecarField, TransactionEnvelope, TxConfig, TxError,
+};
+use crate::{decode_blob_portion_at_position, limit_to, Error};
+
+pub const BLOB_SIZE: usize = 131_072;
+pub const KZG_COMMITMENT_SIZE: usize = 48;
+pub const KZG_PROOF_SIZE: usize = 48;
+
+/// Blob transaction in its network form,
+/// `0x03 || rlp([tx_payload_body, blobs, commitments, proofs])`.
+///
+/// Sidecar items are only located and size-checked; their bytes stay in the
+/// buffer and could be read through the corresponding [`ItemSpan`].
+#[derive(Debug, Eq, PartialEq)]
+pub struct PooledBlobTransaction {
+    pub transaction: Eip4844Transaction,
+    pub body: ItemSpan,
+    pub sidecar: BlobSidecar,
+}
+
+/// Locations of blobs, KZG commitments and KZG proofs. All three lists have
+/// the same length as the transaction `blob_versioned_hashes`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct BlobSidecar {
+    blobs: Vec<ItemSpan>,
+    commitments: Vec<ItemSpan>,
+    proofs: Vec<ItemSpan>,
+}
+
+impl BlobSidecar {
+    pub fn len(&self) -> usize {
+        self.blobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blobs.is_empty()
+    }
+
+    pub fn blob(&self, index: usize) -> Option<ItemSpan> {
+        self.blobs.get(index).copied()
+    }
+
+    pub fn commitment(&self, index: usize) -> Option<ItemSpan> {
+        self.commitments.get(index).copied()
+    }
+
+    pub fn proof(&self, index: usize) -> Option<ItemSpan> {
+        self.proofs.get(index).copied()
+    }
+}
+
+/// Where a transaction comes from, deciding accepted blob transaction
+/// encodings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TxContext {
+    /// Block bodies: blob transactions without sidecars only.
+    Block,
+    /// Mempool gossip: blob transactions with sidecars. Some peers send bare
+    /// blob transactions as well; these are rejected if `strict`.
+    Pooled { strict: bool },
+}
+
+/// Transaction as gossiped between nodes, e.g. in `PooledTransactions`
+/// messages.
+#[derive(Debug, Eq, PartialEq)]
+pub enum PooledTransaction {
+    Canonical(TransactionEnvelope),
+    /// Blob transaction with its sidecar.
+    BlobWrapped(PooledBlobTransaction),
+}
+
+impl PooledTransaction {
+    /// Parse transaction of any supported type, with blob transaction
+    /// encoding checked against `context`.
+    pub fn parse_with_context<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        context: TxContext,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        if is_blob_wrapper(data, ext_memory, 0) {
+            return match context {
+                TxContext::Block => Err(TxError::WrapperNotAllowedHere),
+                TxContext::Pooled { .. } => {
+                    PooledBlobTransaction::parse(data, ext_memory).map(Self::BlobWrapped)
+                }
+            };
+        }
+        let envelope = TransactionEnvelope::parse(data, ext_memory)?;
+        Self::canonical_in_context(envelope, context)
+    }
+
+    /// Transaction in a list located at `span`: legacy transactions are list
+    /// items, typed ones are string items wrapping `type || payload`.
+    pub(crate) fn from_span<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        span: &ItemSpan,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let limited = limit_to(data, span.end)?;
+        if span.kind == ItemKind::String
+            && is_blob_wrapper(&limited, ext_memory, span.payload_start)
+        {
+            return PooledBlobTransaction::parse_at(&limited, ext_memory, span.payload_start)
+                .map(Self::BlobWrapped);
+        }
+        let envelope = decode_item(data, ext_memory, span, &TxConfig::default())?;
+        Self::canonical_in_context(envelope, TxContext::Pooled { strict: false })
+    }
+
+    /// Accept or reject a transaction found in its canonical form.
+    fn canonical_in_context<E: ExternalMemory>(
+        envelope: TransactionEnvelope,
+        context: TxContext,
+    ) -> Result<Self, TxError<E>> {
+        match (context, envelope) {
+            (TxContext::Pooled { strict: true }, TransactionEnvelope::Eip4844(_)) => {
+                Err(TxError::SidecarRequired)
+            }
+            (_, envelope) => Ok(Self::Canonical(envelope)),
+        }
+    }
+}
+
+/// Whether the data at `start` is a blob transaction in its network form,
+/// `0x03 || rlp([tx_payload_body, blobs, commitments, proofs])`.
+///
+/// Only item prefixes are read, so the sidecar is never decoded just to find
+/// out that the transaction is wrapped. Malformed data is reported as not
+/// wrapped and left for the canonical parser to reject.
+pub(crate) fn is_blob_wrapper<B, E>(data: &B, ext_memory: &mut E, start: usize) -> bool
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    match data.read_byte(ext_memory, start) {
+        Ok(Eip4844Transaction::TX_TYPE) => is_wrapper_payload(data, ext_memory, start + 1),
+        _ => false,
+    }
+}
+
+/// Whether the blob transaction payload at `start`, without the type byte,
+/// is the network wrapper list.
+pub(crate) fn is_wrapper_payload<B, E>(data: &B, ext_memory: &mut E, start: usize) -> bool
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    let mut position = start;
+    let payload = match span_at_position(data, ext_memory, &mut position) {
+        Ok(span) if span.kind == ItemKind::List => span,
+        _ => return false,
+    };
+    match payload.list_items(data, ext_memory).as_deref() {
+        Ok([body, _, _, _]) => body.kind == ItemKind::List,
+        _ => false,
+    }
+}
+
+impl PooledBlobTransaction {
+    pub fn parse<B, E>(data: &B, ext_memory: &mut E) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_with_blob_size(data, ext_memory, BLOB_SIZE)
+    }
+
+    /// Parse transaction starting at `start` and ending at the buffer end.
+    pub(crate) fn parse_at<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        start: usize,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_at_with_blob_size(data, ext_memory, start, BLOB_SIZE)
+    }
+
+    fn parse_with_blob_size<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        blob_size: usize,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        Self::parse_at_with_blob_size(data, ext_memory, 0, blob_size)
+    }
+
+    fn parse_at_with_blob_size<B, E>(
+        data: &B,
+        ext_memory: &mut E,
+        start: usize,
+        blob_size: usize,
+    ) -> Result<Self, TxError<E>>
+    where
+        B: AddressableBuffer<E>,
+        E: ExternalMemory,
+    {
+        let found = data
+            .read_byte(ext_memory, start)
+            .map_err(|e| TxError::Decoding(Error::Buffer(e)))?;
+        if found != Eip4844Transaction::TX_TYPE {
+            return Err(TxError::UnexpectedType {
+                expected: Eip4844Transaction::TX_TYPE,
+                found,
+            });
+        }
+
+        let mut position = start + 1;
+        let wrapper = span_at_position(data, ext_memory, &mut position)?;
+        if position < data.total_len() {
+            return Err(TxError::Decoding(Error::SomeDataUnused { from: position }));
+        }
+        if wrapper.kind != ItemKind::List {
+            return Err(TxError::NotAList);
+        }
+        let items = wrapper.list_items(data, ext_memory)?;
+        if items.len() != 4 {
+            return Err(TxError::WrongFieldCount {
+                expected: 4,
+                found: items.len(),
+            });
+        }
+
+        let body = items[0];
+        if body.kind != ItemKind::List {
+            return Err(TxError::NotAList);
+        }
+        let mut body_position = body.start;
+        let parsed_body = decode_blob_portion_at_position(data, ext_memory, &mut body_position)?;
+        let transaction = Eip4844Transaction::from_parsed(&parsed_body, &TxConfig::default())?;
+
+        let sidecar = BlobSidecar {
+            blobs: sidecar_items(data, ext_memory, &items[1], SidecarField::Blobs, blob_size)?,
+            commitments: sidecar_items(
+                data,
+                ext_memory,
+                &items[2],
+                SidecarField::Commitments,
+                KZG_COMMITMENT_SIZE,
+            )?,
+            proofs: sidecar_items(
+                data,
+                ext_memory,
+                &items[3],
+                SidecarField::Proofs,
+                KZG_PROOF_SIZE,
+            )?,
+        };
+
+        let versioned_hashes = transaction.blob_versioned_hashes.len();
+        if sidecar.blobs.len() != versioned_hashes
+            || sidecar.commitments.len() != versioned_hashes
+            || sidecar.proofs.len() != versioned_hashes
+        {
+            return Err(TxError::SidecarLengthMismatch {
+                versioned_hashes,
+                blobs: sidecar.blobs.len(),
+                commitments: sidecar.commitments.len(),
+                proofs: sidecar.proofs.len(),
+            });
+        }
+
+        Ok(Self {
+            transaction,
+            body,
+            sidecar,
+        })
+    }
+}
+
+fn sidecar_items<B, E>(
+    data: &B,
+    ext_memory: &mut E,
+    list: &ItemSpan,
+    field: SidecarField,
+    item_size: usize,
+) -> Result<Vec<ItemSpan>, TxError<E>>
+where
+    B: AddressableBuffer<E>,
+    E: ExternalMemory,
+{
+    if list.kind != ItemKind::List {
+        return Err(TxError::InvalidSidecar {
+            field,
+            index: None,
+            reason: FieldError::ExpectedList,
+        });
+    }
+    let items = list.list_items(data, ext_memory)?;
+    for (index, item) in items.iter().enumerate() {
+        let reason = match item.kind {
+            ItemKind::List => FieldError::ExpectedString,
+            _ if item.payload_len() != item_size => FieldError::WrongLength {
+                expected: item_size,
+                found: item.payload_len(),
+            },
+            _ => continue,
+        };
+        return Err(TxError::InvalidSidecar {
+            field,
+            index: Some(index),
+            reason,
+        });
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{SignableTransaction, TxEip4844};
+    use alloy_eips::eip2930::AccessList;
+    use alloy_primitives::{Address, Bytes, Signature, B256, U256};
+    use alloy_rlp::{Encodable, Header};
+    use std::vec;
+
+    use external_memory_tools::BufferError;
+
+    use super::*;
+    #[cfg(feature = "keccak")]
+    use crate::transaction::transaction_hash_from_raw;
+    use crate::transaction::{VersionedHash, VersionedHashError};
+
+    const SCALED_BLOB_SIZE: usize = 64;
+
+    fn body(blob_count: u8) -> Vec<u8> {
+        body_with_version(blob_count, VersionedHash::VERSION_KZG)
+    }
+
+    fn body_with_version(blob_count: u8, version: u8) -> Vec<u8> {
+        let tx = TxEip4844 {
+            chain_id: 1,
+            nonce: 3,
+            gas_limit: 21_000,
+            max_fee_per_gas: 10,
+            max_priority_fee_per_gas: 1,
+            to: Address::repeat_byte(0x42),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            blob_versioned_hashes: (1..=blob_count)
+                .map(|a| B256::right_padding_from(&[version, a]))
+                .collect(),
+            max_fee_per_blob_gas: 7,
+            input: Bytes::new(),
+        };
+        let signature = Signature::new(U256::from(1), U256::from(2), false);
+        let mut buffer = Vec::new();
+        tx.into_signed(signature).rlp_encode(&mut buffer);
+        buffer
+    }
+
+    fn wrapper(
+        body: &[u8],
+        blobs: &[Vec<u8>],
+        commitments: &[Vec<u8>],
+        proofs: &[Vec<u8>],
+    ) -> Vec<u8> {
+        let mut payload = body.to_vec();
+        for list in [blobs, commitments, proofs] {
+            let items: Vec<Bytes> = list.iter().map(|a| Bytes::copy_from_slice(a)).collect();
+            items.encode(&mut payload);
+        }
+        let mut raw = vec![0x03];
+        Header {
+            list: true,
+            payload_length: payload.len(),
+        }
+        .encode(&mut raw);
+        raw.extend_from_slice(&payload);
+        raw
+    }
+
+    #[test]
+    fn scaled_two_blobs() {
+        let blobs = [vec![0xb1; SCALED_BLOB_SIZE], vec![0xb2; SCALED_BLOB_SIZE]];
+        let commitments = [vec![0xc1; 48], vec![0xc2; 48]];
+        let proofs = [vec![0xd1; 48], vec![0xd2; 48]];
+        let body = body(2);
+        let raw = wrapper(&body, &blobs, &commitments, &proofs);
+
+        let pooled = PooledBlobTransaction::parse_with_blob_size::<&[u8], ()>(
+            &raw.as_ref(),
+            &mut (),
+            SCALED_BLOB_SIZE,
+        )
+        .unwrap();
+        assert_eq!(pooled.transaction.nonce, 3);
+        assert_eq!(pooled.sidecar.len(), 2);
+        assert_eq!(
+            pooled.body.read_raw(&raw.as_ref(), &mut ()).unwrap(),
+            &body[..]
+        );
+        for index in 0..2 {
+            let blob = pooled.sidecar.blob(index).unwrap();
+            assert_eq!(
+                blob.read_payload(&raw.as_ref(), &mut ()).unwrap(),
+                &blobs[index][..]
+            );
+            let commitment = pooled.sidecar.commitment(index).unwrap();
+            assert_eq!(
+                commitment.read_payload(&raw.as_ref(), &mut ()).unwrap(),
+                &commitments[index][..]
+            );
+            let proof = pooled.sidecar.proof(index).unwrap();
+            assert_eq!(
+                proof.read_payload(&raw.as_ref(), &mut ()).unwrap(),
+                &proofs[index][..]
+            );
+        }
+        assert_eq!(pooled.sidecar.blob(2), None);
+    }
+
+    #[test]
+    fn contexts() {
+        let body = body(1);
+        let wrapped = wrapper(
+            &body,
+            &[vec![0xb1; BLOB_SIZE]],
+            &[vec![0xc1; KZG_COMMITMENT_SIZE]],
+            &[vec![0xd1; KZG_PROOF_SIZE]],
+        );
+        let mut canonical = vec![Eip4844Transaction::TX_TYPE];
+        canonical.extend_from_slice(&body);
+        let transaction =
+            Eip4844Transaction::parse::<&[u8], ()>(&canonical.as_ref(), &mut ()).unwrap();
+        let parse = |raw: &[u8], context| {
+            PooledTransaction::parse_with_context::<&[u8], ()>(&raw, &mut (), context)
+        };
+        let lenient = TxContext::Pooled { strict: false };
+        let strict = TxContext::Pooled { strict: true };
+
+        assert_eq!(
+            parse(&canonical, TxContext::Block),
+            Ok(PooledTransaction::Canonical(TransactionEnvelope::Eip4844(
+                transaction.clone()
+            )))
+        );
+        assert_eq!(
+            parse(&wrapped, TxContext::Block),
+            Err(TxError::WrapperNotAllowedHere)
+        );
+        for context in [lenient, strict] {
+            let Ok(PooledTransaction::BlobWrapped(pooled)) = parse(&wrapped, context) else {
+                panic!("expected blob transaction with sidecar");
+            };
+            assert_eq!(pooled.transaction, transaction);
+            assert_eq!(pooled.sidecar.len(), 1);
+        }
+        assert_eq!(
+            parse(&canonical, lenient),
+            Ok(PooledTransaction::Canonical(TransactionEnvelope::Eip4844(
+                transaction
+            )))
+        );
+        assert_eq!(parse(&canonical, strict), Err(TxError::SidecarRequired));
+
+        // Other transaction types are unaffected by the context.
+        let legacy = hex::decode("f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83").unwrap();
+        assert!(matches!(
+            parse(&legacy, strict),
+            Ok(PooledTransaction::Canonical(TransactionEnvelope::Legacy(_)))
+        ));
+    }
+
+    #[test]
+    fn scaled_short_blob() {
+        let raw = wrapper(
+            &body(2),
+            &[vec![0; SCALED_BLOB_SIZE], vec![0; SCALED_BLOB_SIZE - 1]],
+            &[vec![0; 48], vec![0; 48]],
+            &[vec![0; 48], vec![0; 48]],
+        );
+        let error = PooledBlobTransaction::parse_with_blob_size::<&[u8], ()>(
+            &raw.as_ref(),
+            &mut (),
+            SCALED_BLOB_SIZE,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            TxError::InvalidSidecar {
+                field: SidecarField::Blobs,
+                index: Some(1),
+                reason: FieldError::WrongLength {
+                    expected: SCALED_BLOB_SIZE,
+                    found: SCALED_BLOB_SIZE - 1
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn scaled_missing_proof() {
+        let raw = wrapper(
+            &body(2),
+            &[vec![0; SCALED_BLOB_SIZE], vec![0; SCALED_BLOB_SIZE]],
+            &[vec![0; 48], vec![0; 48]],
+            &[vec![0; 48]],
+        );
+        let error = PooledBlobTransaction::parse_with_blob_size::<&[u8], ()>(
+            &raw.as_ref(),
+            &mut (),
+            SCALED_BLOB_SIZE,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            TxError::SidecarLengthMismatch {
+                versioned_hashes: 2,
+                blobs: 2,
+                commitments: 2,
+                proofs: 1
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn full_size_blob() {
+        use alloy_eips::eip4844::{BlobTransactionSidecar, Bytes48};
+
+        let tx = TxEip4844 {
+            chain_id: 1,
+            nonce: 9,
+            gas_limit: 21_000,
+            max_fee_per_gas: 10,
+            max_priority_fee_per_gas: 1,
+            to: Address::repeat_byte(0x42),
+            value: U256::ZERO,
+            access_list: AccessList::default(),
+            blob_versioned_hashes: vec![B256::repeat_byte(0x01)],
+            max_fee_per_blob_gas: 7,
+            input: Bytes::new(),
+        };
+        let mut blob = alloy_eips::eip4844::Blob::ZERO;
+        blob[BLOB_SIZE - 1] = 0xee;
+        let sidecar = BlobTransactionSidecar {
+            blobs: vec![blob],
+            commitments: vec![Bytes48::repeat_byte(0xc0)],
+            proofs: vec![Bytes48::repeat_byte(0xd0)],
+        };
+        let signature = Signature::new(U256::from(1), U256::from(2), false);
+        let mut raw = Vec::new();
+        tx.clone()
+            .with_sidecar(sidecar)
+            .into_signed(signature)
+            .eip2718_encode(&mut raw);
+
+        let pooled = PooledBlobTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(pooled.transaction.nonce, 9);
+        // Signature covers the body only, the sidecar is not in the preimage.
+        assert_eq!(
+            pooled.transaction.signing_payload(),
+            tx.encoded_for_signing()
+        );
+        let blob_span = pooled.sidecar.blob(0).unwrap();
+        assert_eq!(blob_span.payload_len(), BLOB_SIZE);
+        let blob_bytes = blob_span.read_payload(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(blob_bytes[BLOB_SIZE - 1], 0xee);
+        assert_eq!(
+            pooled
+                .sidecar
+                .commitment(0)
+                .unwrap()
+                .read_payload(&raw.as_ref(), &mut ())
+                .unwrap(),
+            [0xc0; 48]
+        );
+    }
+
+    #[test]
+    fn versioned_hash_checked() {
+        let raw = wrapper(
+            &body_with_version(1, 0x02),
+            &[vec![0xb1; SCALED_BLOB_SIZE]],
+            &[vec![0xc1; 48]],
+            &[vec![0xd1; 48]],
+        );
+        assert_eq!(
+            PooledBlobTransaction::parse_with_blob_size::<&[u8], ()>(
+                &raw.as_ref(),
+                &mut (),
+                SCALED_BLOB_SIZE,
+            ),
+            Err(TxError::InvalidVersionedHash {
+                index: 0,
+                reason: VersionedHashError::UnsupportedVersion(0x02)
+            })
+        );
+    }
+
+    /// Counts bytes read from the `blob` range of the buffer.
+    #[derive(Debug, Eq, PartialEq)]
+    struct BlobReads {
+        blob: core::ops::Range<usize>,
+        bytes: usize,
+    }
+
+    impl ExternalMemory for BlobReads {
+        type ExternalMemoryError = external_memory_tools::NoEntries;
+    }
+
+    struct Counting<'a>(&'a [u8]);
+
+    impl<'a> AddressableBuffer<BlobReads> for Counting<'a> {
+        type ReadBuffer = &'a [u8];
+
+        fn total_len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn read_slice(
+            &self,
+            ext_memory: &mut BlobReads,
+            position: usize,
+            slice_len: usize,
+        ) -> Result<Self::ReadBuffer, BufferError<BlobReads>> {
+            let start = position.max(ext_memory.blob.start);
+            let end = (position + slice_len).min(ext_memory.blob.end);
+            ext_memory.bytes += end.saturating_sub(start);
+            self.0.read_slice(ext_memory, position, slice_len)
+        }
+
+        fn limit_length(&self, new_len: usize) -> Result<Self, BufferError<BlobReads>> {
+            AddressableBuffer::<BlobReads>::limit_length(&self.0, new_len).map(Self)
+        }
+    }
+
+    #[test]
+    fn blob_bytes_read_once() {
+        let raw = wrapper(
+            &body(1),
+            &[vec![0xb1; BLOB_SIZE]],
+            &[vec![0xc1; KZG_COMMITMENT_SIZE]],
+            &[vec![0xd1; KZG_PROOF_SIZE]],
+        );
+        let blob = PooledBlobTransaction::parse::<&[u8], ()>(&raw.as_ref(), &mut ())
+            .unwrap()
+            .sidecar
+            .blob(0)
+            .unwrap();
+        let data = Counting(&raw);
+        let mut reads = BlobReads {
+            blob: blob.payload_start..blob.end,
+            bytes: 0,
+        };
+
+        // Locating the wrapper and parsing the body leave the blob untouched.
+        let context = TxContext::Pooled { strict: true };
+        let Ok(PooledTransaction::BlobWrapped(pooled)) =
+            PooledTransaction::parse_with_context(&data, &mut reads, context)
+        else {
+            panic!("expected blob transaction with sidecar");
+        };
+        assert_eq!(
+            PooledTransaction::parse_with_context(&data, &mut reads, TxContext::Block),
+            Err(TxError::WrapperNotAllowedHere)
+        );
+        assert_eq!(
+            Eip4844Transaction::parse(&data, &mut reads),
+            Err(TxError::WrapperNotAllowedHere)
+        );
+        #[cfg(feature = "keccak")]
+        transaction_hash_from_raw(&data, &mut reads).unwrap();
+        assert_eq!(reads.bytes, 0);
+
+        let blob = pooled.sidecar.blob(0).unwrap();
+        let payload = blob.read_payload(&data, &mut reads).unwrap();
+        assert_eq!(payload, &[0xb1; BLOB_SIZE][..]);
+        assert_eq!(reads.bytes, BLOB_SIZE);
+    }
+}
@@ -0,0 +1,324 @@
+/// Order of the secp256k1 group, big-endian.
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// `SECP256K1_ORDER / 2`, largest `s` allowed by EIP-2.
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// Transaction signature with `v` reduced to a recovery id.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Signature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub y_parity: u8,
+}
+
+/// Errors in building a [`Signature`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SignatureError {
+    /// `v` is neither 27/28 nor in EIP-155 form `35 + 2 * chain_id + parity`.
+    InvalidV(u64),
+    /// `s` above half of the group order, forbidden by EIP-2.
+    HighS,
+    /// Typed transaction recovery id other than 0 or 1.
+    InvalidYParity(u64),
+    ROutOfRange,
+    RTooLong {
+        found: usize,
+    },
+    SOutOfRange,
+    STooLong {
+        found: usize,
+    },
+    ZeroR,
+    ZeroS,
+}
+
+/// Largest EIP-155 chain id with `v` of both recovery ids fitting into `u64`.
+pub const MAX_EIP155_CHAIN_ID: u64 = (u64::MAX - 36) / 2;
+
+/// Split `v` of a legacy transaction into recovery id and chain id:
+/// `v` is `27 + recovery_id` before EIP-155, with no chain id, and
+/// `35 + 2 * chain_id + recovery_id` since, with chain id at most
+/// [`MAX_EIP155_CHAIN_ID`].
+pub fn split_eip155_v(v: u64) -> Result<(u8, Option<u64>), SignatureError> {
+    match v {
+        27 | 28 => Ok(((v - 27) as u8, None)),
+        35.. if (v - 35) / 2 <= MAX_EIP155_CHAIN_ID => {
+            Ok((((v - 35) % 2) as u8, Some((v - 35) / 2)))
+        }
+        _ => Err(SignatureError::InvalidV(v)),
+    }
+}
+
+impl Signature {
+    /// Pre-EIP-155 signature, `v` is 27 or 28.
+    pub fn from_legacy(v: u64, r: &[u8], s: &[u8]) -> Result<Self, SignatureError> {
+        match v {
+            27 | 28 => Self::new((v - 27) as u8, r, s),
+            _ => Err(SignatureError::InvalidV(v)),
+        }
+    }
+
+    /// EIP-155 signature, `v` is `35 + 2 * chain_id + parity`. Returns the
+    /// chain id alongside.
+    pub fn from_eip155(v: u64, r: &[u8], s: &[u8]) -> Result<(Self, u64), SignatureError> {
+        match split_eip155_v(v)? {
+            (y_parity, Some(chain_id)) => Ok((Self::new(y_parity, r, s)?, chain_id)),
+            (_, None) => Err(SignatureError::InvalidV(v)),
+        }
+    }
+
+    /// Legacy transaction signature of either form, see [`split_eip155_v`].
+    /// Returns the chain id alongside, `None` before EIP-155.
+    pub fn from_v(v: u64, r: &[u8], s: &[u8]) -> Result<(Self, Option<u64>), SignatureError> {
+        let (y_parity, chain_id) = split_eip155_v(v)?;
+        Ok((Self::new(y_parity, r, s)?, chain_id))
+    }
+
+    /// Typed transaction signature, `y_parity` is 0 or 1.
+    pub fn from_typed(y_parity: u64, r: &[u8], s: &[u8]) -> Result<Self, SignatureError> {
+        match y_parity {
+            0 | 1 => Self::new(y_parity as u8, r, s),
+            _ => Err(SignatureError::InvalidYParity(y_parity)),
+        }
+    }
+
+    /// Structural checks preceding recovery: `r` and `s` are non-zero, below
+    /// the group order, and `s` is in the lower half of it.
+    pub fn validate(&self) -> Result<(), SignatureError> {
+        self.validate_with(true)
+    }
+
+    /// Same as [`validate`](Self::validate), but without the low `s` rule,
+    /// for transactions signed before Homestead.
+    pub fn validate_lenient(&self) -> Result<(), SignatureError> {
+        self.validate_with(false)
+    }
+
+    fn validate_with(&self, low_s: bool) -> Result<(), SignatureError> {
+        if self.r == [0; 32] {
+            return Err(SignatureError::ZeroR);
+        }
+        if self.s == [0; 32] {
+            return Err(SignatureError::ZeroS);
+        }
+        if self.r >= SECP256K1_ORDER {
+            return Err(SignatureError::ROutOfRange);
+        }
+        if self.s >= SECP256K1_ORDER {
+            return Err(SignatureError::SOutOfRange);
+        }
+        if low_s && self.s > SECP256K1_HALF_ORDER {
+            return Err(SignatureError::HighS);
+        }
+        Ok(())
+    }
+
+    fn new(y_parity: u8, r: &[u8], s: &[u8]) -> Result<Self, SignatureError> {
+        Ok(Self {
+            r: left_pad(r).ok_or(SignatureError::RTooLong { found: r.len() })?,
+            s: left_pad(s).ok_or(SignatureError::STooLong { found: s.len() })?,
+            y_parity,
+        })
+    }
+}
+
+fn left_pad(bytes: &[u8]) -> Option<[u8; 32]> {
+    if bytes.len() > 32 {
+        return None;
+    }
+    let mut out = [0; 32];
+    out[32 - bytes.len()..].copy_from_slice(bytes);
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+
+    use super::*;
+
+    /// Signature by a fixed test key, as alloy-signer produces it.
+    fn signed(hash: u8) -> alloy_primitives::Signature {
+        let signer = PrivateKeySigner::from_bytes(&B256::repeat_byte(0x46)).unwrap();
+        signer.sign_hash_sync(&B256::repeat_byte(hash)).unwrap()
+    }
+
+    fn expected(signature: &alloy_primitives::Signature) -> Signature {
+        Signature {
+            r: signature.r().to_be_bytes(),
+            s: signature.s().to_be_bytes(),
+            y_parity: signature.v() as u8,
+        }
+    }
+
+    #[test]
+    fn legacy() {
+        for hash in 0..8 {
+            let signature = signed(hash);
+            let v = 27 + signature.v() as u64;
+            let r = signature.r().to_be_bytes::<32>();
+            let s = signature.s().to_be_bytes::<32>();
+            assert_eq!(Signature::from_legacy(v, &r, &s), Ok(expected(&signature)));
+        }
+        assert_eq!(
+            Signature::from_legacy(37, &[1], &[1]),
+            Err(SignatureError::InvalidV(37))
+        );
+        assert_eq!(
+            Signature::from_legacy(1, &[1], &[1]),
+            Err(SignatureError::InvalidV(1))
+        );
+    }
+
+    #[test]
+    fn eip155() {
+        for (hash, chain_id) in [(0, 1u64), (1, 1), (2, 137), (3, 11_155_111)] {
+            let signature = signed(hash);
+            let v = 35 + 2 * chain_id + signature.v() as u64;
+            let r = signature.r().to_be_bytes::<32>();
+            let s = signature.s().to_be_bytes::<32>();
+            assert_eq!(
+                Signature::from_eip155(v, &r, &s),
+                Ok((expected(&signature), chain_id))
+            );
+        }
+        for v in [0, 27, 28, 34] {
+            assert_eq!(
+                Signature::from_eip155(v, &[1], &[1]),
+                Err(SignatureError::InvalidV(v))
+            );
+        }
+    }
+
+    #[test]
+    fn split_v() {
+        assert_eq!(split_eip155_v(27), Ok((0, None)));
+        assert_eq!(split_eip155_v(28), Ok((1, None)));
+        assert_eq!(split_eip155_v(35), Ok((0, Some(0))));
+        assert_eq!(split_eip155_v(37), Ok((0, Some(1))));
+        assert_eq!(split_eip155_v(38), Ok((1, Some(1))));
+        assert_eq!(split_eip155_v(310), Ok((1, Some(137))));
+        assert_eq!(
+            split_eip155_v(u64::MAX - 1),
+            Ok((1, Some(MAX_EIP155_CHAIN_ID)))
+        );
+        assert_eq!(
+            split_eip155_v(u64::MAX),
+            Err(SignatureError::InvalidV(u64::MAX))
+        );
+        for v in [0, 1, 26, 29, 34] {
+            assert_eq!(split_eip155_v(v), Err(SignatureError::InvalidV(v)));
+        }
+
+        let signature = signed(0);
+        let r = signature.r().to_be_bytes::<32>();
+        let s = signature.s().to_be_bytes::<32>();
+        let y_parity = signature.v() as u64;
+        assert_eq!(
+            Signature::from_v(27 + y_parity, &r, &s),
+            Ok((expected(&signature), None))
+        );
+        assert_eq!(
+            Signature::from_v(37 + y_parity, &r, &s),
+            Ok((expected(&signature), Some(1)))
+        );
+    }
+
+    #[test]
+    fn typed() {
+        for hash in 0..8 {
+            let signature = signed(hash);
+            let r = signature.r().to_be_bytes::<32>();
+            let s = signature.s().to_be_bytes::<32>();
+            assert_eq!(
+                Signature::from_typed(signature.v() as u64, &r, &s),
+                Ok(expected(&signature))
+            );
+        }
+        for y_parity in [2, 27, 28] {
+            assert_eq!(
+                Signature::from_typed(y_parity, &[1], &[1]),
+                Err(SignatureError::InvalidYParity(y_parity))
+            );
+        }
+    }
+
+    #[test]
+    fn short_and_long_scalars() {
+        let signature = Signature::from_typed(1, &[0x01, 0x02], &[]).unwrap();
+        let mut r = [0; 32];
+        r[30..].copy_from_slice(&[0x01, 0x02]);
+        assert_eq!(signature.r, r);
+        assert_eq!(signature.s, [0; 32]);
+
+        assert_eq!(
+            Signature::from_typed(0, &[1; 33], &[1]),
+            Err(SignatureError::RTooLong { found: 33 })
+        );
+        assert_eq!(
+            Signature::from_typed(0, &[1], &[1; 33]),
+            Err(SignatureError::STooLong { found: 33 })
+        );
+    }
+
+    #[test]
+    fn validate() {
+        // Signature of the EIP-155 example transaction.
+        let r = hex::decode("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276")
+            .unwrap();
+        let s = hex::decode("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83")
+            .unwrap();
+        let (signature, _) = Signature::from_eip155(37, &r, &s).unwrap();
+        assert_eq!(signature.validate(), Ok(()));
+
+        let half = Signature {
+            s: SECP256K1_HALF_ORDER,
+            ..signature
+        };
+        assert_eq!(half.validate(), Ok(()));
+
+        let mut above_half_s = SECP256K1_HALF_ORDER;
+        above_half_s[31] += 1;
+        let above_half = Signature {
+            s: above_half_s,
+            ..signature
+        };
+        assert_eq!(above_half.validate(), Err(SignatureError::HighS));
+        assert_eq!(above_half.validate_lenient(), Ok(()));
+
+        let order = Signature {
+            s: SECP256K1_ORDER,
+            ..signature
+        };
+        assert_eq!(order.validate_lenient(), Err(SignatureError::SOutOfRange));
+
+        let zero_r = Signature {
+            r: [0; 32],
+            ..signature
+        };
+        assert_eq!(zero_r.validate(), Err(SignatureError::ZeroR));
+
+        let mut long_r = [0; 33];
+        long_r[1..].copy_from_slice(&r);
+        assert_eq!(
+            Signature::from_eip155(37, &long_r, &s),
+            Err(SignatureError::RTooLong { found: 33 })
+        );
+    }
+
+    #[test]
+    fn signer_output_is_valid() {
+        for hash in 0..8 {
+            assert_eq!(expected(&signed(hash)).validate(), Ok(()));
+        }
+    }
+}
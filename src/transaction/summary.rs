@@ -0,0 +1,374 @@
+use core::fmt::{self, Write};
+
+use crate::calldata::{recognize_erc20, Erc20Call};
+use crate::transaction::{TransactionEnvelope, TxKind};
+
+/// Decimals of ether in wei.
+const ETH_DECIMALS: usize = 18;
+
+/// Decimals of gwei in wei.
+const GWEI_DECIMALS: usize = 9;
+
+/// Enough digits for `2^256 - 1` and the leading zero of a fraction.
+const MAX_DIGITS: usize = 80;
+
+/// Rendering options for [`TransactionEnvelope::summary`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SummaryOptions {
+    /// Wrap lines longer than this many characters, `None` for no wrapping.
+    pub line_width: Option<usize>,
+}
+
+impl TransactionEnvelope {
+    /// Render the fields to verify before signing, one per line.
+    ///
+    /// Values are in ETH, fees in gwei, ERC-20 amounts in token base units
+    /// as the token decimals are not known here.
+    pub fn summary(&self, opts: &SummaryOptions, out: &mut impl Write) -> fmt::Result {
+        let mut w = Wrapped {
+            out,
+            line_width: opts.line_width,
+            column: 0,
+        };
+        let type_name = match self {
+            Self::Legacy(_) => "legacy",
+            Self::Eip2930(_) => "EIP-2930",
+            Self::Eip1559(_) => "EIP-1559",
+            Self::Eip4844(_) => "EIP-4844",
+            Self::Eip7702(_) => "EIP-7702",
+        };
+        writeln!(w, "Type: {type_name}")?;
+        match self.chain_id() {
+            Some(chain_id) => writeln!(w, "Chain: {chain_id}")?,
+            None => writeln!(w, "Chain: any (no replay protection)")?,
+        }
+        writeln!(w, "To: {}", self.to())?;
+        w.write_str("Value: ")?;
+        write_units(&mut w, &self.value(), ETH_DECIMALS)?;
+        writeln!(w, " ETH")?;
+        let (fee_label, fee) = match self {
+            Self::Legacy(tx) => ("Gas price", tx.gas_price),
+            Self::Eip2930(tx) => ("Gas price", tx.gas_price),
+            Self::Eip1559(tx) => ("Max fee", tx.max_fee_per_gas),
+            Self::Eip4844(tx) => ("Max fee", tx.max_fee_per_gas),
+            Self::Eip7702(tx) => ("Max fee", tx.max_fee_per_gas),
+        };
+        write!(w, "{fee_label}: ")?;
+        write_units(&mut w, &fee, GWEI_DECIMALS)?;
+        writeln!(w, " gwei")?;
+        writeln!(w, "Gas limit: {}", self.gas_limit())?;
+        writeln!(w, "Nonce: {}", self.nonce())?;
+        write_calldata(&mut w, self.data())?;
+        match self {
+            Self::Eip4844(tx) => writeln!(w, "Blobs: {}", tx.blob_versioned_hashes.len()),
+            Self::Eip7702(tx) => writeln!(w, "Authorizations: {}", tx.authorization_list.len()),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn write_calldata<W: Write>(w: &mut W, data: &[u8]) -> fmt::Result {
+    let Some(selector) = data.first_chunk::<4>() else {
+        if data.is_empty() {
+            return writeln!(w, "Data: none");
+        }
+        return writeln!(w, "Data: {} bytes", data.len());
+    };
+    write!(w, "Data: {} bytes, selector 0x", data.len())?;
+    selector.iter().try_for_each(|a| write!(w, "{a:02x}"))?;
+    writeln!(w)?;
+    let (action, to, amount) = match recognize_erc20(data) {
+        Some(Erc20Call::Transfer { to, amount }) => ("transfer", to, amount),
+        Some(Erc20Call::Approve { spender, amount }) => ("approve", spender, amount),
+        Some(Erc20Call::TransferFrom { from, to, amount }) => {
+            writeln!(w, "ERC-20 from: {}", TxKind::Call(from))?;
+            ("transfer", to, amount)
+        }
+        None => return Ok(()),
+    };
+    write!(w, "ERC-20 {action}: ")?;
+    write_units(w, &amount, 0)?;
+    let preposition = if action == "approve" { "for" } else { "to" };
+    writeln!(w, " {preposition} {}", TxKind::Call(to))
+}
+
+/// Write a big-endian `uint256` scaled down by `10^decimals`, without
+/// trailing fraction zeros.
+fn write_units<W: Write>(w: &mut W, value: &[u8; 32], decimals: usize) -> fmt::Result {
+    // Least significant digit first.
+    let mut digits = [0u8; MAX_DIGITS];
+    let mut len = 0;
+    let mut rest = *value;
+    loop {
+        let mut remainder = 0u16;
+        for byte in rest.iter_mut() {
+            let current = (remainder << 8) | *byte as u16;
+            *byte = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits[len] = remainder as u8;
+        len += 1;
+        if rest == [0; 32] {
+            break;
+        }
+    }
+    let len = len.max(decimals + 1);
+    for digit in digits[decimals..len].iter().rev() {
+        w.write_char((b'0' + digit) as char)?;
+    }
+    if let Some(start) = digits[..decimals].iter().position(|a| *a != 0) {
+        w.write_char('.')?;
+        for digit in digits[start..decimals].iter().rev() {
+            w.write_char((b'0' + digit) as char)?;
+        }
+    }
+    Ok(())
+}
+
+/// Breaks lines longer than `line_width` characters.
+struct Wrapped<'a, W: Write> {
+    out: &'a mut W,
+    line_width: Option<usize>,
+    column: usize,
+}
+
+impl<W: Write> Write for Wrapped<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        if c == '\n' {
+            self.column = 0;
+        } else {
+            if self.line_width.is_some_and(|width| self.column >= width) {
+                self.out.write_char('\n')?;
+                self.column = 0;
+            }
+            self.column += 1;
+        }
+        self.out.write_char(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, vec, vec::Vec};
+
+    use super::*;
+    use crate::calldata::ERC20_TRANSFER;
+    use crate::transaction::{Authorization, Signature, TxBuilder};
+
+    fn signature() -> Signature {
+        Signature {
+            r: [0x01; 32],
+            s: [0x01; 32],
+            y_parity: 0,
+        }
+    }
+
+    fn scalar(value: u128) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[16..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    /// Addresses of digits only render the same with and without `keccak`.
+    const TO: TxKind = TxKind::Call([0x11; 20]);
+
+    fn render(tx: &TransactionEnvelope, line_width: Option<usize>) -> String {
+        let mut out = String::new();
+        tx.summary(&SummaryOptions { line_width }, &mut out)
+            .unwrap();
+        out
+    }
+
+    fn transfer_calldata() -> Vec<u8> {
+        let mut data = ERC20_TRANSFER.to_vec();
+        data.extend_from_slice(&[0; 12]);
+        data.extend_from_slice(&[0x22; 20]);
+        data.extend_from_slice(&scalar(250_000_000));
+        data
+    }
+
+    #[test]
+    fn units() {
+        let cases: [(u128, usize, &str); 6] = [
+            (0, 18, "0"),
+            (1, 18, "0.000000000000000001"),
+            (1_500_000_000_000_000_000, 18, "1.5"),
+            (20_000_000_000, 9, "20"),
+            (1_234_567_890, 9, "1.23456789"),
+            (250_000_000, 0, "250000000"),
+        ];
+        for (value, decimals, expected) in cases {
+            let mut out = String::new();
+            write_units(&mut out, &scalar(value), decimals).unwrap();
+            assert_eq!(out, expected);
+        }
+        let mut out = String::new();
+        write_units(&mut out, &[0xff; 32], 18).unwrap();
+        assert_eq!(
+            out,
+            "115792089237316195423570985008687907853269984665640564039457.584007913129639935"
+        );
+    }
+
+    #[test]
+    fn legacy() {
+        let tx = TxBuilder::legacy()
+            .nonce(9)
+            .gas_price(scalar(20_000_000_000))
+            .gas_limit(21_000)
+            .to(TO)
+            .value(scalar(1_000_000_000_000_000_000))
+            .build_signed(signature())
+            .unwrap();
+        assert_eq!(
+            render(&tx, None),
+            "Type: legacy\n\
+             Chain: any (no replay protection)\n\
+             To: 0x1111111111111111111111111111111111111111\n\
+             Value: 1 ETH\n\
+             Gas price: 20 gwei\n\
+             Gas limit: 21000\n\
+             Nonce: 9\n\
+             Data: none\n"
+        );
+    }
+
+    #[test]
+    fn eip2930() {
+        let tx = TxBuilder::eip2930()
+            .chain_id(1)
+            .nonce(0)
+            .gas_price(scalar(1_500_000_000))
+            .gas_limit(100_000)
+            .to(TxKind::Create)
+            .data(vec![0x60, 0x80])
+            .build_signed(signature())
+            .unwrap();
+        assert_eq!(
+            render(&tx, None),
+            "Type: EIP-2930\n\
+             Chain: 1\n\
+             To: CREATE\n\
+             Value: 0 ETH\n\
+             Gas price: 1.5 gwei\n\
+             Gas limit: 100000\n\
+             Nonce: 0\n\
+             Data: 2 bytes\n"
+        );
+    }
+
+    #[test]
+    fn eip1559() {
+        let tx = TxBuilder::eip1559()
+            .chain_id(1)
+            .nonce(3)
+            .max_priority_fee_per_gas(scalar(1_000_000_000))
+            .max_fee_per_gas(scalar(30_000_000_000))
+            .gas_limit(60_000)
+            .to(TO)
+            .data(transfer_calldata())
+            .build_signed(signature())
+            .unwrap();
+        assert_eq!(
+            render(&tx, None),
+            "Type: EIP-1559\n\
+             Chain: 1\n\
+             To: 0x1111111111111111111111111111111111111111\n\
+             Value: 0 ETH\n\
+             Max fee: 30 gwei\n\
+             Gas limit: 60000\n\
+             Nonce: 3\n\
+             Data: 68 bytes, selector 0xa9059cbb\n\
+             ERC-20 transfer: 250000000 to 0x2222222222222222222222222222222222222222\n"
+        );
+    }
+
+    #[test]
+    fn eip4844() {
+        let tx = TxBuilder::eip4844()
+            .chain_id(11_155_111)
+            .nonce(1)
+            .max_priority_fee_per_gas(scalar(1))
+            .max_fee_per_gas(scalar(2))
+            .gas_limit(21_000)
+            .to(TO)
+            .max_fee_per_blob_gas(scalar(3))
+            .blob_versioned_hashes(vec![[0x01; 32], [0x01; 32]])
+            .build_signed(signature())
+            .unwrap();
+        assert_eq!(
+            render(&tx, None),
+            "Type: EIP-4844\n\
+             Chain: 11155111\n\
+             To: 0x1111111111111111111111111111111111111111\n\
+             Value: 0 ETH\n\
+             Max fee: 0.000000002 gwei\n\
+             Gas limit: 21000\n\
+             Nonce: 1\n\
+             Data: none\n\
+             Blobs: 2\n"
+        );
+    }
+
+    #[test]
+    fn eip7702() {
+        let authorization = Authorization {
+            chain_id: [0; 32],
+            address: [0xaa; 20],
+            nonce: 0,
+            y_parity: 0,
+            r: [0x01; 32],
+            s: [0x01; 32],
+        };
+        let tx = TxBuilder::eip7702()
+            .chain_id(1)
+            .nonce(2)
+            .max_priority_fee_per_gas(scalar(1))
+            .max_fee_per_gas(scalar(1_000_000_000))
+            .gas_limit(80_000)
+            .to(TO)
+            .value(scalar(10_000_000_000_000_000))
+            .data(vec![0xde, 0xad])
+            .authorization_list(vec![authorization])
+            .build_signed(signature())
+            .unwrap();
+        assert_eq!(
+            render(&tx, None),
+            "Type: EIP-7702\n\
+             Chain: 1\n\
+             To: 0x1111111111111111111111111111111111111111\n\
+             Value: 0.01 ETH\n\
+             Max fee: 1 gwei\n\
+             Gas limit: 80000\n\
+             Nonce: 2\n\
+             Data: 2 bytes\n\
+             Authorizations: 1\n"
+        );
+    }
+
+    #[test]
+    fn line_width() {
+        let tx = TxBuilder::eip1559()
+            .chain_id(1)
+            .nonce(3)
+            .max_priority_fee_per_gas(scalar(1))
+            .max_fee_per_gas(scalar(1))
+            .gas_limit(21_000)
+            .to(TO)
+            .build_signed(signature())
+            .unwrap();
+        let rendered = render(&tx, Some(20));
+        assert!(rendered.lines().all(|line| line.chars().count() <= 20));
+        assert!(rendered.starts_with(
+            "Type: EIP-1559\n\
+             Chain: 1\n\
+             To: 0x11111111111111\n\
+             11111111111111111111\n\
+             111111\n"
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::field::FieldError;
+use crate::ParsedData;
+
+/// Versioned hash of a blob KZG commitment, `version || sha256(commitment)[1..]`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct VersionedHash(pub [u8; 32]);
+
+/// Errors in reading a [`VersionedHash`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum VersionedHashError {
+    /// Item is not a 32 byte string.
+    InvalidField(FieldError),
+    /// Version byte other than [`VersionedHash::VERSION_KZG`].
+    UnsupportedVersion(u8),
+}
+
+impl VersionedHash {
+    /// Version of hashes of KZG commitments, the only one defined by EIP-4844.
+    pub const VERSION_KZG: u8 = 0x01;
+
+    /// Check the version byte.
+    pub fn new(hash: [u8; 32]) -> Result<Self, VersionedHashError> {
+        match hash[0] {
+            Self::VERSION_KZG => Ok(Self(hash)),
+            version => Err(VersionedHashError::UnsupportedVersion(version)),
+        }
+    }
+
+    /// Check length and version byte of a versioned hash item.
+    pub fn from_parsed(parsed_data: &ParsedData) -> Result<Self, VersionedHashError> {
+        parsed_data
+            .as_fixed_bytes::<32>()
+            .map_err(VersionedHashError::InvalidField)
+            .and_then(Self::new)
+    }
+
+    pub fn version(&self) -> u8 {
+        self.0[0]
+    }
+
+    /// Last 31 bytes of the commitment SHA-256 digest.
+    pub fn commitment_digest(&self) -> [u8; 31] {
+        let mut digest = [0; 31];
+        digest.copy_from_slice(&self.0[1..]);
+        digest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+
+    #[test]
+    fn versioned_hash() {
+        let mut raw = [0x5a; 32];
+        raw[0] = 0x01;
+        let hash = VersionedHash::from_parsed(&ParsedData::String(raw.to_vec())).unwrap();
+        assert_eq!(hash.version(), 0x01);
+        assert_eq!(hash.commitment_digest(), [0x5a; 31]);
+
+        assert_eq!(
+            VersionedHash::from_parsed(&ParsedData::String(vec![0x02; 32])),
+            Err(VersionedHashError::UnsupportedVersion(0x02))
+        );
+        assert_eq!(
+            VersionedHash::from_parsed(&ParsedData::String(vec![0x01; 31])),
+            Err(VersionedHashError::InvalidField(FieldError::WrongLength {
+                expected: 32,
+                found: 31
+            }))
+        );
+    }
+}
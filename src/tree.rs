@@ -0,0 +1,376 @@
+//! Traversal helpers over decoded [`ParsedData`] trees.
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use core::ops::ControlFlow;
+
+use crate::encode::leaf;
+use crate::ParsedData;
+
+impl ParsedData {
+    /// Rebuild the tree, replacing every string leaf with `f(leaf)`.
+    ///
+    /// Single byte items are passed to `f` as one byte strings. Results are
+    /// stored the way the decoder would produce them: a single byte below
+    /// `0x80` becomes [`ParsedData::Byte`].
+    pub fn map_strings<F: FnMut(&[u8]) -> Vec<u8>>(&self, mut f: F) -> ParsedData {
+        self.map_strings_inner(&mut f)
+    }
+
+    /// Call `f` on every string leaf, in order of appearance.
+    pub fn visit_strings<F: FnMut(&[u8])>(&self, mut f: F) {
+        self.visit_strings_inner(&mut f)
+    }
+
+    /// Number of [`ParsedData::Byte`] and [`ParsedData::String`] nodes.
+    pub fn count_leaves(&self) -> usize {
+        match self {
+            ParsedData::List(a) => a.iter().map(ParsedData::count_leaves).sum(),
+            _ => 1,
+        }
+    }
+
+    /// Number of all nodes, lists included.
+    pub fn count_nodes(&self) -> usize {
+        match self {
+            ParsedData::List(a) => 1 + a.iter().map(ParsedData::count_nodes).sum::<usize>(),
+            _ => 1,
+        }
+    }
+
+    /// Turn single byte strings below `0x80` into [`ParsedData::Byte`], as
+    /// [`CanonicalPolicy::Normalize`](crate::CanonicalPolicy::Normalize)
+    /// does on decoding. The result encodes canonically; canonical trees
+    /// are returned unchanged.
+    pub fn canonicalize(self) -> ParsedData {
+        match self {
+            ParsedData::List(a) => {
+                ParsedData::List(a.into_iter().map(ParsedData::canonicalize).collect())
+            }
+            ParsedData::String(a) => leaf(a),
+            byte => byte,
+        }
+    }
+
+    fn map_strings_inner<F: FnMut(&[u8]) -> Vec<u8>>(&self, f: &mut F) -> ParsedData {
+        match self {
+            ParsedData::Byte(a) => leaf(f(core::slice::from_ref(a))),
+            ParsedData::List(a) => {
+                ParsedData::List(a.iter().map(|item| item.map_strings_inner(f)).collect())
+            }
+            ParsedData::String(a) => leaf(f(a)),
+        }
+    }
+
+    fn visit_strings_inner<F: FnMut(&[u8])>(&self, f: &mut F) {
+        match self {
+            ParsedData::Byte(a) => f(core::slice::from_ref(a)),
+            ParsedData::List(a) => a.iter().for_each(|item| item.visit_strings_inner(f)),
+            ParsedData::String(a) => f(a),
+        }
+    }
+}
+
+/// First difference between two trees, see [`ParsedData::diff`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiffPath {
+    /// List indices leading to the differing nodes, as in
+    /// [`ParsedData::path`].
+    pub path: Vec<usize>,
+    pub kind: DiffKind,
+}
+
+/// How the nodes at [`DiffPath::path`] differ.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DiffKind {
+    /// Nodes are of different [`ParsedData`] variants.
+    Variant,
+    /// Lists with equal common elements, but of different lengths.
+    ListLength { left: usize, right: usize },
+    /// Strings with equal common bytes, but of different lengths.
+    StringLength { left: usize, right: usize },
+    /// Strings or bytes differ first at `offset`.
+    Byte { offset: usize, left: u8, right: u8 },
+}
+
+impl<S: AsRef<[u8]>> ParsedData<S> {
+    /// Locate the first difference from `other`, `None` if the trees are
+    /// equal.
+    ///
+    /// Lists are compared element by element before their lengths, so the
+    /// deepest first mismatch is reported.
+    pub fn diff<T: AsRef<[u8]>>(&self, other: &ParsedData<T>) -> Option<DiffPath> {
+        let mut diff = self.diff_inner(other)?;
+        diff.path.reverse();
+        Some(diff)
+    }
+
+    /// Difference with the path collected in reverse order.
+    fn diff_inner<T: AsRef<[u8]>>(&self, other: &ParsedData<T>) -> Option<DiffPath> {
+        let kind = match (self, other) {
+            (ParsedData::Byte(left), ParsedData::Byte(right)) => bytes_diff(&[*left], &[*right])?,
+            (ParsedData::String(left), ParsedData::String(right)) => {
+                bytes_diff(left.as_ref(), right.as_ref())?
+            }
+            (ParsedData::List(left), ParsedData::List(right)) => {
+                for (index, (left_item, right_item)) in left.iter().zip(right.iter()).enumerate() {
+                    if let Some(mut diff) = left_item.diff_inner(right_item) {
+                        diff.path.push(index);
+                        return Some(diff);
+                    }
+                }
+                if left.len() == right.len() {
+                    return None;
+                }
+                DiffKind::ListLength {
+                    left: left.len(),
+                    right: right.len(),
+                }
+            }
+            _ => DiffKind::Variant,
+        };
+        Some(DiffPath {
+            path: Vec::new(),
+            kind,
+        })
+    }
+}
+
+fn bytes_diff(left: &[u8], right: &[u8]) -> Option<DiffKind> {
+    if let Some((offset, (left, right))) = left
+        .iter()
+        .zip(right.iter())
+        .enumerate()
+        .find(|(_, (left, right))| left != right)
+    {
+        return Some(DiffKind::Byte {
+            offset,
+            left: *left,
+            right: *right,
+        });
+    }
+    if left.len() == right.len() {
+        None
+    } else {
+        Some(DiffKind::StringLength {
+            left: left.len(),
+            right: right.len(),
+        })
+    }
+}
+
+impl<S> ParsedData<S> {
+    /// Node reached by following `indices` into nested lists, e.g.
+    /// `block.path(&[0, 8])` for the number in the block header.
+    ///
+    /// `None` if an index is out of range or a step is not a list. Empty
+    /// `indices` give the node itself.
+    pub fn path(&self, indices: &[usize]) -> Option<&ParsedData<S>> {
+        indices.iter().try_fold(self, |node, index| match node {
+            ParsedData::List(a) => a.get(*index),
+            _ => None,
+        })
+    }
+
+    /// Pre-order traversal calling `f` on every node with its depth, the
+    /// node itself being at depth `0`. Stops at the first
+    /// [`ControlFlow::Break`], which is then returned.
+    pub fn walk<F: FnMut(&ParsedData<S>, usize) -> ControlFlow<()>>(
+        &self,
+        mut f: F,
+    ) -> ControlFlow<()> {
+        self.walk_inner(&mut f, 0)
+    }
+
+    fn walk_inner<F: FnMut(&ParsedData<S>, usize) -> ControlFlow<()>>(
+        &self,
+        f: &mut F,
+        depth: usize,
+    ) -> ControlFlow<()> {
+        f(self, depth)?;
+        if let ParsedData::List(a) = self {
+            for item in a {
+                item.walk_inner(f, depth + 1)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+
+    fn sample() -> ParsedData {
+        ParsedData::List(vec![
+            ParsedData::Byte(0x0a),
+            ParsedData::String(b"ABC".to_vec()),
+            ParsedData::List(vec![ParsedData::String(b"De".to_vec())]),
+        ])
+    }
+
+    #[test]
+    fn map_strings_lowercase() {
+        let mapped = sample().map_strings(|a| a.to_ascii_lowercase());
+        assert_eq!(
+            mapped,
+            ParsedData::List(vec![
+                ParsedData::Byte(0x0a),
+                ParsedData::String(b"abc".to_vec()),
+                ParsedData::List(vec![ParsedData::String(b"de".to_vec())]),
+            ])
+        );
+    }
+
+    #[test]
+    fn map_strings_restores_single_bytes() {
+        let mapped = sample().map_strings(|a| a[..1].to_vec());
+        assert_eq!(
+            mapped,
+            ParsedData::List(vec![
+                ParsedData::Byte(0x0a),
+                ParsedData::Byte(b'A'),
+                ParsedData::List(vec![ParsedData::Byte(b'D')]),
+            ])
+        );
+    }
+
+    #[test]
+    fn visit_strings_in_order() {
+        let mut lengths = Vec::new();
+        sample().visit_strings(|a| lengths.push(a.len()));
+        assert_eq!(lengths, vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn counts() {
+        assert_eq!(sample().count_leaves(), 3);
+        assert_eq!(sample().count_nodes(), 5);
+        assert_eq!(ParsedData::Byte(0).count_nodes(), 1);
+        assert_eq!(ParsedData::List(Vec::new()).count_leaves(), 0);
+        assert_eq!(ParsedData::List(Vec::new()).count_nodes(), 1);
+    }
+
+    #[test]
+    fn path() {
+        let tree = sample();
+        assert_eq!(tree.path(&[]), Some(&tree));
+        assert_eq!(tree.path(&[0]), Some(&ParsedData::Byte(0x0a)));
+        assert_eq!(
+            tree.path(&[2, 0]),
+            Some(&ParsedData::String(b"De".to_vec()))
+        );
+        assert_eq!(tree.path(&[3]), None);
+        assert_eq!(tree.path(&[2, 1]), None);
+        // Strings are not descended into.
+        assert_eq!(tree.path(&[1, 0]), None);
+        assert_eq!(tree.path(&[2, 0, 0]), None);
+    }
+
+    #[test]
+    fn canonicalize() {
+        // [ "\x05", [ "\x7f", "\x80" ] ] with single bytes as strings.
+        let raw = hex::decode("c78105c4817f8180").unwrap();
+        let lenient = crate::decode_whole_blob::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+        assert_eq!(lenient.to_vec(), raw);
+
+        let canonical = lenient.canonicalize();
+        assert_eq!(
+            canonical,
+            ParsedData::List(vec![
+                ParsedData::Byte(0x05),
+                ParsedData::List(vec![ParsedData::Byte(0x7f), ParsedData::String(vec![0x80])]),
+            ])
+        );
+        assert_eq!(canonical.to_vec(), hex::decode("c505c37f8180").unwrap());
+        assert_eq!(sample().canonicalize(), sample());
+    }
+
+    #[test]
+    fn walk() {
+        let mut visited = Vec::new();
+        let flow = sample().walk(|node, depth| {
+            visited.push((node.count_nodes(), depth));
+            ControlFlow::Continue(())
+        });
+        assert_eq!(flow, ControlFlow::Continue(()));
+        assert_eq!(visited, vec![(5, 0), (1, 1), (1, 1), (2, 1), (1, 2)]);
+
+        // Search stops at the first match.
+        let mut visited = 0;
+        let flow = sample().walk(|node, _| {
+            visited += 1;
+            if node.as_bytes() == Ok(&b"ABC"[..]) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(flow, ControlFlow::Break(()));
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn diff() {
+        let tree = sample();
+        assert_eq!(tree.diff(&sample()), None);
+
+        let mapped = tree.map_strings(|a| a.to_ascii_lowercase());
+        assert_eq!(
+            tree.diff(&mapped),
+            Some(DiffPath {
+                path: vec![1],
+                kind: DiffKind::Byte {
+                    offset: 0,
+                    left: b'A',
+                    right: b'a'
+                }
+            })
+        );
+
+        let longer = ParsedData::List(vec![
+            ParsedData::Byte(0x0a),
+            ParsedData::String(b"ABC".to_vec()),
+            ParsedData::List(vec![
+                ParsedData::String(b"Def".to_vec()),
+                ParsedData::Byte(0),
+            ]),
+        ]);
+        assert_eq!(
+            tree.diff(&longer),
+            Some(DiffPath {
+                path: vec![2, 0],
+                kind: DiffKind::StringLength { left: 2, right: 3 }
+            })
+        );
+        assert_eq!(
+            <ParsedData>::List(vec![ParsedData::Byte(1)]).diff(&<ParsedData>::List(vec![
+                ParsedData::Byte(1),
+                ParsedData::Byte(2)
+            ])),
+            Some(DiffPath {
+                path: Vec::new(),
+                kind: DiffKind::ListLength { left: 1, right: 2 }
+            })
+        );
+
+        // Equal bytes in different variants.
+        assert_eq!(
+            <ParsedData>::Byte(1).diff(&<ParsedData>::String(vec![1])),
+            Some(DiffPath {
+                path: Vec::new(),
+                kind: DiffKind::Variant
+            })
+        );
+        // Borrowed strings compare with owned ones.
+        assert_eq!(
+            ParsedData::String(&b"De"[..]).diff(tree.path(&[2, 0]).unwrap()),
+            None
+        );
+    }
+}
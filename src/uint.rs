@@ -0,0 +1,117 @@
+//! Arithmetic on 256-bit big-endian scalars, as stored in decoded fields.
+
+/// `ceil(value * num / den)` for a 256-bit big-endian `value`, saturating at
+/// `2^256 - 1`. `den` must be non-zero.
+pub(crate) fn mul_div_ceil(value: &[u8; 32], num: u64, den: u64) -> [u8; 32] {
+    mul_div(value, num, den, true)
+}
+
+/// `floor(value * num / den)`, see [`mul_div_ceil`].
+pub(crate) fn mul_div_floor(value: &[u8; 32], num: u64, den: u64) -> [u8; 32] {
+    mul_div(value, num, den, false)
+}
+
+fn mul_div(value: &[u8; 32], num: u64, den: u64, round_up: bool) -> [u8; 32] {
+    // Product in five 64-bit limbs, most significant first.
+    let mut product = [0u64; 5];
+    let mut carry = 0u128;
+    for i in (0..4).rev() {
+        let limb = u64::from_be_bytes(value[8 * i..8 * i + 8].try_into().unwrap());
+        let sum = limb as u128 * num as u128 + carry;
+        product[i + 1] = sum as u64;
+        carry = sum >> 64;
+    }
+    product[0] = carry as u64;
+
+    if round_up {
+        // Adding `den - 1` before division rounds up.
+        let mut carry = (den - 1) as u128;
+        for limb in product.iter_mut().rev() {
+            let sum = *limb as u128 + carry;
+            *limb = sum as u64;
+            carry = sum >> 64;
+        }
+        if carry != 0 {
+            return [0xff; 32];
+        }
+    }
+
+    let mut remainder = 0u128;
+    for limb in product.iter_mut() {
+        let current = (remainder << 64) | *limb as u128;
+        *limb = (current / den as u128) as u64;
+        remainder = current % den as u128;
+    }
+    if product[0] != 0 {
+        return [0xff; 32];
+    }
+    let mut out = [0; 32];
+    for (i, limb) in product[1..].iter().enumerate() {
+        out[8 * i..8 * i + 8].copy_from_slice(&limb.to_be_bytes());
+    }
+    out
+}
+
+/// `a + b`, saturating at `2^256 - 1`.
+pub(crate) fn saturating_add(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut out = [0; 32];
+    let mut carry = 0u16;
+    for i in (0..32).rev() {
+        let sum = a[i] as u16 + b[i] as u16 + carry;
+        out[i] = sum as u8;
+        carry = sum >> 8;
+    }
+    if carry != 0 {
+        return [0xff; 32];
+    }
+    out
+}
+
+/// `a - b`, saturating at zero.
+pub(crate) fn saturating_sub(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    // Big-endian arrays compare as the numbers they hold.
+    if a <= b {
+        return [0; 32];
+    }
+    let mut out = [0; 32];
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let mut difference = a[i] as i16 - b[i] as i16 - borrow;
+        borrow = (difference < 0) as i16;
+        difference += 256 * borrow;
+        out[i] = difference as u8;
+    }
+    out
+}
+
+pub(crate) fn from_u64(value: u64) -> [u8; 32] {
+    let mut out = [0; 32];
+    out[24..].copy_from_slice(&value.to_be_bytes());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_sub() {
+        assert_eq!(saturating_add(&from_u64(255), &from_u64(1)), from_u64(256));
+        assert_eq!(saturating_add(&[0xff; 32], &from_u64(1)), [0xff; 32]);
+        assert_eq!(saturating_sub(&from_u64(256), &from_u64(1)), from_u64(255));
+        assert_eq!(saturating_sub(&from_u64(1), &from_u64(2)), [0; 32]);
+        let mut high = [0; 32];
+        high[0] = 0x01;
+        let mut expected = [0xff; 32];
+        expected[0] = 0x00;
+        assert_eq!(saturating_sub(&high, &from_u64(1)), expected);
+    }
+
+    #[test]
+    fn mul_div_rounding() {
+        assert_eq!(mul_div_floor(&from_u64(7), 3, 2), from_u64(10));
+        assert_eq!(mul_div_ceil(&from_u64(7), 3, 2), from_u64(11));
+        assert_eq!(mul_div_floor(&[0xff; 32], 1, 1), [0xff; 32]);
+        assert_eq!(mul_div_floor(&[0xff; 32], 2, 1), [0xff; 32]);
+    }
+}
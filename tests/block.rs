@@ -0,0 +1,126 @@
+//! Parsing of a whole post-London block with the public API.
+use alloy_eips::eip4895::Withdrawal as AlloyWithdrawal;
+use alloy_primitives::Address;
+use alloy_rlp::{Encodable, Header as RlpHeader};
+
+use eth_parser::block::Block;
+
+/// Header of mainnet block 19,449,567, since Cancun, hash
+/// 0x85cdcbe36217fd57bf2c33731d8460657a7ce512401f49c9f6392c82a7ccf7ac.
+const HEADER: &str = "f90255a090926e0298d418181bd20c23b332451e35fd7d696b5dcdc5a3a0a6b715f4c717a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d493479495222290dd7278aa3ddd389cc1e1d165cc4bafe5a0707875120a7103621fb4131df59904cda39de948dfda9084a1e3da44594d5404a0889a1c26dc42ba829dab552b779620feac231cde8a6c79af022bdc605c23a780a0d43aa19ecb03571d1b86d89d9bb980139d32f2f2ba59646cd5c1de9e80c68c90b90100c36919406572730518285284f2293101104140c0d42c4a786c892467868a8806f40159d29988002870403902413a1d04321320308da2e845438429e0012a00b419d8ccc8584a1c28f82a415d04eab8a5ae75c00d07761acf233414c08b6d9b571c06156086c70ea5186e9b989b0c2d55c0213c936805cd2ab331589c90194d070c00867549b1e1be14cb24500b0386cd901197c1ef5a00da453234fa48f3003dcaa894e3111c22b80e17f7d4388385a10720cda1140c0400f9e084ca34fc4870fb16b472340a2a6a63115a82522f506c06c2675080508834828c63defd06bc2331b4aa708906a06a560457b114248041e40179ebc05c6846c1e922125982f42780840128c6df8401c9c38083b0033c8465f5f4c38f6265617665726275696c642e6f7267a04c068e902990f21f92a2456fc75c59bec8be03b7f13682b6ebd27da56269beb5880000000000000000850886b221ada0360c33f20eeed5efbc7d08be46e58f8440af5db503e40908ef3d1eb314856ef78080a02843cb9f7d001bd58816a915e685ed96a555c9aeec1217736bd83a96ebd409cc";
+
+/// Mainnet EIP-1559 transaction
+/// 0xce4dc6d7a7549a98ee3b071b67e970879ff51b5b95d1c340bacd80fa1e1aab31.
+const EIP1559: &str = "02f86f0102843b9aca0085029e7822d68298f094d9e1459a7a482635700cbc20bbaf52d495ab9c9680841b55ba3ac080a0c199674fcb29f353693dd779c017823b954b3c69dffa3cd6b2a6ff7888798039a028ca912de909e7e6cdef9cdcaf24c54dd8c1032946dfa1d85c206b32a9064fe8";
+
+/// Mainnet legacy transaction
+/// 0x280cde7cdefe4b188750e76c888f13bd05ce9a4d7767730feefe8a0e50ca6fc4.
+const LEGACY: &str = "f9015482078b8505d21dba0083022ef1947a250d5630b4cf539739df2c5dacb4c659f2488d880c46549a521b13d8b8e47ff36ab50000000000000000000000000000000000000000000066ab5a608bd00a23f2fe000000000000000000000000000000000000000000000000000000000000008000000000000000000000000048c04ed5691981c42154c6167398f95e8f38a7ff00000000000000000000000000000000000000000000000000000000632ceac70000000000000000000000000000000000000000000000000000000000000002000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc20000000000000000000000006c6ee5e31d828de241282b9606c8e98ea48526e225a0c9077369501641a92ef7399ff81c21639ed4fd8fc69cb793cfa1dbfab342e10aa0615facb2f1bcf3274a354cfe384a38d0cc008a11c2dd23a69111bc6930ba27a8";
+
+fn list(payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    RlpHeader {
+        list: true,
+        payload_length: payload.len(),
+    }
+    .encode(&mut out);
+    out.extend_from_slice(payload);
+    out
+}
+
+/// Real header of block 19,449,567 with real mainnet transactions and the
+/// block's first withdrawal. Body does not match the header roots.
+fn block() -> Vec<u8> {
+    let mut transactions = Vec::new();
+    hex::decode(EIP1559)
+        .unwrap()
+        .as_slice()
+        .encode(&mut transactions);
+    transactions.extend(hex::decode(LEGACY).unwrap());
+    let mut withdrawals = Vec::new();
+    vec![AlloyWithdrawal {
+        index: 38_633_702,
+        validator_index: 570_038,
+        address: "7cd1122e8e118b12ece8d25480dfeef230da17ff"
+            .parse::<Address>()
+            .unwrap(),
+        amount: 18_226_960,
+    }]
+    .encode(&mut withdrawals);
+    let mut payload = hex::decode(HEADER).unwrap();
+    payload.extend(list(&transactions));
+    payload.extend(list(&[]));
+    payload.extend(withdrawals);
+    list(&payload)
+}
+
+fn bytes<const N: usize>(hex_str: &str) -> [u8; N] {
+    hex::decode(hex_str).unwrap().try_into().unwrap()
+}
+
+#[test]
+fn post_london_block() {
+    let raw = block();
+    let block = Block::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+
+    let header = &block.header;
+    assert_eq!(header.number, 19_449_567);
+    assert_eq!(header.gas_used, 11_535_164);
+    assert_eq!(header.timestamp, 1_710_617_795);
+    assert_eq!(
+        header.beneficiary,
+        bytes("95222290dd7278aa3ddd389cc1e1d165cc4bafe5")
+    );
+    let mut base_fee = [0; 32];
+    base_fee[24..].copy_from_slice(&36_619_559_341u64.to_be_bytes());
+    assert_eq!(header.base_fee_per_gas, Some(base_fee));
+    assert_eq!(
+        header.withdrawals_root,
+        Some(bytes(
+            "360c33f20eeed5efbc7d08be46e58f8440af5db503e40908ef3d1eb314856ef7"
+        ))
+    );
+    assert_eq!(header.blob_gas_used, Some(0));
+    assert_eq!(header.excess_blob_gas, Some(0));
+
+    let body = &block.body;
+    assert_eq!(body.transactions.len(), 2);
+    assert_eq!(body.transactions[0].tx_type(), 2);
+    assert_eq!(body.transactions[1].tx_type(), 0);
+    assert!(body.ommers.is_empty());
+    let withdrawals = body.withdrawals.as_ref().unwrap();
+    assert_eq!(withdrawals.len(), 1);
+    assert_eq!(withdrawals[0].validator_index, 570_038);
+    assert_eq!(withdrawals[0].amount_gwei, 18_226_960);
+}
+
+#[cfg(feature = "keccak")]
+#[test]
+fn hashes() {
+    let raw = block();
+    let block = Block::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+    assert_eq!(
+        block.header.hash(),
+        bytes("85cdcbe36217fd57bf2c33731d8460657a7ce512401f49c9f6392c82a7ccf7ac")
+    );
+    assert_eq!(
+        block.body.transactions[0].hash(),
+        bytes("ce4dc6d7a7549a98ee3b071b67e970879ff51b5b95d1c340bacd80fa1e1aab31")
+    );
+}
+
+#[cfg(feature = "recover")]
+#[test]
+fn senders() {
+    let raw = block();
+    let block = Block::parse::<&[u8], ()>(&raw.as_ref(), &mut ()).unwrap();
+    let transactions = &block.body.transactions;
+    assert_eq!(
+        transactions[0].recover_sender(),
+        Ok(bytes("001e2b7de757ba469a57bf6b23d982458a07efce"))
+    );
+    assert_eq!(
+        transactions[1].recover_sender(),
+        Ok(bytes("a12e1462d0ced572f396f58b6e2d03894cd7c8a4"))
+    );
+}